@@ -0,0 +1,96 @@
+//! Compares the scalar and SIMD updates of a `TweenBank`, against a naive loop of tweeners,
+//! for one easing and for a few, and shows what the bank saves by multiplying by the
+//! reciprocal of each duration, rather than dividing by the duration.
+//!
+//! Run with `cargo bench --features wide`.
+
+use std::{hint::black_box, time::Instant};
+use tween::{Ease, QuadInOut, TweenBank, Tweener};
+
+const TWEENS: usize = 100_000;
+const FRAMES: usize = 100;
+const MIXED: [Ease; 4] = [Ease::QuadInOut, Ease::SineOut, Ease::BackOut, Ease::CubicIn];
+
+/// Times the fastest of the frames, which is the least disturbed by anything else running.
+fn time(name: &str, mut frame: impl FnMut()) {
+    let mut fastest = u128::MAX;
+    for _ in 0..FRAMES {
+        let start = Instant::now();
+        frame();
+        fastest = fastest.min(start.elapsed().as_nanos());
+    }
+
+    let per_tween = fastest as f64 / TWEENS as f64;
+    println!("{name:>10}: {per_tween:.2}ns per tween");
+}
+
+fn main() {
+    let delta = 1.0 / 600.0;
+
+    let mut tweeners: Vec<_> = (0..TWEENS)
+        .map(|i| Tweener::new(QuadInOut::new(0.0f32..=i as f32, 1.0f32)))
+        .collect();
+    let mut out = vec![0.0; TWEENS];
+    time("tweeners", || {
+        for (tweener, out) in tweeners.iter_mut().zip(&mut out) {
+            *out = tweener.update(delta).unwrap_or(*out);
+        }
+        black_box(&mut out);
+    });
+
+    let mut bank = TweenBank::with_capacity(TWEENS);
+    for i in 0..TWEENS {
+        bank.push(0.0..=i as f32, 1.0, Ease::QuadInOut);
+    }
+    let mut simd_bank = bank.clone();
+
+    time("bank", || {
+        bank.update_all(delta, &mut out);
+        black_box(&mut out);
+    });
+
+    time("bank simd", || {
+        simd_bank.update_all_simd(delta, &mut out);
+        black_box(&mut out);
+    });
+
+    // a scene of a few easings, each pushed in a batch, such as by a particle emitter
+    let mut kinds = TweenBank::with_capacity(TWEENS);
+    for i in 0..TWEENS {
+        kinds.push(0.0..=i as f32, 1.0, MIXED[i / 256 % MIXED.len()]);
+    }
+
+    time("bank kinds", || {
+        kinds.update_all(delta, &mut out);
+        black_box(&mut out);
+    });
+
+    // the same easings, interleaved, which is the worst case for grouping them
+    let mut mixed = TweenBank::with_capacity(TWEENS);
+    for i in 0..TWEENS {
+        mixed.push(0.0..=i as f32, 1.0, MIXED[i % MIXED.len()]);
+    }
+
+    time("bank mixed", || {
+        mixed.update_all(delta, &mut out);
+        black_box(&mut out);
+    });
+
+    let elapsed: Vec<f32> = (0..TWEENS).map(|i| i as f32 / TWEENS as f32).collect();
+    let durations: Vec<f32> = (0..TWEENS).map(|i| 1.0 + (i % 7) as f32).collect();
+    let rates: Vec<f32> = durations.iter().map(|duration| 1.0 / duration).collect();
+
+    time("divide", || {
+        for ((out, elapsed), duration) in out.iter_mut().zip(&elapsed).zip(&durations) {
+            *out = elapsed / duration;
+        }
+        black_box(&mut out);
+    });
+
+    time("multiply", || {
+        for ((out, elapsed), rate) in out.iter_mut().zip(&elapsed).zip(&rates) {
+            *out = elapsed * rate;
+        }
+        black_box(&mut out);
+    });
+}
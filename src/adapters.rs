@@ -0,0 +1,15 @@
+mod erased;
+mod extrapolate;
+mod jitter;
+mod map;
+mod per_axis;
+mod time_remap;
+
+#[cfg(feature = "std")]
+pub use erased::BoxedTween;
+pub use erased::ErasedTween;
+pub use extrapolate::Extrapolate;
+pub use jitter::Jitter;
+pub use map::Map;
+pub use per_axis::{Axes, PerAxis};
+pub use time_remap::TimeRemap;
@@ -0,0 +1,111 @@
+use crate::Tween;
+#[cfg(feature = "std")]
+use crate::{TweenTime, TweenValue};
+use core::ops::RangeInclusive;
+#[cfg(feature = "std")]
+use std::boxed::Box;
+
+/// An object safe version of [Tween], which every tween implements. A `dyn ErasedTween<V, T>`
+/// can be any tween of `V` over `T`, so one collection can hold many kinds of easings. With
+/// the `std` feature, a [BoxedTween] is a [Tween] itself, and can be given to any tweener.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// # use tween::{BoxedTween, Linear, QuadIn, SineOut, Tween, Tweener};
+/// let mut tweens: Vec<BoxedTween<'_, f32, f32>> = vec![
+///     Box::new(Linear::new(0.0..=10.0, 10.0)),
+///     Box::new(QuadIn::new(0.0..=10.0, 10.0)),
+/// ];
+/// tweens.push(Box::new(SineOut::new(0.0..=10.0, 10.0)));
+///
+/// assert_eq!(tweens[1].run(5.0), 2.5);
+///
+/// let mut tweener = Tweener::new(tweens.remove(0));
+/// assert_eq!(tweener.update(5.0), Some(5.0));
+/// # }
+/// ```
+pub trait ErasedTween<V, T> {
+    /// Run the tween with a new time. See [Tween::run].
+    fn run(&mut self, new_time: T) -> V;
+
+    /// Run the tween at a percentage of the way through its duration. See [Tween::run_percent].
+    fn run_percent(&mut self, percent: f64) -> V;
+
+    /// Get a reference to the tween's range.
+    fn range(&self) -> RangeInclusive<V>;
+
+    /// Get the tween's total duration.
+    fn duration(&self) -> T;
+}
+
+impl<Tw> ErasedTween<Tw::Value, Tw::Time> for Tw
+where
+    Tw: Tween,
+{
+    fn run(&mut self, new_time: Tw::Time) -> Tw::Value {
+        Tween::run(self, new_time)
+    }
+
+    fn run_percent(&mut self, percent: f64) -> Tw::Value {
+        Tween::run_percent(self, percent)
+    }
+
+    fn range(&self) -> RangeInclusive<Tw::Value> {
+        Tween::range(self)
+    }
+
+    fn duration(&self) -> Tw::Time {
+        Tween::duration(self)
+    }
+}
+
+/// A boxed [ErasedTween], which holds any tween of `V` over `T`.
+#[cfg(feature = "std")]
+pub type BoxedTween<'a, V, T> = Box<dyn ErasedTween<V, T> + 'a>;
+
+#[cfg(feature = "std")]
+impl<V, T> Tween for Box<dyn ErasedTween<V, T> + '_>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    type Value = V;
+    type Time = T;
+
+    fn run(&mut self, new_time: T) -> V {
+        (**self).run(new_time)
+    }
+
+    fn run_percent(&mut self, percent: f64) -> V {
+        (**self).run_percent(percent)
+    }
+
+    fn range(&self) -> RangeInclusive<V> {
+        (**self).range()
+    }
+
+    fn duration(&self) -> T {
+        (**self).duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BounceOut, Linear};
+
+    #[test]
+    fn erased() {
+        let mut tween = BounceOut::new(0.0..=10.0, 4.0);
+        let expected = tween.run(1.0);
+
+        let erased: &mut dyn ErasedTween<f64, f64> = &mut tween;
+        assert_eq!(erased.run(1.0), expected);
+        assert_eq!(erased.duration(), 4.0);
+
+        let mut linear = Linear::new(0..=10, 10);
+        let erased: &mut dyn ErasedTween<i32, i32> = &mut linear;
+        assert_eq!(erased.run_percent(0.5), 5);
+        assert_eq!(erased.range(), 0..=10);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Tween, TweenTime};
+use core::ops::RangeInclusive;
+
+/// An [Extrapolate] lets an easing carry on its curve for negative times, rather than holding
+/// its start value, which is what every easing does by default.
+///
+/// ```
+/// # use tween::{Extrapolate, Linear, QuadIn};
+/// let mut clamped = Linear::new(0.0..=10.0, 10.0);
+/// assert_eq!(clamped.run(-5.0), 0.0);
+///
+/// let mut extrapolated = Extrapolate::new(Linear::new(0.0..=10.0, 10.0));
+/// assert_eq!(extrapolated.run(-5.0), -5.0);
+///
+/// // a quadratic curves back up, the way it came
+/// let mut extrapolated = Extrapolate::new(QuadIn::new(0.0..=100.0, 10.0));
+/// assert_eq!(extrapolated.run(-5.0), 25.0);
+/// ```
+///
+/// This runs the inner tween with [Tween::run_percent], so it works with the easings of this
+/// library, and any other tween which evaluates that directly.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Extrapolate<Tw> {
+    tween: Tw,
+}
+
+impl<Tw> Extrapolate<Tw>
+where
+    Tw: Tween,
+{
+    /// Creates a new [Extrapolate] around a tween.
+    pub fn new(tween: Tw) -> Self {
+        Self { tween }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: Tw::Time) -> Tw::Value {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// Allows inspection of the inner tween.
+    pub fn tween(&self) -> &Tw {
+        &self.tween
+    }
+}
+
+impl<Tw> Tween for Extrapolate<Tw>
+where
+    Tw: Tween,
+{
+    type Value = Tw::Value;
+    type Time = Tw::Time;
+
+    fn run(&mut self, new_time: Self::Time) -> Self::Value {
+        let duration = self.tween.duration();
+        if duration == Tw::Time::ZERO {
+            return *self.tween.range().end();
+        }
+
+        let percent = Tw::Time::percent(duration, new_time);
+
+        self.tween.run_percent(percent)
+    }
+
+    fn run_percent(&mut self, percent: f64) -> Self::Value {
+        self.tween.run_percent(percent)
+    }
+
+    fn range(&self) -> RangeInclusive<Self::Value> {
+        self.tween.range()
+    }
+
+    fn duration(&self) -> Self::Time {
+        self.tween.duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BounceIn, CubicOut, Hermite};
+
+    #[test]
+    fn carries_on_backwards() {
+        let mut cubic = Extrapolate::new(CubicOut::new(0.0..=1.0, 1.0));
+        assert_eq!(cubic.run(-1.0), -7.0);
+        assert_eq!(cubic.run(0.5), CubicOut::new(0.0..=1.0, 1.0).run(0.5));
+
+        let mut bounce = Extrapolate::new(BounceIn::new(0.0..=1.0, 4));
+        assert!(bounce.run(-1) != 0.0);
+
+        let mut hermite = Extrapolate::new(Hermite::with_velocities(0.0..=10.0, 1.0, 0.0, 10.0));
+        assert!(hermite.run(-1.0) < 0.0);
+    }
+}
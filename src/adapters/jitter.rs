@@ -0,0 +1,150 @@
+use crate::{Tween, TweenTime, rng::SplitMix64};
+use core::ops::RangeInclusive;
+
+/// A [Jitter] randomizes the timing of another tween, by stretching its duration and
+/// optionally delaying its start, by an amount picked from a seeded random number generator.
+///
+/// If you give every element in a crowd of particles the same tween, they look mechanical.
+/// Giving each of them a [Jitter] with a different seed (such as their index) makes them
+/// each move a little differently, while staying perfectly reproducible.
+///
+/// ```
+/// # use tween::{Jitter, Linear, Tween};
+/// let jittered = Jitter::new(Linear::new(0.0..=1.0, 10.0), 42)
+///     .duration_jitter(0.8..=1.2)
+///     .delay_jitter(0.0..=2.0);
+///
+/// assert!((8.0..=14.0).contains(&jittered.duration()));
+///
+/// // the same seed always agrees with itself
+/// let again = Jitter::new(Linear::new(0.0..=1.0, 10.0), 42)
+///     .duration_jitter(0.8..=1.2)
+///     .delay_jitter(0.0..=2.0);
+/// assert_eq!(jittered.duration(), again.duration());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Jitter<Tw: Tween> {
+    tween: Tw,
+    rng: SplitMix64,
+    speed: f64,
+    jittered_duration: Tw::Time,
+    delay: Tw::Time,
+}
+
+impl<Tw> Jitter<Tw>
+where
+    Tw: Tween,
+{
+    /// Creates a new [Jitter] around a tween, seeded with the given seed. Without
+    /// configuring [duration_jitter](Self::duration_jitter) or
+    /// [delay_jitter](Self::delay_jitter), the timing is unchanged.
+    pub fn new(tween: Tw, seed: u64) -> Self {
+        Self {
+            jittered_duration: tween.duration(),
+            tween,
+            rng: SplitMix64::new(seed),
+            speed: 1.0,
+            delay: Tw::Time::ZERO,
+        }
+    }
+
+    /// Randomly stretches the duration of the tween by a multiplier picked from the given
+    /// range, so `0.9..=1.1` gives a duration within 10% of the original.
+    pub fn duration_jitter(mut self, multiplier: RangeInclusive<f64>) -> Self {
+        let multiplier = lerp(&multiplier, self.rng.next_f64());
+
+        self.speed = 1.0 / multiplier;
+        self.jittered_duration = self.tween.duration().scale(multiplier);
+        self
+    }
+
+    /// Randomly delays the start of the tween by an amount of time picked from the given
+    /// range. Until the delay has passed, the tween stays at its start value.
+    pub fn delay_jitter(mut self, delay: RangeInclusive<Tw::Time>) -> Self {
+        let spread = delay.end().sub(*delay.start());
+
+        self.delay = delay.start().add(spread.scale(self.rng.next_f64()));
+        self
+    }
+
+    /// The random delay which was picked for this tween.
+    pub fn picked_delay(&self) -> Tw::Time {
+        self.delay
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: Tw::Time) -> Tw::Value {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// Allows inspection of the inner tween.
+    pub fn tween(&self) -> &Tw {
+        &self.tween
+    }
+}
+
+fn lerp(range: &RangeInclusive<f64>, t: f64) -> f64 {
+    range.start() + (range.end() - range.start()) * t
+}
+
+impl<Tw> Tween for Jitter<Tw>
+where
+    Tw: Tween,
+{
+    type Value = Tw::Value;
+    type Time = Tw::Time;
+
+    fn run(&mut self, new_time: Self::Time) -> Self::Value {
+        if !new_time.is_complete(self.delay) {
+            return *self.tween.range().start();
+        }
+
+        let local_time = new_time.sub(self.delay).scale(self.speed);
+        self.tween.run(local_time)
+    }
+
+    fn range(&self) -> RangeInclusive<Self::Value> {
+        self.tween.range()
+    }
+
+    fn duration(&self) -> Self::Time {
+        self.delay.add(self.jittered_duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Tweener};
+    use approx::assert_ulps_eq;
+
+    #[test]
+    fn unjittered_is_unchanged() {
+        let mut jitter = Jitter::new(Linear::new(0.0..=10.0, 10.0), 0);
+
+        assert_eq!(jitter.duration(), 10.0);
+        assert_eq!(jitter.run(5.0), 5.0);
+    }
+
+    #[test]
+    fn stretches_and_delays() {
+        let mut jitter = Jitter::new(Linear::new(0.0..=1.0, 10.0), 3)
+            .duration_jitter(2.0..=2.0)
+            .delay_jitter(1.0..=1.0);
+
+        assert_ulps_eq!(jitter.duration(), 21.0);
+        assert_eq!(jitter.run(0.5), 0.0);
+        assert_ulps_eq!(jitter.run(11.0), 0.5);
+
+        let mut tweener = Tweener::new(jitter);
+        assert_eq!(tweener.update(21.0), Some(1.0));
+    }
+
+    #[test]
+    fn seeds_differ() {
+        let a = Jitter::new(Linear::new(0.0..=1.0, 10.0), 1).duration_jitter(0.5..=1.5);
+        let b = Jitter::new(Linear::new(0.0..=1.0, 10.0), 2).duration_jitter(0.5..=1.5);
+
+        assert_ne!(a.duration(), b.duration());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{Tween, TweenValue};
+use core::ops::RangeInclusive;
+
+/// A [Map] converts every value another tween outputs into a different value, with a
+/// function of your own. The new value can be of any type at all, so [Map::run] works for
+/// labels, enums, or anything else, but it only implements [Tween] when the new value is a
+/// [TweenValue] itself.
+///
+/// This is usually made with [Progress::map](crate::Progress::map):
+///
+/// ```
+/// # use tween::{Progress, SineInOut};
+/// let mut opacity = Progress::new(SineInOut::new, 1.0).map(|t| (t * 255.0) as u8);
+///
+/// assert_eq!(opacity.run(0.0), 0);
+/// assert_eq!(opacity.run(1.0), 255);
+/// ```
+#[derive(Clone)]
+pub struct Map<Tw: Tween, F, U> {
+    tween: Tw,
+    map: F,
+    range: RangeInclusive<U>,
+}
+
+impl<Tw, F, U> Map<Tw, F, U>
+where
+    Tw: Tween,
+    F: FnMut(Tw::Value) -> U,
+{
+    /// Creates a new [Map] around a tween. The function is called right away on both ends
+    /// of the tween's range, and then once every time the [Map] is run.
+    pub fn new(tween: Tw, mut map: F) -> Self {
+        let range = map(*tween.range().start())..=map(*tween.range().end());
+
+        Self { tween, map, range }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: Tw::Time) -> U {
+        (self.map)(self.tween.run(new_time))
+    }
+
+    /// Run the given Tween at a percentage of the way through its duration.
+    pub fn run_percent(&mut self, percent: f64) -> U {
+        (self.map)(self.tween.run_percent(percent))
+    }
+
+    /// The mapped start and end values of the tween.
+    pub fn mapped_range(&self) -> &RangeInclusive<U> {
+        &self.range
+    }
+
+    /// Allows inspection of the inner tween.
+    pub fn tween(&self) -> &Tw {
+        &self.tween
+    }
+}
+
+impl<Tw, F, U> Tween for Map<Tw, F, U>
+where
+    Tw: Tween,
+    F: FnMut(Tw::Value) -> U,
+    U: TweenValue,
+{
+    type Value = U;
+    type Time = Tw::Time;
+
+    fn run(&mut self, new_time: Self::Time) -> Self::Value {
+        (self.map)(self.tween.run(new_time))
+    }
+
+    fn run_percent(&mut self, percent: f64) -> Self::Value {
+        (self.map)(self.tween.run_percent(percent))
+    }
+
+    fn range(&self) -> RangeInclusive<Self::Value> {
+        self.range.clone()
+    }
+
+    fn duration(&self) -> Self::Time {
+        self.tween.duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Progress, Tweener};
+
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    enum Phase {
+        Windup,
+        Strike,
+    }
+
+    #[test]
+    fn any_value() {
+        let mut phase = Progress::new(Linear::new, 10).map(|t| if t < 0.3 { Phase::Windup } else { Phase::Strike });
+
+        assert_eq!(phase.mapped_range(), &(Phase::Windup..=Phase::Strike));
+        assert_eq!(phase.run(2), Phase::Windup);
+        assert_eq!(phase.run(5), Phase::Strike);
+    }
+
+    #[test]
+    fn drives_in_tweener() {
+        let mut tweener = Tweener::new(Map::new(Linear::new(0.0..=1.0, 4.0), |t: f32| [t, t * 2.0]));
+
+        assert_eq!(tweener.update(2.0), Some([0.5, 1.0]));
+        assert_eq!(tweener.update(2.0), Some([1.0, 2.0]));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Map, Tween, TweenTime, TweenValue};
+use core::ops::RangeInclusive;
+
+/// A [PerAxis] runs a tuple of tweens, one for each component (or "axis") of a value, from
+/// the same time, so each component can have its own easing.
+///
+/// A jump, for example, moves along at a constant speed while rising and falling on a curve.
+/// The output is a tuple, which [PerAxis::map] can turn back into your own vector type:
+///
+/// ```
+/// # use tween::{Linear, PerAxis, QuadOut};
+/// let mut jump = PerAxis::new((Linear::new(0.0..=10.0, 1.0), QuadOut::new(0.0..=4.0, 1.0)))
+///     .map(|(x, y)| [x, y]);
+///
+/// assert_eq!(jump.run(0.5), [5.0, 3.0]);
+/// assert_eq!(jump.run(1.0), [10.0, 4.0]);
+/// ```
+///
+/// The axes don't need to share a duration. The [PerAxis] lasts as long as its longest axis,
+/// and the shorter axes hold their end values once they've finished.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PerAxis<A: Axes> {
+    axes: A,
+    range: RangeInclusive<A::Value>,
+}
+
+impl<A> PerAxis<A>
+where
+    A: Axes,
+{
+    /// Creates a new [PerAxis] out of a tuple of tweens, one for each axis, which all use the
+    /// same kind of time.
+    pub fn new(axes: A) -> Self {
+        let range = axes.axes_range();
+
+        Self { axes, range }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: A::Time) -> A::Value {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// Converts the tuple of every axis into another value, such as a vector, every time
+    /// this is run.
+    pub fn map<U, F>(self, map: F) -> Map<Self, F, U>
+    where
+        F: FnMut(A::Value) -> U,
+    {
+        Map::new(self, map)
+    }
+
+    /// Allows inspection of the tween of each axis.
+    pub fn axes(&self) -> &A {
+        &self.axes
+    }
+}
+
+impl<A> Tween for PerAxis<A>
+where
+    A: Axes,
+{
+    type Value = A::Value;
+    type Time = A::Time;
+
+    fn run(&mut self, new_time: Self::Time) -> Self::Value {
+        self.axes.run_axes(new_time)
+    }
+
+    fn range(&self) -> RangeInclusive<Self::Value> {
+        self.range.clone()
+    }
+
+    fn duration(&self) -> Self::Time {
+        self.axes.longest_duration()
+    }
+}
+
+/// A tuple of two to four tweens, which all use the same kind of time, that a [PerAxis] can
+/// run together.
+pub trait Axes {
+    /// A tuple of the value of each axis.
+    type Value: TweenValue;
+    /// The time every axis is run with.
+    type Time: TweenTime;
+
+    /// Runs every axis with the same time. Axes which have finished hold their end value.
+    fn run_axes(&mut self, new_time: Self::Time) -> Self::Value;
+
+    /// The start and end values of all the axes together.
+    fn axes_range(&self) -> RangeInclusive<Self::Value>;
+
+    /// The duration of the longest axis.
+    fn longest_duration(&self) -> Self::Time;
+}
+
+/// Runs an axis, holding its end value once its duration has passed.
+fn run_axis<Tw: Tween>(axis: &mut Tw, new_time: Tw::Time) -> Tw::Value {
+    let duration = axis.duration();
+
+    axis.run(if new_time.is_complete(duration) {
+        duration
+    } else {
+        new_time
+    })
+}
+
+macro_rules! axes {
+    ($($name:ident $index:tt),+) => {
+        impl<T: TweenTime, $($name: Tween<Time = T>),+> Axes for ($($name,)+) {
+            type Value = ($($name::Value,)+);
+            type Time = T;
+
+            fn run_axes(&mut self, new_time: T) -> Self::Value {
+                ($(run_axis(&mut self.$index, new_time),)+)
+            }
+
+            fn axes_range(&self) -> RangeInclusive<Self::Value> {
+                ($(*self.$index.range().start(),)+)..=($(*self.$index.range().end(),)+)
+            }
+
+            fn longest_duration(&self) -> T {
+                let mut longest = T::ZERO;
+                $(
+                    let duration = self.$index.duration();
+                    if !longest.is_complete(duration) {
+                        longest = duration;
+                    }
+                )+
+
+                longest
+            }
+        }
+    };
+}
+
+axes!(A 0, B 1);
+axes!(A 0, B 1, C 2);
+axes!(A 0, B 1, C 2, D 3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BounceOut, Linear, QuadIn, Tweener};
+
+    #[test]
+    fn eases_each_axis() {
+        let mut bounce = BounceOut::new(0.0..=5.0, 4.0);
+        let mut tween = PerAxis::new((Linear::new(0.0..=8.0, 4.0), BounceOut::new(0.0..=5.0, 4.0)));
+
+        assert_eq!(tween.range(), (0.0, 0.0)..=(8.0, 5.0));
+        for time in 0..=4 {
+            let time = time as f32;
+            assert_eq!(tween.run(time), (time * 2.0, bounce.run(time)));
+        }
+    }
+
+    #[test]
+    fn shorter_axes_hold() {
+        let tween = PerAxis::new((
+            Linear::new(0..=10, 10),
+            Linear::new(0..=2, 2),
+            QuadIn::new(0.0..=1.0, 5),
+        ));
+        assert_eq!(tween.duration(), 10);
+
+        let mut tweener = Tweener::new(tween);
+        assert_eq!(tweener.update(5), Some((5, 2, 1.0)));
+        assert_eq!(tweener.update(5), Some((10, 2, 1.0)));
+        assert_eq!(tweener.update(5), None);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{Tween, TweenTime, TweenValue};
+use core::ops::RangeInclusive;
+
+/// A [TimeRemap] eases the *clock* of another tween, rather than its value.
+///
+/// Every time it is run, the given time is first fed through the `Ease` tween, which runs
+/// from zero to the inner tween's duration, and the *warped* time it outputs is what the
+/// inner tween is run with. This lets you, for example, add a slow-in to a tween which
+/// already has its own easing.
+///
+/// ```
+/// # use tween::{Linear, QuadIn, TimeRemap};
+/// // a linear tween, whose clock is eased by a quadratic in.
+/// let mut remapped = TimeRemap::new(Linear::new(0.0..=100.0, 10.0), QuadIn::new);
+///
+/// assert_eq!(remapped.run(0.0), 0.0);
+/// assert_eq!(remapped.run(5.0), 25.0);
+/// assert_eq!(remapped.run(10.0), 100.0);
+/// ```
+///
+/// Easings which overshoot, such as [BackOut](crate::BackOut), will run the inner tween
+/// outside of its duration.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TimeRemap<Ease, Tw> {
+    ease: Ease,
+    tween: Tw,
+}
+
+impl<Ease, Tw> TimeRemap<Ease, Tw>
+where
+    Tw: Tween,
+    Tw::Time: TweenValue,
+    Ease: Tween<Value = Tw::Time, Time = Tw::Time>,
+{
+    /// Creates a new [TimeRemap] out of a tween, and a constructor for the easing of its
+    /// clock. The constructor is given a range from zero to the tween's duration, and that
+    /// same duration, so any tween's `new` function, like `SineIn::new`, can be passed in
+    /// directly.
+    pub fn new(tween: Tw, ease: impl FnOnce(RangeInclusive<Tw::Time>, Tw::Time) -> Ease) -> Self {
+        let duration = tween.duration();
+        let ease = ease(<Tw::Time as TweenTime>::ZERO..=duration, duration);
+
+        Self { ease, tween }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: Tw::Time) -> Tw::Value {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// Allows inspection of the easing which warps the clock.
+    pub fn ease(&self) -> &Ease {
+        &self.ease
+    }
+
+    /// Allows inspection of the inner tween.
+    pub fn tween(&self) -> &Tw {
+        &self.tween
+    }
+}
+
+impl<Ease, Tw> Tween for TimeRemap<Ease, Tw>
+where
+    Tw: Tween,
+    Tw::Time: TweenValue,
+    Ease: Tween<Value = Tw::Time, Time = Tw::Time>,
+{
+    type Value = Tw::Value;
+    type Time = Tw::Time;
+
+    fn run(&mut self, new_time: Self::Time) -> Self::Value {
+        let warped_time = self.ease.run(new_time);
+
+        self.tween.run(warped_time)
+    }
+
+    fn range(&self) -> RangeInclusive<Self::Value> {
+        self.tween.range()
+    }
+
+    fn duration(&self) -> Self::Time {
+        self.tween.duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CubicIn, Linear, QuadIn, SineInOut, Tweener};
+    use approx::assert_ulps_eq;
+
+    #[test]
+    fn linear_remap_is_identity() {
+        let mut remapped = TimeRemap::new(CubicIn::new(0.0..=100.0, 10.0), Linear::new);
+        let mut cubic = CubicIn::new(0.0..=100.0, 10.0);
+
+        for time in 0..=10 {
+            let time = time as f64;
+            assert_ulps_eq!(remapped.run(time), cubic.run(time));
+        }
+    }
+
+    #[test]
+    fn nested_easing() {
+        let mut remapped = TimeRemap::new(QuadIn::new(0.0..=1.0, 1.0), SineInOut::new);
+
+        assert_ulps_eq!(remapped.run(0.5), 0.25);
+        assert_ulps_eq!(remapped.run(1.0), 1.0);
+    }
+
+    #[test]
+    fn drives_in_tweener() {
+        let mut tweener = Tweener::new(TimeRemap::new(Linear::new(0..=100, 10), QuadIn::new));
+
+        assert_eq!(tweener.update(5), Some(20));
+        assert_eq!(tweener.update(5), Some(100));
+        assert_eq!(tweener.update(5), None);
+    }
+}
@@ -0,0 +1,206 @@
+//! Imports the `<pathInterpolator>` resources of Android apps, so their animations can be
+//! ported without retuning their curves.
+//!
+//! ```
+//! # use tween::android;
+//! let xml = r#"
+//!     <?xml version="1.0" encoding="utf-8"?>
+//!     <pathInterpolator xmlns:android="http://schemas.android.com/apk/res/android"
+//!         android:controlX1="0.4"
+//!         android:controlY1="0"
+//!         android:controlX2="0.2"
+//!         android:controlY2="1" />
+//! "#;
+//!
+//! let bezier = android::path_interpolator(xml).unwrap();
+//! assert_eq!(bezier.control_points(), [0.4, 0.0, 0.2, 1.0]);
+//!
+//! let mut tween = bezier.tween(0.0f32..=1.0, 0.3f32);
+//! # let _ = tween.run(0.15);
+//! ```
+//!
+//! Only interpolators of control points are supported. An interpolator of `android:pathData`
+//! can draw any path, which no single bezier is, so it gives [AndroidError::PathData].
+
+use crate::CubicBezier;
+use core::fmt;
+
+/// Parses the XML of an Android `<pathInterpolator>`, or of the same attributes on a
+/// `<cubic-bezier>` element, into the bezier it eases by, which can be made into a tween with
+/// [CubicBezier::tween].
+///
+/// An interpolator with only `controlX1` and `controlY1` is a quadratic bezier, which is
+/// raised to the cubic bezier of the same curve.
+pub fn path_interpolator(xml: &str) -> Result<CubicBezier, AndroidError> {
+    let element = skip_prolog(xml).strip_prefix('<').ok_or(AndroidError::Unrecognized)?;
+    let attributes = ["pathInterpolator", "cubic-bezier"]
+        .into_iter()
+        .find_map(|name| {
+            element
+                .strip_prefix(name)
+                .filter(|rest| rest.starts_with(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>'))
+        })
+        .ok_or(AndroidError::Unrecognized)?;
+
+    let mut controls = [None; 4];
+    let mut rest = attributes;
+    loop {
+        rest = rest.trim_start();
+        if rest.starts_with("/>") || rest.starts_with('>') {
+            break;
+        }
+
+        let (name, value, after) = attribute(rest)?;
+        rest = after;
+
+        // attributes are almost always in the `android:` namespace, but the prefix is the
+        // document's to pick
+        let name = name.rsplit(':').next().unwrap_or(name);
+        let index = match name {
+            "controlX1" => 0,
+            "controlY1" => 1,
+            "controlX2" => 2,
+            "controlY2" => 3,
+            "pathData" => return Err(AndroidError::PathData),
+            _ => continue,
+        };
+
+        let number = value.trim().parse::<f64>().ok().filter(|number| number.is_finite());
+        controls[index] = Some(number.ok_or(AndroidError::InvalidNumber)?);
+    }
+
+    let [x1, y1, x2, y2] = controls;
+    let x1 = x1.ok_or(AndroidError::MissingAttribute { name: "controlX1" })?;
+    let y1 = y1.ok_or(AndroidError::MissingAttribute { name: "controlY1" })?;
+    let (x1, y1, x2, y2) = match (x2, y2) {
+        (Some(x2), Some(y2)) => (x1, y1, x2, y2),
+        // a quadratic bezier's control point is two thirds of the way to each cubic one
+        (None, None) => (
+            x1 * 2.0 / 3.0,
+            y1 * 2.0 / 3.0,
+            (1.0 + x1 * 2.0) / 3.0,
+            (1.0 + y1 * 2.0) / 3.0,
+        ),
+        (None, Some(_)) => return Err(AndroidError::MissingAttribute { name: "controlX2" }),
+        (Some(_), None) => return Err(AndroidError::MissingAttribute { name: "controlY2" }),
+    };
+
+    if !(0.0..=1.0).contains(&x1) || !(0.0..=1.0).contains(&x2) {
+        return Err(AndroidError::OutOfRange);
+    }
+
+    Ok(CubicBezier::new(x1, y1, x2, y2))
+}
+
+/// Skips the whitespace, XML declaration, and comments before the first element.
+fn skip_prolog(mut xml: &str) -> &str {
+    loop {
+        xml = xml.trim_start();
+        let end = if xml.starts_with("<?") {
+            xml.find("?>").map(|end| end + 2)
+        } else if xml.starts_with("<!--") {
+            xml.find("-->").map(|end| end + 3)
+        } else {
+            return xml;
+        };
+
+        match end {
+            Some(end) => xml = &xml[end..],
+            None => return "",
+        }
+    }
+}
+
+/// Splits the attribute at the start of `xml` into its name, its value, and what follows it.
+fn attribute(xml: &str) -> Result<(&str, &str, &str), AndroidError> {
+    let (name, rest) = xml.split_once('=').ok_or(AndroidError::Unclosed)?;
+    let rest = rest.trim_start();
+    let quote = rest
+        .chars()
+        .next()
+        .filter(|&quote| quote == '"' || quote == '\'')
+        .ok_or(AndroidError::Unclosed)?;
+
+    let (value, rest) = rest[1..].split_once(quote).ok_or(AndroidError::Unclosed)?;
+    Ok((name.trim(), value, rest))
+}
+
+/// Why an Android interpolator couldn't be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AndroidError {
+    /// The XML isn't a `<pathInterpolator>` or `<cubic-bezier>` element.
+    Unrecognized,
+    /// The element or one of its attributes isn't closed.
+    Unclosed,
+    /// A control point the interpolator needs isn't given.
+    MissingAttribute {
+        /// The name of the attribute, such as `"controlX1"`.
+        name: &'static str,
+    },
+    /// A control point isn't a number. Resource references, such as `@dimen/x1`, aren't
+    /// resolved.
+    InvalidNumber,
+    /// The x of a control point is outside of `0.0..=1.0`.
+    OutOfRange,
+    /// The interpolator is drawn by `android:pathData`, which is not supported.
+    PathData,
+}
+
+impl fmt::Display for AndroidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unrecognized => f.write_str("not a path interpolator"),
+            Self::Unclosed => f.write_str("the element isn't closed"),
+            Self::MissingAttribute { name } => write!(f, "the `{name}` attribute is missing"),
+            Self::InvalidNumber => f.write_str("a control point isn't a number"),
+            Self::OutOfRange => f.write_str("a control point's x is out of range"),
+            Self::PathData => f.write_str("interpolators of path data are not supported"),
+        }
+    }
+}
+
+impl core::error::Error for AndroidError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn control_points() {
+        let bezier = path_interpolator(
+            "<!-- fast out, slow in --><cubic-bezier app:controlX1='0.4' app:controlY1='0' \
+             app:controlX2='1' app:controlY2='1'/>",
+        );
+        assert_eq!(bezier.map(|bezier| bezier.control_points()), Ok([0.4, 0.0, 1.0, 1.0]));
+
+        let quadratic = path_interpolator(r#"<pathInterpolator android:controlX1="0.5" android:controlY1="1" />"#);
+        let [x1, y1, x2, y2] = quadratic.unwrap().control_points();
+        assert_eq!([x1, y1], [1.0 / 3.0, 2.0 / 3.0]);
+        assert_eq!([x2, y2], [2.0 / 3.0, 1.0]);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(path_interpolator("<set />"), Err(AndroidError::Unrecognized));
+        assert_eq!(
+            path_interpolator(r#"<pathInterpolator android:pathData="M 0,0 L 1,1" />"#),
+            Err(AndroidError::PathData)
+        );
+        assert_eq!(
+            path_interpolator(r#"<pathInterpolator android:controlY1="0" />"#),
+            Err(AndroidError::MissingAttribute { name: "controlX1" })
+        );
+        assert_eq!(
+            path_interpolator(r#"<pathInterpolator android:controlX1="@dimen/x" android:controlY1="0" />"#),
+            Err(AndroidError::InvalidNumber)
+        );
+        assert_eq!(
+            path_interpolator(r#"<pathInterpolator android:controlX1="2" android:controlY1="0" />"#),
+            Err(AndroidError::OutOfRange)
+        );
+        assert_eq!(
+            path_interpolator(r#"<pathInterpolator android:controlX1="0.5"#),
+            Err(AndroidError::Unclosed)
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use crate::{Ease, EaseVisitor, TweenTime, TweenValue};
+use core::ops::{Range, RangeInclusive};
+use std::vec::Vec;
+
+#[cfg(feature = "wide")]
+mod simd;
+
+/// A [TweenBank] stores many simple tweens in a structure of arrays, and updates them all
+/// in one tight loop, writing their values out into a slice.
+///
+/// Rather than a [Tweener](crate::Tweener) for each tween, the starts, deltas, durations,
+/// elapsed times, and easings of every tween are each kept in their own array, so a particle
+/// system or a long list of widgets updates thousands of tweens without a call per tween.
+/// Easings go through their [functions](crate::functions), in `f32`. Finished tweens hold
+/// their end values until the bank is [cleared](Self::clear).
+///
+/// The reciprocal of each duration is found when the tween is pushed, so finding how far
+/// through its duration a tween is takes a multiply in the update, rather than a divide.
+///
+/// Tweens are grouped by their easing as they're pushed, and each group is updated in its own
+/// loop with its easing inlined, so a scene of a few easings pays for no dispatch per tween.
+/// Only the custom easings of [push_fn](Self::push_fn) are called through a pointer.
+///
+/// ```
+/// # use tween::{Ease, TweenBank};
+/// let mut bank = TweenBank::new();
+/// bank.push(0.0..=10.0, 4.0, Ease::Linear);
+/// bank.push(0.0..=100.0, 2.0, Ease::QuadIn);
+///
+/// let mut values = [0.0; 2];
+/// bank.update_all(1.0, &mut values);
+/// assert_eq!(values, [2.5, 25.0]);
+///
+/// bank.update_all(2.0, &mut values);
+/// assert_eq!(values, [7.5, 100.0]);
+/// assert!(bank.is_finished(1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TweenBank<V, T> {
+    starts: Vec<V>,
+    deltas: Vec<V>,
+    durations: Vec<T>,
+    rates: Vec<f32>,
+    elapsed: Vec<T>,
+    /// The easing of each tween, or `None` for custom easings.
+    eases: Vec<Option<Ease>>,
+    /// The runs of tweens which share each easing, in the order of [Ease::ALL].
+    groups: Vec<Vec<Range<usize>>>,
+    /// The number of runs in every group.
+    runs: usize,
+    /// The tweens with custom easings, in the order they were pushed.
+    custom: Vec<Custom>,
+}
+
+/// The shortest average run of tweens with the same easing which is updated by easing.
+const MIN_RUN: usize = 4;
+
+/// A tween in a [TweenBank] with a custom easing.
+#[derive(Debug, Clone, Copy)]
+struct Custom {
+    index: usize,
+    function: fn(f32) -> f32,
+}
+
+impl PartialEq for Custom {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && core::ptr::fn_addr_eq(self.function, other.function)
+    }
+}
+
+impl<V, T> TweenBank<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Creates a new, empty [TweenBank].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new, empty [TweenBank], with room for `capacity` tweens.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            starts: Vec::with_capacity(capacity),
+            deltas: Vec::with_capacity(capacity),
+            durations: Vec::with_capacity(capacity),
+            rates: Vec::with_capacity(capacity),
+            elapsed: Vec::with_capacity(capacity),
+            eases: Vec::with_capacity(capacity),
+            groups: Vec::new(),
+            runs: 0,
+            custom: Vec::new(),
+        }
+    }
+
+    /// Adds a new tween to the bank, returning its index, which is where its value is
+    /// written in [update_all](Self::update_all).
+    pub fn push(&mut self, range: RangeInclusive<V>, duration: T, ease: Ease) -> usize {
+        let index = self.push_eased(range, duration, Some(ease));
+        if self.groups.is_empty() {
+            self.groups.resize(Ease::ALL.len(), Vec::new());
+        }
+        let runs = &mut self.groups[ease as usize];
+        match runs.last_mut() {
+            Some(run) if run.end == index => run.end += 1,
+            _ => {
+                runs.push(index..index + 1);
+                self.runs += 1;
+            }
+        }
+
+        index
+    }
+
+    /// Adds a new tween to the bank, which is eased by a custom function, returning its
+    /// index. These are called through a pointer, so they're slower than an [Ease].
+    pub fn push_fn(&mut self, range: RangeInclusive<V>, duration: T, ease: fn(f32) -> f32) -> usize {
+        let index = self.push_eased(range, duration, None);
+        self.custom.push(Custom { index, function: ease });
+
+        index
+    }
+
+    fn push_eased(&mut self, range: RangeInclusive<V>, duration: T, ease: Option<Ease>) -> usize {
+        let (start, end) = range.into_inner();
+
+        self.starts.push(start);
+        self.deltas.push(V::calculate_delta(end, start));
+        self.durations.push(duration);
+        self.rates.push((1.0 / duration.as_f64()) as f32);
+        self.elapsed.push(T::ZERO);
+        self.eases.push(ease);
+
+        self.starts.len() - 1
+    }
+
+    /// Drives every tween forward by `delta`, writing the value of each tween into `out`, at
+    /// its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` isn't the same length as the bank.
+    pub fn update_all(&mut self, delta: T, out: &mut [V]) {
+        assert_eq!(out.len(), self.len(), "the output must have a value for every tween");
+        profile_span!("TweenBank::update_all");
+        profile_count!("TweenBank::tweens", self.len());
+
+        for elapsed in &mut self.elapsed {
+            *elapsed = elapsed.add(delta);
+        }
+
+        // when easings are interleaved, each group would pass over all of the tweens in turn,
+        // so they're updated in order instead. The easing is picked once it's needed, which
+        // lets it be inlined
+        if self.runs * MIN_RUN > self.len() {
+            for (index, (out, ease)) in out.iter_mut().zip(&self.eases).enumerate() {
+                if let Some(ease) = ease {
+                    *out = self.value_with(index, |percent| ease.function()(percent));
+                }
+            }
+        } else {
+            for (&ease, runs) in Ease::ALL.iter().zip(&self.groups) {
+                ease.visit(Group {
+                    bank: self,
+                    runs,
+                    out: &mut *out,
+                });
+            }
+        }
+
+        for custom in &self.custom {
+            out[custom.index] = self.value_with(custom.index, custom.function);
+        }
+    }
+
+    /// The value of the tween at `index`, eased by `ease`.
+    fn value_with(&self, index: usize, ease: impl Fn(f32) -> f32) -> V {
+        eased(
+            self.starts[index],
+            self.deltas[index],
+            self.rates[index],
+            self.elapsed[index],
+            ease,
+        )
+    }
+
+    /// Returns `true` if the tween at `index` has finished.
+    pub fn is_finished(&self, index: usize) -> bool {
+        self.elapsed[index].is_complete(self.durations[index])
+    }
+
+    /// The number of tweens in the bank.
+    pub fn len(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Returns `true` if the bank has no tweens.
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    /// Removes every tween from the bank.
+    pub fn clear(&mut self) {
+        self.starts.clear();
+        self.deltas.clear();
+        self.durations.clear();
+        self.rates.clear();
+        self.elapsed.clear();
+        self.eases.clear();
+        self.groups.clear();
+        self.runs = 0;
+        self.custom.clear();
+    }
+}
+
+/// The value of a tween, `elapsed` into it.
+fn eased<V: TweenValue, T: TweenTime>(start: V, delta: V, rate: f32, elapsed: T, ease: impl Fn(f32) -> f32) -> V {
+    // a tween with no duration has an infinite rate, and so is `NaN` of the way through,
+    // which makes it finished too
+    let percent = elapsed.as_f64() as f32 * rate;
+    let scale = if percent < 1.0 { ease(percent) as f64 } else { 1.0 };
+
+    delta.scale(scale).add(start)
+}
+
+/// Updates the tweens of one easing, a run at a time, in a loop of their own.
+struct Group<'a, V, T> {
+    bank: &'a TweenBank<V, T>,
+    runs: &'a [Range<usize>],
+    out: &'a mut [V],
+}
+
+impl<V, T> EaseVisitor for Group<'_, V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    type Output = ();
+
+    fn visit<F: Fn(f32) -> f32>(self, function: F) {
+        let bank = self.bank;
+        for run in self.runs {
+            let tweens = bank.starts[run.clone()]
+                .iter()
+                .zip(&bank.deltas[run.clone()])
+                .zip(&bank.rates[run.clone()])
+                .zip(&bank.elapsed[run.clone()]);
+
+            for (out, (((&start, &delta), &rate), &elapsed)) in self.out[run.clone()].iter_mut().zip(tweens) {
+                *out = eased(start, delta, rate, elapsed, &function);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BounceOut, Tweener};
+
+    #[test]
+    fn matches_tweeners() {
+        let mut bank = TweenBank::with_capacity(3);
+        bank.push(0.0f32..=10.0, 10.0f32, Ease::BounceOut);
+        bank.push(5.0..=-5.0, 4.0, Ease::BounceOut);
+        bank.push(1.0..=2.0, 0.0, Ease::BounceOut);
+
+        let mut tweeners = [
+            Tweener::new(BounceOut::new(0.0f32..=10.0, 10.0f32)),
+            Tweener::new(BounceOut::new(5.0..=-5.0, 4.0)),
+        ];
+
+        let mut values = [0.0; 3];
+        for _ in 0..8 {
+            bank.update_all(1.0, &mut values);
+            for (tweener, value) in tweeners.iter_mut().zip(values) {
+                if let Some(expected) = tweener.update(1.0) {
+                    assert!((value - expected).abs() < 1e-5);
+                }
+            }
+        }
+
+        assert_eq!(values[1..], [-5.0, 2.0]);
+        assert!(!bank.is_finished(0));
+        assert!(bank.is_finished(2));
+    }
+
+    #[test]
+    fn grouped_and_interleaved() {
+        let eases = [Ease::QuadIn, Ease::SineOut, Ease::Linear];
+        let (mut grouped, mut interleaved) = (TweenBank::new(), TweenBank::new());
+        for i in 0..24 {
+            grouped.push(0.0f32..=i as f32, 2.0f32, eases[i / 8]);
+        }
+        for i in 0..24 {
+            interleaved.push(0.0f32..=i as f32, 2.0f32, eases[i / 8]);
+            interleaved.push_fn(0.0..=i as f32, 2.0, |t| t * t * t);
+        }
+
+        let (mut a, mut b) = ([0.0; 24], [0.0; 48]);
+        grouped.update_all(0.5, &mut a);
+        interleaved.update_all(0.5, &mut b);
+
+        for (i, value) in a.into_iter().enumerate() {
+            assert_eq!(b[i * 2], value);
+            assert_eq!(b[i * 2 + 1], i as f32 / 64.0);
+        }
+    }
+}
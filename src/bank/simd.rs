@@ -0,0 +1,172 @@
+use super::TweenBank;
+use crate::Ease;
+use wide::{CmpLt, f32x8};
+
+const LANES: usize = 8;
+
+impl TweenBank<f32, f32> {
+    /// Drives every tween forward by `delta`, like [update_all](Self::update_all), but
+    /// evaluates eight tweens at once with SIMD.
+    ///
+    /// Each run of eight tweens which share one of the polynomial easings, which are
+    /// [Ease::Linear] and the quadratic, cubic, quartic, and quintic easings, is eased in a
+    /// single `f32x8`. Every other tween falls back to the scalar path, so pushing tweens of
+    /// the same easing next to each other makes the most of this.
+    ///
+    /// ```
+    /// # use tween::{Ease, TweenBank};
+    /// let mut bank = TweenBank::new();
+    /// for i in 0..16 {
+    ///     bank.push(0.0..=i as f32, 2.0, Ease::CubicIn);
+    /// }
+    ///
+    /// let mut values = [0.0; 16];
+    /// bank.update_all_simd(1.0, &mut values);
+    /// assert_eq!(values[8], 1.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` isn't the same length as the bank.
+    pub fn update_all_simd(&mut self, delta: f32, out: &mut [f32]) {
+        assert_eq!(out.len(), self.len(), "the output must have a value for every tween");
+
+        for elapsed in &mut self.elapsed {
+            *elapsed += delta;
+        }
+
+        let lanes = self.len() / LANES * LANES;
+        for start in (0..lanes).step_by(LANES) {
+            let lanes = start..start + LANES;
+            let kind = self.eases[start];
+
+            let eased = match kind {
+                Some(ease) if self.eases[lanes.clone()].iter().all(|&other| other == kind) => {
+                    let elapsed = load(&self.elapsed[lanes.clone()]);
+                    let percent = elapsed * load(&self.rates[lanes.clone()]);
+                    // finished tweens, including those with no duration, are all the way through
+                    let percent = percent.cmp_lt(f32x8::ONE).blend(percent, f32x8::ONE);
+
+                    polynomial(ease, percent)
+                }
+                _ => None,
+            };
+
+            match eased {
+                Some(eased) => {
+                    let value = load(&self.deltas[lanes.clone()]) * eased + load(&self.starts[lanes.clone()]);
+                    out[lanes].copy_from_slice(&value.to_array());
+                }
+                None => {
+                    for index in lanes {
+                        out[index] = self.value(index);
+                    }
+                }
+            }
+        }
+
+        for (index, out) in out.iter_mut().enumerate().skip(lanes) {
+            *out = self.value(index);
+        }
+    }
+
+    /// The value of the tween at `index`, for the tweens which can't be done eight at a time.
+    fn value(&self, index: usize) -> f32 {
+        match self.eases[index] {
+            Some(ease) => self.value_with(index, ease.function()),
+            None => {
+                let custom = self.custom.binary_search_by_key(&index, |custom| custom.index).unwrap();
+                self.value_with(index, self.custom[custom].function)
+            }
+        }
+    }
+}
+
+fn load(lanes: &[f32]) -> f32x8 {
+    let lanes: [f32; LANES] = lanes.try_into().unwrap();
+    f32x8::from(lanes)
+}
+
+/// Eases eight percentages at once, for the easings which are just polynomials.
+fn polynomial(ease: Ease, t: f32x8) -> Option<f32x8> {
+    let one = f32x8::ONE;
+    let two = f32x8::splat(2.0);
+    let half = f32x8::HALF;
+
+    // the in and out halves of an in out easing, where `t` has been doubled
+    let in_out = |t: f32x8, ease_in: f32x8, ease_out: f32x8| t.cmp_lt(one).blend(ease_in, ease_out) * half;
+
+    let eased = match ease {
+        Ease::Linear => t,
+        Ease::QuadIn => t * t,
+        Ease::QuadOut => -t * (t - two),
+        Ease::QuadInOut => {
+            let t = t * two;
+            let u = t - one;
+            in_out(t, t * t, -(u * (u - two) - one))
+        }
+        Ease::CubicIn => t * t * t,
+        Ease::CubicOut => {
+            let u = t - one;
+            u * u * u + one
+        }
+        Ease::CubicInOut => {
+            let t = t * two;
+            let u = t - two;
+            in_out(t, t * t * t, u * u * u + two)
+        }
+        Ease::QuartIn => t * t * t * t,
+        Ease::QuartOut => {
+            let u = t - one;
+            one - u * u * u * u
+        }
+        Ease::QuartInOut => {
+            let t = t * two;
+            let u = t - two;
+            in_out(t, t * t * t * t, two - u * u * u * u)
+        }
+        Ease::QuintIn => t * t * t * t * t,
+        Ease::QuintOut => {
+            let u = t - one;
+            u * u * u * u * u + one
+        }
+        Ease::QuintInOut => {
+            let t = t * two;
+            let u = t - two;
+            in_out(t, t * t * t * t * t, u * u * u * u * u + two)
+        }
+        _ => return None,
+    };
+
+    Some(eased)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_scalar() {
+        let mut simd = TweenBank::new();
+        for (i, &ease) in Ease::ALL.iter().enumerate() {
+            // runs of eight of each easing, and then a mixed run
+            for lane in 0..LANES {
+                simd.push(0.0..=10.0, 4.0 + lane as f32, ease);
+            }
+            simd.push(i as f32..=-1.0, 3.0, ease);
+        }
+        simd.push(1.0..=2.0, 0.0, Ease::QuadIn);
+        let mut scalar = simd.clone();
+
+        let mut simd_values = std::vec![0.0; simd.len()];
+        let mut scalar_values = simd_values.clone();
+        for _ in 0..10 {
+            simd.update_all_simd(0.5, &mut simd_values);
+            scalar.update_all(0.5, &mut scalar_values);
+
+            for (simd, scalar) in simd_values.iter().zip(&scalar_values) {
+                assert!((simd - scalar).abs() < 1e-4, "{} != {}", simd, scalar);
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use bevy_color::{Color, LinearRgba, Oklaba, Srgba};
+use bevy_math::{DVec2, DVec3, DVec4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+/// Implements [TweenValue](crate::TweenValue) for a Bevy vector, which can be added,
+/// subtracted, and scaled by its own float type.
+macro_rules! bevy_value {
+    ($t:ty, $float:ty) => {
+        impl crate::TweenValue for $t {
+            const ZERO: Self = <$t>::ZERO;
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * scale as $float
+            }
+        }
+    };
+}
+
+bevy_value!(Vec2, f32);
+bevy_value!(Vec3, f32);
+bevy_value!(Vec3A, f32);
+bevy_value!(Vec4, f32);
+bevy_value!(DVec2, f64);
+bevy_value!(DVec3, f64);
+bevy_value!(DVec4, f64);
+
+/// Bevy's rotations are slerped along the shortest arc, exactly like a [Rotation](crate::Rotation),
+/// so they stay normalized and can go straight into a `Transform`.
+impl crate::TweenValue for Quat {
+    const ZERO: Self = Quat::IDENTITY;
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        let delta = destination * start.inverse();
+        if delta.w < 0.0 { -delta } else { delta }
+    }
+
+    fn add(self, other: Self) -> Self {
+        (self * other).normalize()
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        let (axis, angle) = self.to_axis_angle();
+        Quat::from_axis_angle(axis, angle * scale as f32)
+    }
+}
+
+impl crate::TweenValue for LinearRgba {
+    const ZERO: Self = LinearRgba::NONE;
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        LinearRgba::new(
+            destination.red - start.red,
+            destination.green - start.green,
+            destination.blue - start.blue,
+            destination.alpha - start.alpha,
+        )
+    }
+
+    fn add(self, other: Self) -> Self {
+        LinearRgba::new(
+            self.red + other.red,
+            self.green + other.green,
+            self.blue + other.blue,
+            self.alpha + other.alpha,
+        )
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        let scale = scale as f32;
+        LinearRgba::new(
+            self.red * scale,
+            self.green * scale,
+            self.blue * scale,
+            self.alpha * scale,
+        )
+    }
+}
+
+impl crate::TweenValue for Oklaba {
+    const ZERO: Self = Oklaba::new(0.0, 0.0, 0.0, 0.0);
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Oklaba::new(
+            destination.lightness - start.lightness,
+            destination.a - start.a,
+            destination.b - start.b,
+            destination.alpha - start.alpha,
+        )
+    }
+
+    fn add(self, other: Self) -> Self {
+        Oklaba::new(
+            self.lightness + other.lightness,
+            self.a + other.a,
+            self.b + other.b,
+            self.alpha + other.alpha,
+        )
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        let scale = scale as f32;
+        Oklaba::new(
+            self.lightness * scale,
+            self.a * scale,
+            self.b * scale,
+            self.alpha * scale,
+        )
+    }
+}
+
+/// sRGB colors are tweened in linear light, like [Srgba](crate::Srgba).
+impl crate::TweenValue for Srgba {
+    const ZERO: Self = Srgba::NONE;
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        LinearRgba::calculate_delta(destination.into(), start.into()).into()
+    }
+
+    fn add(self, other: Self) -> Self {
+        LinearRgba::from(self).add(other.into()).into()
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        LinearRgba::from(self).scale(scale).into()
+    }
+}
+
+/// Colors of any space are tweened in linear light, so tweened values are always
+/// [Color::LinearRgba]. Convert them into the space you need afterwards.
+impl crate::TweenValue for Color {
+    const ZERO: Self = Color::LinearRgba(LinearRgba::NONE);
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Color::LinearRgba(LinearRgba::calculate_delta(destination.into(), start.into()))
+    }
+
+    fn add(self, other: Self) -> Self {
+        Color::LinearRgba(LinearRgba::from(self).add(other.into()))
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        Color::LinearRgba(LinearRgba::from(self).scale(scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn vectors_and_rotations() {
+        let mut tween = Linear::new(Vec3::ZERO..=Vec3::new(2.0, 4.0, 8.0), 4.0);
+        assert_eq!(tween.run(1.0), Vec3::new(0.5, 1.0, 2.0));
+
+        let mut tween = Linear::new(Quat::IDENTITY..=Quat::from_rotation_z(2.0), 2.0);
+        let halfway = tween.run(1.0);
+        assert!(halfway.abs_diff_eq(Quat::from_rotation_z(1.0), 1e-6));
+        assert!(halfway.is_normalized());
+    }
+
+    #[test]
+    fn colors() {
+        let red = Color::srgb(1.0, 0.0, 0.0);
+        let green = Color::srgb(0.0, 1.0, 0.0);
+
+        let mut tween = Linear::new(red..=green, 2.0);
+        let halfway = Srgba::from(tween.run(1.0));
+        assert_abs_diff_eq!(halfway.red, crate::Srgba::from_hex(0xbcbc00ff).r, epsilon = 2e-3);
+
+        let mut tween = Linear::new(Srgba::RED..=Srgba::BLUE, 2.0);
+        assert_eq!(tween.run(2.0).to_hex(), Srgba::BLUE.to_hex());
+    }
+}
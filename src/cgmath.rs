@@ -0,0 +1,140 @@
+use cgmath::{EuclideanSpace, Point2, Point3, Quaternion, Vector2, Vector3, Vector4};
+
+/// Implements [TweenValue](crate::TweenValue) for the cgmath vectors and quaternions, which can
+/// be added, subtracted, and scaled by their own float type.
+macro_rules! cgmath_value {
+    ($t:ty, $zero:expr, $float:ty) => {
+        impl crate::TweenValue for $t {
+            const ZERO: Self = $zero;
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * scale as $float
+            }
+        }
+    };
+}
+
+/// Implements [TweenValue](crate::TweenValue) for a cgmath point. Points can't be added
+/// together, so the delta between two points is stored as a point holding the offset.
+macro_rules! cgmath_point {
+    ($t:ident, $zero:expr, $float:ty) => {
+        impl crate::TweenValue for $t<$float> {
+            const ZERO: Self = $zero;
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                $t::from_vec(destination - start)
+            }
+
+            fn add(self, other: Self) -> Self {
+                other + self.to_vec()
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * scale as $float
+            }
+        }
+    };
+}
+
+macro_rules! cgmath_values {
+    ($float:ty) => {
+        cgmath_value!(Vector2<$float>, Vector2 { x: 0.0, y: 0.0 }, $float);
+        cgmath_value!(
+            Vector3<$float>,
+            Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0
+            },
+            $float
+        );
+        cgmath_value!(
+            Vector4<$float>,
+            Vector4 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: 0.0
+            },
+            $float
+        );
+
+        cgmath_point!(Point2, Point2 { x: 0.0, y: 0.0 }, $float);
+        cgmath_point!(
+            Point3,
+            Point3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0
+            },
+            $float
+        );
+
+        // Quaternions are tweened component-wise, which is cheap, but means that the in-between
+        // values are generally *not* normalized. Normalize them before using them as rotations.
+        cgmath_value!(
+            Quaternion<$float>,
+            Quaternion {
+                v: Vector3 {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0
+                },
+                s: 0.0
+            },
+            $float
+        );
+    };
+}
+
+cgmath_values!(f32);
+cgmath_values!(f64);
+
+impl From<Quaternion<f32>> for crate::Rotation {
+    fn from(q: Quaternion<f32>) -> Self {
+        Self::from_xyzw(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+impl From<crate::Rotation> for Quaternion<f32> {
+    fn from(rotation: crate::Rotation) -> Self {
+        let [x, y, z, w] = rotation.to_xyzw();
+        Quaternion::new(w, x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Linear;
+    use cgmath::{Point2, Quaternion, Vector3, Vector4, Zero};
+
+    #[test]
+    fn vectors_and_points() {
+        let mut tween = Linear::new(Vector3::zero()..=Vector3::new(2.0, 4.0, 8.0), 4.0);
+        assert_eq!(tween.run(1.0), Vector3::new(0.5, 1.0, 2.0));
+
+        let mut tween = Linear::new(Vector4::zero()..=Vector4::new(1.0, 1.0, 1.0, 1.0), 2.0);
+        assert_eq!(tween.run(1.0), Vector4::new(0.5, 0.5, 0.5, 0.5));
+
+        let mut tween = Linear::new(Point2::new(1.0, 1.0)..=Point2::new(3.0, 5.0), 2.0);
+        assert_eq!(tween.run(1.0), Point2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn quaternions() {
+        let start = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+        let end = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+
+        let mut tween = Linear::new(start..=end, 2.0);
+        assert_eq!(tween.run(1.0), Quaternion::new(0.5, 0.5, 0.0, 0.0));
+        assert_eq!(tween.run(2.0), end);
+    }
+}
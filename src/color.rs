@@ -0,0 +1,45 @@
+mod hsv;
+mod hue;
+mod oklab;
+mod rgba;
+mod srgba;
+
+pub use hsv::{Hsla, Hsva};
+pub use hue::HuePath;
+pub(crate) use hue::{combined_path, hue_delta, hue_to_rgb, rgb_to_hue, wrap_hue};
+pub use oklab::{Oklab, Oklch};
+pub use rgba::Rgba;
+pub use srgba::Srgba;
+
+use crate::math::powf;
+
+/// Decodes an sRGB-encoded component into linear light. Negative components are mirrored, so
+/// this is defined (and invertible) for every float, which tween deltas rely upon.
+pub(crate) fn srgb_to_linear(encoded: f32) -> f32 {
+    let magnitude = encoded.abs();
+    let linear = if magnitude <= 0.04045 {
+        magnitude / 12.92
+    } else {
+        powf((magnitude + 0.055) / 1.055, 2.4)
+    };
+
+    linear.copysign(encoded)
+}
+
+/// Encodes a linear component with the sRGB transfer function. This is the inverse of
+/// [srgb_to_linear].
+pub(crate) fn linear_to_srgb(linear: f32) -> f32 {
+    let magnitude = linear.abs();
+    let encoded = if magnitude <= 0.0031308 {
+        magnitude * 12.92
+    } else {
+        1.055 * powf(magnitude, 1.0 / 2.4) - 0.055
+    };
+
+    encoded.copysign(linear)
+}
+
+/// Converts a normalized component into a byte, clamping it into range.
+pub(crate) fn to_byte(v: f32) -> u8 {
+    (v.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
@@ -0,0 +1,204 @@
+use super::{HuePath, Srgba, combined_path, hue_delta, hue_to_rgb, rgb_to_hue, wrap_hue};
+use crate::TweenValue;
+
+macro_rules! hue_color {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident { $second:ident, $third:ident }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        pub struct $name {
+            /// The hue, in degrees. Tweened values are always in `0.0..360.0`.
+            pub hue: f32,
+            #[doc = concat!("The ", stringify!($second), ", in `0.0..=1.0`.")]
+            pub $second: f32,
+            #[doc = concat!("The ", stringify!($third), ", in `0.0..=1.0`.")]
+            pub $third: f32,
+            /// The alpha, in `0.0..=1.0`.
+            pub alpha: f32,
+            /// `None` for deltas, which are never wrapped.
+            path: Option<HuePath>,
+        }
+
+        impl $name {
+            /// Creates a new color, which takes the shorter path around the color wheel.
+            pub const fn new(hue: f32, $second: f32, $third: f32, alpha: f32) -> Self {
+                Self {
+                    hue,
+                    $second,
+                    $third,
+                    alpha,
+                    path: Some(HuePath::Shorter),
+                }
+            }
+
+            /// Sets which way around the color wheel tweens to or from this color go.
+            /// If either end of a tween asks for [HuePath::Longer], the longer path is taken.
+            pub const fn with_hue_path(mut self, path: HuePath) -> Self {
+                self.path = Some(path);
+                self
+            }
+
+            /// The path around the color wheel tweens to or from this color will take.
+            pub fn hue_path(&self) -> HuePath {
+                self.path.unwrap_or_default()
+            }
+        }
+
+        impl TweenValue for $name {
+            const ZERO: Self = Self {
+                hue: 0.0,
+                $second: 0.0,
+                $third: 0.0,
+                alpha: 0.0,
+                path: None,
+            };
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                let hue = match combined_path(destination.path, start.path) {
+                    Some(path) => hue_delta(destination.hue, start.hue, path),
+                    None => destination.hue - start.hue,
+                };
+
+                Self {
+                    hue,
+                    $second: destination.$second - start.$second,
+                    $third: destination.$third - start.$third,
+                    alpha: destination.alpha - start.alpha,
+                    path: None,
+                }
+            }
+
+            fn add(self, other: Self) -> Self {
+                let path = self.path.or(other.path);
+                let hue = self.hue + other.hue;
+
+                Self {
+                    hue: if path.is_some() { wrap_hue(hue) } else { hue },
+                    $second: self.$second + other.$second,
+                    $third: self.$third + other.$third,
+                    alpha: self.alpha + other.alpha,
+                    path,
+                }
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                let scale = scale as f32;
+
+                Self {
+                    hue: self.hue * scale,
+                    $second: self.$second * scale,
+                    $third: self.$third * scale,
+                    alpha: self.alpha * scale,
+                    path: self.path,
+                }
+            }
+        }
+    };
+}
+
+hue_color!(
+    /// A color in the HSV (hue, saturation, value) model, tweened around the color wheel
+    /// rather than straight through the rgb cube, which keeps hue shifts saturated.
+    ///
+    /// The hue takes the shortest arc by default. Use [Hsva::with_hue_path] for the long way.
+    ///
+    /// ```
+    /// # use tween::{HuePath, Hsva, Linear};
+    /// let mut tween = Linear::new(Hsva::new(350.0, 1.0, 1.0, 1.0)..=Hsva::new(30.0, 1.0, 1.0, 1.0), 4.0);
+    /// assert_eq!(tween.run(1.0).hue, 0.0);
+    ///
+    /// let the_long_way = Hsva::new(30.0, 1.0, 1.0, 1.0).with_hue_path(HuePath::Longer);
+    /// let mut tween = Linear::new(Hsva::new(350.0, 1.0, 1.0, 1.0)..=the_long_way, 4.0);
+    /// assert_eq!(tween.run(2.0).hue, 190.0);
+    /// ```
+    pub struct Hsva { saturation, value }
+);
+
+hue_color!(
+    /// A color in the HSL (hue, saturation, lightness) model, tweened around the color wheel
+    /// just like [Hsva].
+    ///
+    /// The hue takes the shortest arc by default. Use [Hsla::with_hue_path] for the long way.
+    pub struct Hsla { saturation, lightness }
+);
+
+impl From<Srgba> for Hsva {
+    fn from(color: Srgba) -> Self {
+        let rgb = [color.r, color.g, color.b];
+        let max = color.r.max(color.g).max(color.b);
+        let chroma = max - color.r.min(color.g).min(color.b);
+
+        let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+        Self::new(rgb_to_hue(rgb, max, chroma), saturation, max, color.a)
+    }
+}
+
+impl From<Hsva> for Srgba {
+    fn from(color: Hsva) -> Self {
+        let chroma = color.value * color.saturation;
+        let [r, g, b] = hue_to_rgb(color.hue, chroma, color.value - chroma);
+
+        Srgba::new(r, g, b, color.alpha)
+    }
+}
+
+impl From<Srgba> for Hsla {
+    fn from(color: Srgba) -> Self {
+        let rgb = [color.r, color.g, color.b];
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.0;
+
+        let saturation = if chroma == 0.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        Self::new(rgb_to_hue(rgb, max, chroma), saturation, lightness, color.a)
+    }
+}
+
+impl From<Hsla> for Srgba {
+    fn from(color: Hsla) -> Self {
+        let chroma = (1.0 - (2.0 * color.lightness - 1.0).abs()) * color.saturation;
+        let [r, g, b] = hue_to_rgb(color.hue, chroma, color.lightness - chroma / 2.0);
+
+        Srgba::new(r, g, b, color.alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BounceIn, Linear, SineIn};
+
+    #[test]
+    fn srgba_round_trip() {
+        for hex in [0xff0000ff, 0x00ff0080, 0x2040e0ff, 0xf0a01000, 0x808080ff] {
+            let color = Srgba::from_hex(hex);
+            assert_eq!(Srgba::from(Hsva::from(color)).to_hex(), hex);
+            assert_eq!(Srgba::from(Hsla::from(color)).to_hex(), hex);
+        }
+    }
+
+    #[test]
+    fn hue_paths() {
+        let start = Hsla::new(300.0, 1.0, 0.5, 1.0);
+        let end = Hsla::new(60.0, 1.0, 0.5, 1.0);
+
+        let mut tween = Linear::new(start..=end, 4.0);
+        assert_eq!(tween.run(3.0).hue, 30.0);
+
+        let end = end.with_hue_path(HuePath::Longer);
+        let mut tween = Linear::new(start..=end, 4.0);
+        assert_eq!(tween.run(1.0).hue, 240.0);
+        assert_eq!(tween.run(4.0).hue, 60.0);
+
+        // these easings combine deltas together, which must not be wrapped
+        assert_eq!(SineIn::new(start..=end, 4.0).run(4.0).hue, 60.0);
+        assert_eq!(BounceIn::new(start..=end, 4.0).run(4.0).hue, 60.0);
+    }
+}
@@ -0,0 +1,88 @@
+/// Which way around the color wheel a hue is tweened. This matches the `shorter` and `longer`
+/// hue interpolation methods of CSS Color 4.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub enum HuePath {
+    /// Takes the shortest arc between the two hues, wrapping around `360°` if that is shorter.
+    /// A tween from `350°` to `10°` passes through `0°`, not through `180°`.
+    #[default]
+    Shorter,
+    /// Takes the longest arc between the two hues. Two equal hues make a full turn.
+    Longer,
+}
+
+/// Wraps a hue into `0.0..360.0`.
+pub(crate) fn wrap_hue(degrees: f32) -> f32 {
+    let hue = degrees % 360.0;
+    if hue < 0.0 { hue + 360.0 } else { hue }
+}
+
+/// Finds the signed distance, in degrees, to travel from `start` to `destination`
+/// along the given path.
+pub(crate) fn hue_delta(destination: f32, start: f32, path: HuePath) -> f32 {
+    let mut delta = (destination - start) % 360.0;
+    if delta > 180.0 {
+        delta -= 360.0;
+    } else if delta <= -180.0 {
+        delta += 360.0;
+    }
+
+    match path {
+        HuePath::Shorter => delta,
+        HuePath::Longer if delta > 0.0 => delta - 360.0,
+        HuePath::Longer => delta + 360.0,
+    }
+}
+
+/// Picks the path for a tween between two ends. The tween takes the longer path if either end
+/// asks for it. `None` marks a delta, and two deltas are combined without any wrapping.
+pub(crate) fn combined_path(a: Option<HuePath>, b: Option<HuePath>) -> Option<HuePath> {
+    match (a, b) {
+        (None, None) => None,
+        (Some(HuePath::Longer), _) | (_, Some(HuePath::Longer)) => Some(HuePath::Longer),
+        _ => Some(HuePath::Shorter),
+    }
+}
+
+/// Finds the hue, in degrees, of an rgb color given its largest component and its chroma.
+pub(crate) fn rgb_to_hue([r, g, b]: [f32; 3], max: f32, chroma: f32) -> f32 {
+    if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        wrap_hue(60.0 * ((g - b) / chroma))
+    } else if max == g {
+        60.0 * ((b - r) / chroma + 2.0)
+    } else {
+        60.0 * ((r - g) / chroma + 4.0)
+    }
+}
+
+/// Converts a hue, chroma, and lightness offset back into rgb.
+pub(crate) fn hue_to_rgb(hue: f32, chroma: f32, offset: f32) -> [f32; 3] {
+    let sector = wrap_hue(hue) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+    let [r, g, b] = match sector as u32 {
+        0 => [chroma, x, 0.0],
+        1 => [x, chroma, 0.0],
+        2 => [0.0, chroma, x],
+        3 => [0.0, x, chroma],
+        4 => [x, 0.0, chroma],
+        _ => [chroma, 0.0, x],
+    };
+
+    [r + offset, g + offset, b + offset]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deltas() {
+        assert_eq!(hue_delta(10.0, 350.0, HuePath::Shorter), 20.0);
+        assert_eq!(hue_delta(350.0, 10.0, HuePath::Shorter), -20.0);
+        assert_eq!(hue_delta(10.0, 350.0, HuePath::Longer), -340.0);
+        assert_eq!(hue_delta(90.0, 90.0, HuePath::Longer), 360.0);
+        assert_eq!(wrap_hue(-20.0), 340.0);
+    }
+}
@@ -0,0 +1,255 @@
+use super::{HuePath, Rgba, combined_path, hue_delta, wrap_hue};
+use crate::{
+    TweenValue,
+    math::{atan2, cbrt, sin_cos, sqrt},
+};
+
+/// A color in the OKLab space, which is perceptually uniform: equal steps in a tween *look*
+/// like equal steps, and the lightness doesn't dip or bulge along the way. This is the
+/// `oklab` interpolation space of CSS Color 4.
+///
+/// Convert from and to a linear [Rgba] to use it.
+///
+/// ```
+/// # use tween::{Linear, Oklab, Rgba, Srgba};
+/// let start = Oklab::from(Srgba::from_hex(0x0000ffff).to_linear());
+/// let end = Oklab::from(Srgba::from_hex(0xffffffff).to_linear());
+///
+/// let mut tween = Linear::new(start..=end, 2.0);
+/// let halfway = Srgba::from(Rgba::from(tween.run(1.0)));
+/// assert_eq!(halfway.to_hex(), 0x74a3ffff);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Oklab {
+    /// The perceived lightness, in `0.0..=1.0`.
+    pub l: f32,
+    /// How green (negative) or red (positive) the color is.
+    pub a: f32,
+    /// How blue (negative) or yellow (positive) the color is.
+    pub b: f32,
+    /// The alpha, in `0.0..=1.0`.
+    pub alpha: f32,
+}
+
+impl Oklab {
+    /// Creates a new color.
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+}
+
+impl From<Rgba> for Oklab {
+    fn from(color: Rgba) -> Self {
+        let l = cbrt(0.41222147 * color.r + 0.53633254 * color.g + 0.05144599 * color.b);
+        let m = cbrt(0.2119035 * color.r + 0.6806995 * color.g + 0.10739696 * color.b);
+        let s = cbrt(0.08830246 * color.r + 0.28171884 * color.g + 0.6299787 * color.b);
+
+        Self::new(
+            0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+            1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+            0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+            color.a,
+        )
+    }
+}
+
+impl From<Oklab> for Rgba {
+    fn from(color: Oklab) -> Self {
+        let l = color.l + 0.39633778 * color.a + 0.21580376 * color.b;
+        let m = color.l - 0.105561346 * color.a - 0.06385417 * color.b;
+        let s = color.l - 0.08948418 * color.a - 1.2914855 * color.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        Rgba::new(
+            4.0767417 * l - 3.3077116 * m + 0.23096994 * s,
+            -1.268438 * l + 2.6097574 * m - 0.34131938 * s,
+            -0.0041960863 * l - 0.7034186 * m + 1.7076147 * s,
+            color.alpha,
+        )
+    }
+}
+
+impl TweenValue for Oklab {
+    const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Self::new(
+            destination.l - start.l,
+            destination.a - start.a,
+            destination.b - start.b,
+            destination.alpha - start.alpha,
+        )
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.l + other.l,
+            self.a + other.a,
+            self.b + other.b,
+            self.alpha + other.alpha,
+        )
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        let scale = scale as f32;
+        Self::new(self.l * scale, self.a * scale, self.b * scale, self.alpha * scale)
+    }
+}
+
+/// The polar form of [Oklab], which tweens its hue around the color wheel like [Hsva](crate::Hsva)
+/// does, but with a perceptually uniform lightness and chroma. This is the `oklch`
+/// interpolation space of CSS Color 4.
+///
+/// The hue takes the shortest arc by default. Use [Oklch::with_hue_path] for the long way.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Oklch {
+    /// The perceived lightness, in `0.0..=1.0`.
+    pub lightness: f32,
+    /// The chroma, from `0.0` for grays up to roughly `0.4` for the most vivid colors.
+    pub chroma: f32,
+    /// The hue, in degrees. Tweened values are always in `0.0..360.0`.
+    pub hue: f32,
+    /// The alpha, in `0.0..=1.0`.
+    pub alpha: f32,
+    /// `None` for deltas, which are never wrapped.
+    path: Option<HuePath>,
+}
+
+impl Oklch {
+    /// Creates a new color, which takes the shorter path around the color wheel.
+    pub const fn new(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Self {
+        Self {
+            lightness,
+            chroma,
+            hue,
+            alpha,
+            path: Some(HuePath::Shorter),
+        }
+    }
+
+    /// Sets which way around the color wheel tweens to or from this color go.
+    /// If either end of a tween asks for [HuePath::Longer], the longer path is taken.
+    pub const fn with_hue_path(mut self, path: HuePath) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// The path around the color wheel tweens to or from this color will take.
+    pub fn hue_path(&self) -> HuePath {
+        self.path.unwrap_or_default()
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(color: Oklab) -> Self {
+        let hue = atan2(color.b, color.a).to_degrees();
+        let chroma = sqrt(color.a * color.a + color.b * color.b);
+
+        Self::new(color.l, chroma, wrap_hue(hue), color.alpha)
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(color: Oklch) -> Self {
+        let (sin, cos) = sin_cos(color.hue.to_radians());
+
+        Self::new(color.lightness, color.chroma * cos, color.chroma * sin, color.alpha)
+    }
+}
+
+impl From<Rgba> for Oklch {
+    fn from(color: Rgba) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl From<Oklch> for Rgba {
+    fn from(color: Oklch) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl TweenValue for Oklch {
+    const ZERO: Self = Self {
+        lightness: 0.0,
+        chroma: 0.0,
+        hue: 0.0,
+        alpha: 0.0,
+        path: None,
+    };
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        let hue = match combined_path(destination.path, start.path) {
+            Some(path) => hue_delta(destination.hue, start.hue, path),
+            None => destination.hue - start.hue,
+        };
+
+        Self {
+            lightness: destination.lightness - start.lightness,
+            chroma: destination.chroma - start.chroma,
+            hue,
+            alpha: destination.alpha - start.alpha,
+            path: None,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        let path = self.path.or(other.path);
+        let hue = self.hue + other.hue;
+
+        Self {
+            lightness: self.lightness + other.lightness,
+            chroma: self.chroma + other.chroma,
+            hue: if path.is_some() { wrap_hue(hue) } else { hue },
+            alpha: self.alpha + other.alpha,
+            path,
+        }
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        let scale = scale as f32;
+
+        Self {
+            lightness: self.lightness * scale,
+            chroma: self.chroma * scale,
+            hue: self.hue * scale,
+            alpha: self.alpha * scale,
+            path: self.path,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Srgba};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn round_trips() {
+        for hex in [0xff0000ff, 0x00ff0080, 0x2040e0ff, 0xf0a01000, 0x808080ff] {
+            let color = Srgba::from_hex(hex).to_linear();
+            assert_eq!(Srgba::from(Rgba::from(Oklab::from(color))).to_hex(), hex);
+            assert_eq!(Srgba::from(Rgba::from(Oklch::from(color))).to_hex(), hex);
+        }
+
+        // white is `L = 1` with no chroma
+        let white = Oklch::from(Rgba::new(1.0, 1.0, 1.0, 1.0));
+        assert_abs_diff_eq!(white.lightness, 1.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(white.chroma, 0.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn oklch_hue_paths() {
+        let start = Oklch::new(0.7, 0.1, 340.0, 1.0);
+        let end = Oklch::new(0.5, 0.2, 20.0, 1.0);
+
+        let mut tween = Linear::new(start..=end, 4.0);
+        let halfway = tween.run(2.0);
+        assert_abs_diff_eq!(halfway.hue, 0.0);
+        assert_abs_diff_eq!(halfway.lightness, 0.6);
+
+        let mut tween = Linear::new(start..=end.with_hue_path(HuePath::Longer), 4.0);
+        assert_abs_diff_eq!(tween.run(2.0).hue, 180.0);
+    }
+}
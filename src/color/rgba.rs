@@ -0,0 +1,114 @@
+use super::{linear_to_srgb, srgb_to_linear, to_byte};
+use crate::TweenValue;
+
+/// A color with its components in *linear light*, which is the space colors should be blended
+/// in. Lerping sRGB bytes directly, which is what most colors on disk and in hex codes are,
+/// produces dark and muddy midpoints -- a tween from red to green passes through brown.
+///
+/// Converting from `[u8; 4]` decodes the bytes as sRGB, and converting back encodes them again,
+/// so you can keep your colors as bytes and only tween them as an `Rgba`.
+///
+/// ```
+/// # use tween::{Linear, Rgba};
+/// let red = Rgba::from([255, 0, 0, 255]);
+/// let green = Rgba::from([0, 255, 0, 255]);
+///
+/// let mut tween = Linear::new(red..=green, 2.0);
+/// let halfway: [u8; 4] = tween.run(1.0).into();
+///
+/// // naive byte lerping would give us `[128, 128, 0, 255]`.
+/// assert_eq!(halfway, [188, 188, 0, 255]);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Rgba {
+    /// The red component, in linear light.
+    pub r: f32,
+    /// The green component, in linear light.
+    pub g: f32,
+    /// The blue component, in linear light.
+    pub b: f32,
+    /// The alpha component. Alpha is never gamma encoded.
+    pub a: f32,
+}
+
+impl Rgba {
+    /// Creates a new color from linear components.
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a new color from sRGB-encoded components in `0.0..=1.0`, decoding them into
+    /// linear light.
+    pub fn from_srgb(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self::new(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a)
+    }
+
+    /// Encodes this color back into sRGB components, in the order `[r, g, b, a]`.
+    pub fn to_srgb(self) -> [f32; 4] {
+        [
+            linear_to_srgb(self.r),
+            linear_to_srgb(self.g),
+            linear_to_srgb(self.b),
+            self.a,
+        ]
+    }
+}
+
+impl From<[u8; 4]> for Rgba {
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        Self::from_srgb(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0)
+    }
+}
+
+impl From<Rgba> for [u8; 4] {
+    fn from(color: Rgba) -> Self {
+        color.to_srgb().map(to_byte)
+    }
+}
+
+impl TweenValue for Rgba {
+    const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Self::new(
+            destination.r - start.r,
+            destination.g - start.g,
+            destination.b - start.b,
+            destination.a - start.a,
+        )
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.r + other.r, self.g + other.g, self.b + other.b, self.a + other.a)
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        let scale = scale as f32;
+        Self::new(self.r * scale, self.g * scale, self.b * scale, self.a * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[test]
+    fn bytes_round_trip() {
+        for v in 0..=255 {
+            let bytes = [v, 255 - v, v / 2, v];
+            assert_eq!(<[u8; 4]>::from(Rgba::from(bytes)), bytes);
+        }
+    }
+
+    #[test]
+    fn tweens_in_linear_light() {
+        let black = Rgba::from([0, 0, 0, 0]);
+        let white = Rgba::from([255, 255, 255, 255]);
+
+        let mut tween = Linear::new(black..=white, 2.0);
+        let halfway = tween.run(1.0);
+        assert_eq!(halfway, Rgba::new(0.5, 0.5, 0.5, 0.5));
+        assert_eq!(<[u8; 4]>::from(halfway), [188, 188, 188, 128]);
+    }
+}
@@ -0,0 +1,123 @@
+use super::{Rgba, to_byte};
+use crate::TweenValue;
+
+/// A color with sRGB-encoded components, as found in hex codes, CSS, and most image formats.
+///
+/// Unlike tweening the encoded components directly, an `Srgba` is decoded into linear light
+/// for every step of the tween and re-encoded afterwards, so its midpoints are the gamma-correct
+/// [Rgba] midpoints, without you having to convert anything yourself.
+///
+/// ```
+/// # use tween::{Linear, Srgba};
+/// let mut tween = Linear::new(Srgba::from_hex(0xff0000ff)..=Srgba::from_hex(0x00ff00ff), 2.0);
+///
+/// assert_eq!(tween.run(1.0).to_hex(), 0xbcbc00ff);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Srgba {
+    /// The red component, sRGB-encoded.
+    pub r: f32,
+    /// The green component, sRGB-encoded.
+    pub g: f32,
+    /// The blue component, sRGB-encoded.
+    pub b: f32,
+    /// The alpha component. Alpha is never gamma encoded.
+    pub a: f32,
+}
+
+impl Srgba {
+    /// Creates a new color from sRGB-encoded components.
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a new color from a hex code in the form `0xRRGGBBAA`.
+    pub fn from_hex(hex: u32) -> Self {
+        hex.to_be_bytes().into()
+    }
+
+    /// Converts this color into a hex code in the form `0xRRGGBBAA`.
+    pub fn to_hex(self) -> u32 {
+        u32::from_be_bytes(self.into())
+    }
+
+    /// Decodes this color into linear light.
+    pub fn to_linear(self) -> Rgba {
+        self.into()
+    }
+}
+
+impl From<[u8; 4]> for Srgba {
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        Self::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0)
+    }
+}
+
+impl From<Srgba> for [u8; 4] {
+    fn from(color: Srgba) -> Self {
+        [color.r, color.g, color.b, color.a].map(to_byte)
+    }
+}
+
+impl From<Srgba> for Rgba {
+    fn from(color: Srgba) -> Self {
+        Rgba::from_srgb(color.r, color.g, color.b, color.a)
+    }
+}
+
+impl From<Rgba> for Srgba {
+    fn from(color: Rgba) -> Self {
+        let [r, g, b, a] = color.to_srgb();
+        Self::new(r, g, b, a)
+    }
+}
+
+// Every operation is done on the linear color and encoded again, so the tweens, which only
+// ever combine values through these three functions, are effectively tweening an `Rgba`.
+// The transfer functions are defined for negative components too, so deltas survive the trip.
+impl TweenValue for Srgba {
+    const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Rgba::calculate_delta(destination.into(), start.into()).into()
+    }
+
+    fn add(self, other: Self) -> Self {
+        Rgba::from(self).add(other.into()).into()
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        Rgba::from(self).scale(scale).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, QuadInOut, Tween};
+    use approx::assert_ulps_eq;
+
+    #[test]
+    fn hex_round_trip() {
+        assert_eq!(Srgba::from_hex(0x12345678).to_hex(), 0x12345678);
+        assert_ulps_eq!(Srgba::from_hex(0xff8000ff).to_linear().to_srgb()[1], 128.0 / 255.0);
+    }
+
+    #[test]
+    fn tweens_match_linear_tweens() {
+        let start = Srgba::from_hex(0x2040e0ff);
+        let end = Srgba::from_hex(0xf0a01080);
+
+        let mut encoded = QuadInOut::new(start..=end, 10.0);
+        let mut linear = QuadInOut::new(start.to_linear()..=end.to_linear(), 10.0);
+
+        for time in 0..=10 {
+            let time = time as f64;
+            let ours: [u8; 4] = encoded.run(time).into();
+            let theirs: [u8; 4] = linear.run(time).into();
+
+            assert_eq!(ours, theirs);
+        }
+        assert_eq!(Linear::new(start..=end, 1.0).run(1.0).to_hex(), 0xf0a01080);
+    }
+}
@@ -0,0 +1,231 @@
+//! Animations described as data, so they can be loaded from a file and tweaked by designers
+//! without recompiling.
+//!
+//! A [ClipDef] is a named animation with a [TrackDef] for each property it moves, and each track
+//! is a list of [KeyDef]s, the values the property passes through and when. With the `serde`
+//! feature, these can be loaded from RON, JSON, or any other format serde supports:
+//!
+//! ```json
+//! {
+//!     "name": "pop_in",
+//!     "tracks": [
+//!         { "target": "scale", "keys": [
+//!             { "time": 0.0, "value": 0.0 },
+//!             { "time": 0.3, "value": 1.2, "ease": "QuadOut" },
+//!             { "time": 0.5, "value": 1.0, "ease": "SineInOut" }
+//!         ] },
+//!         { "target": "opacity", "keys": [
+//!             { "time": 0.0, "value": 0.0 },
+//!             { "time": 0.2, "value": 1.0 }
+//!         ] }
+//!     ]
+//! }
+//! ```
+//!
+//! A key's easing is how it's eased into from the key before it, which is [Ease::Linear] if
+//! it's left out. Once loaded, a clip is [instantiated](ClipDef::instantiate) into a
+//! [TweenManager], with a property for each track, which plays it.
+//!
+//! ```
+//! # use tween::{definitions::{ClipDef, KeyDef, TrackDef}, Ease};
+//! let clip = ClipDef {
+//!     name: "fade".into(),
+//!     tracks: vec![TrackDef {
+//!         target: "opacity".into(),
+//!         keys: vec![KeyDef::new(1.0, 0.0), KeyDef::new(3.0, 1.0).eased(Ease::QuadIn)],
+//!     }],
+//! };
+//!
+//! let mut manager = clip.instantiate().unwrap();
+//! manager.update(2.0);
+//! assert_eq!(manager.get(&"opacity".into()), Some(0.25));
+//! ```
+
+use crate::{Ease, Eased, Sequence, TweenManager, TweenTime, TweenValue};
+use core::fmt;
+use std::{string::String, vec::Vec};
+
+/// An animation, made of a track for each of the properties it moves.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClipDef<V, T> {
+    /// The name of the clip.
+    pub name: String,
+    /// A track for each property which the clip moves.
+    pub tracks: Vec<TrackDef<V, T>>,
+}
+
+/// The keys which one property of a [ClipDef] passes through.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackDef<V, T> {
+    /// The property this track moves, which is its key in the [TweenManager] the clip is
+    /// instantiated into.
+    pub target: String,
+    /// The keys of the track, in order of their times.
+    pub keys: Vec<KeyDef<V, T>>,
+}
+
+/// A value which a [TrackDef] passes through at a given time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyDef<V, T> {
+    /// When the track reaches this key, from the start of the clip.
+    pub time: T,
+    /// The value of the track at this key.
+    pub value: V,
+    /// How the track is eased into this key from the key before it.
+    #[cfg_attr(feature = "serde", serde(default = "linear"))]
+    pub ease: Ease,
+}
+
+#[cfg(feature = "serde")]
+fn linear() -> Ease {
+    Ease::Linear
+}
+
+/// Why a [TrackDef] couldn't be instantiated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefinitionError {
+    /// The track has no keys.
+    NoKeys {
+        /// The target of the track.
+        target: String,
+    },
+    /// A key of the track is at an earlier time than the key before it.
+    UnorderedKeys {
+        /// The target of the track.
+        target: String,
+        /// The index of the key which is out of order.
+        index: usize,
+    },
+}
+
+impl fmt::Display for DefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoKeys { target } => write!(f, "the track `{target}` has no keys"),
+            Self::UnorderedKeys { target, index } => {
+                write!(f, "key {index} of the track `{target}` is before the key before it")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DefinitionError {}
+
+impl<V, T> KeyDef<V, T> {
+    /// Creates a new key, which is eased into linearly.
+    pub fn new(time: T, value: V) -> Self {
+        Self {
+            time,
+            value,
+            ease: Ease::Linear,
+        }
+    }
+
+    /// Sets how this key is eased into.
+    pub fn eased(self, ease: Ease) -> Self {
+        Self { ease, ..self }
+    }
+}
+
+impl<V, T> TrackDef<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Makes a [Sequence] which plays this track from the start of the clip. Until the first
+    /// key, the track holds its value.
+    pub fn instantiate(&self) -> Result<Sequence<Eased<V, T>>, DefinitionError> {
+        let Some(first) = self.keys.first() else {
+            return Err(DefinitionError::NoKeys {
+                target: self.target.clone(),
+            });
+        };
+
+        let mut sequence = Sequence::new(Ease::Linear.tween(first.value..=first.value, first.time));
+        for (index, pair) in self.keys.windows(2).enumerate() {
+            let [from, to] = [pair[0], pair[1]];
+            if !to.time.is_complete(from.time) {
+                return Err(DefinitionError::UnorderedKeys {
+                    target: self.target.clone(),
+                    index: index + 1,
+                });
+            }
+
+            sequence.push(to.ease.tween(from.value..=to.value, to.time.sub(from.time)));
+        }
+
+        Ok(sequence)
+    }
+}
+
+impl<V, T> ClipDef<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Makes a [TweenManager] which plays this clip, with a property for each track, keyed by
+    /// its target.
+    pub fn instantiate(&self) -> Result<TweenManager<String, Sequence<Eased<V, T>>>, DefinitionError> {
+        let mut manager = TweenManager::new();
+        for track in &self.tracks {
+            manager.insert(track.target.clone(), track.instantiate()?);
+        }
+
+        Ok(manager)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tween;
+    use std::{string::ToString, vec};
+
+    #[test]
+    fn instantiate_track() {
+        let mut track = TrackDef {
+            target: "x".to_string(),
+            keys: vec![
+                KeyDef::new(0, 0),
+                KeyDef::new(10, 100).eased(Ease::QuadIn),
+                KeyDef::new(10, 50),
+                KeyDef::new(20, 0),
+            ],
+        };
+
+        let mut sequence = track.instantiate().unwrap();
+        assert_eq!(sequence.duration(), 20);
+        assert_eq!(sequence.run(5), 25);
+        assert_eq!(sequence.run(15), 25);
+
+        track.keys[3].time = 5;
+        assert_eq!(
+            track.instantiate().unwrap_err(),
+            DefinitionError::UnorderedKeys {
+                target: "x".to_string(),
+                index: 3
+            }
+        );
+
+        track.keys.clear();
+        assert_eq!(
+            track.instantiate().unwrap_err().to_string(),
+            "the track `x` has no keys"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn default_ease() {
+        use serde::{
+            Deserialize,
+            de::value::{Error, MapDeserializer},
+        };
+
+        let key = MapDeserializer::<_, Error>::new([("time", 2.0), ("value", 1.0)].into_iter());
+        assert_eq!(KeyDef::deserialize(key), Ok(KeyDef::new(2.0f32, 1.0f32)));
+    }
+}
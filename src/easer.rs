@@ -0,0 +1,177 @@
+use crate::{Tween, TweenTime, TweenValue};
+use core::{fmt, marker::PhantomData, ops::RangeInclusive};
+use easer::functions::Easing;
+
+/// Which of the three functions of an easer [Easing] an [EaserTween] runs.
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EaserMode {
+    /// [Easing::ease_in].
+    In,
+    /// [Easing::ease_out].
+    Out,
+    /// [Easing::ease_in_out].
+    InOut,
+}
+
+/// A tween which is eased by an easing of the [easer] crate, so a project moving from easer can
+/// keep its own `Easing` implementations, or mix them with this library's tweens.
+///
+/// The easing is given as a type, like easer's own functions, and is evaluated in `f64` from
+/// `0.0` to `1.0`, so it can tween any [TweenValue] over any [TweenTime].
+///
+/// ```
+/// # use tween::{EaserTween, Tweener};
+/// use easer::functions::Bounce;
+///
+/// let mut tweener = Tweener::new(EaserTween::<Bounce, _, _>::ease_out(0.0f32..=10.0, 4.0f32));
+/// assert_eq!(tweener.update(4.0), Some(10.0));
+/// ```
+pub struct EaserTween<E, V, T> {
+    start: V,
+    end: V,
+    duration: T,
+    mode: EaserMode,
+    easing: PhantomData<fn() -> E>,
+}
+
+impl<E, V, T> EaserTween<E, V, T>
+where
+    E: Easing<f64>,
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Creates a new tween out of a range with a duration, which is eased by the function of
+    /// `E` picked by `mode`.
+    pub fn new(range: RangeInclusive<V>, duration: T, mode: EaserMode) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            start,
+            end,
+            duration,
+            mode,
+            easing: PhantomData,
+        }
+    }
+
+    /// Creates a new tween which is eased by [Easing::ease_in].
+    pub fn ease_in(range: RangeInclusive<V>, duration: T) -> Self {
+        Self::new(range, duration, EaserMode::In)
+    }
+
+    /// Creates a new tween which is eased by [Easing::ease_out].
+    pub fn ease_out(range: RangeInclusive<V>, duration: T) -> Self {
+        Self::new(range, duration, EaserMode::Out)
+    }
+
+    /// Creates a new tween which is eased by [Easing::ease_in_out].
+    pub fn ease_in_out(range: RangeInclusive<V>, duration: T) -> Self {
+        Self::new(range, duration, EaserMode::InOut)
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: T) -> V {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// Which function of the easing this tween runs.
+    pub fn mode(&self) -> EaserMode {
+        self.mode
+    }
+}
+
+impl<E, V, T> Tween for EaserTween<E, V, T>
+where
+    E: Easing<f64>,
+    V: TweenValue,
+    T: TweenTime,
+{
+    type Value = V;
+    type Time = T;
+
+    fn run(&mut self, new_time: T) -> V {
+        if let Some(held) = crate::tweens::held_value(self.range(), self.duration, new_time) {
+            return held;
+        }
+
+        self.run_percent(T::percent(self.duration, new_time))
+    }
+
+    fn run_percent(&mut self, percent: f64) -> V {
+        let percent = match self.mode {
+            EaserMode::In => E::ease_in(percent, 0.0, 1.0, 1.0),
+            EaserMode::Out => E::ease_out(percent, 0.0, 1.0, 1.0),
+            EaserMode::InOut => E::ease_in_out(percent, 0.0, 1.0, 1.0),
+        };
+
+        V::calculate_delta(self.end, self.start).scale(percent).add(self.start)
+    }
+
+    fn range(&self) -> RangeInclusive<V> {
+        self.start..=self.end
+    }
+
+    fn duration(&self) -> T {
+        self.duration
+    }
+}
+
+// easer's easings derive nothing but `Debug`, so these are written out to not need them.
+impl<E, V: Clone, T: Clone> Clone for EaserTween<E, V, T> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start.clone(),
+            end: self.end.clone(),
+            duration: self.duration.clone(),
+            mode: self.mode,
+            easing: PhantomData,
+        }
+    }
+}
+
+impl<E, V: Copy, T: Copy> Copy for EaserTween<E, V, T> {}
+
+impl<E, V: PartialEq, T: PartialEq> PartialEq for EaserTween<E, V, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && self.duration == other.duration && self.mode == other.mode
+    }
+}
+
+impl<E, V: fmt::Debug, T: fmt::Debug> fmt::Debug for EaserTween<E, V, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EaserTween")
+            .field("easing", &core::any::type_name::<E>())
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("duration", &self.duration)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_ulps_eq;
+    use easer::functions::{Cubic, Quad};
+
+    #[test]
+    fn matches_easer() {
+        let mut ours = crate::QuadInOut::new(0.0..=100.0, 10.0);
+        let mut theirs = EaserTween::<Quad, _, _>::ease_in_out(0.0..=100.0, 10.0);
+
+        for time in 0..=10 {
+            let time = time as f32;
+            assert_ulps_eq!(theirs.run(time), ours.run(time), max_ulps = 4);
+            assert_ulps_eq!(
+                theirs.run(time),
+                Quad::ease_in_out(time, 0.0, 100.0, 10.0),
+                max_ulps = 4
+            );
+        }
+
+        let mut tween = EaserTween::<Cubic, _, _>::ease_in(0..=8, 2);
+        assert_eq!(tween.run(1), 1);
+        assert_eq!(tween.run(2), 8);
+    }
+}
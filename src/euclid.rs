@@ -0,0 +1,100 @@
+use euclid::{Angle, Point2D, Size2D, Vector2D};
+
+macro_rules! euclid_values {
+    ($float:ty) => {
+        impl<U> crate::TweenValue for Vector2D<$float, U> {
+            const ZERO: Self = Vector2D::new(0.0, 0.0);
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * scale as $float
+            }
+        }
+
+        /// Points can't be added together, so the delta between two points is stored as a
+        /// point holding the offset.
+        impl<U> crate::TweenValue for Point2D<$float, U> {
+            const ZERO: Self = Point2D::new(0.0, 0.0);
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                (destination - start).to_point()
+            }
+
+            fn add(self, other: Self) -> Self {
+                other + self.to_vector()
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * scale as $float
+            }
+        }
+
+        impl<U> crate::TweenValue for Size2D<$float, U> {
+            const ZERO: Self = Size2D::new(0.0, 0.0);
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * scale as $float
+            }
+        }
+
+        /// Angles are tweened linearly in radians, so a tween from `0` to `3π / 2` goes the
+        /// long way around rather than wrapping.
+        impl crate::TweenValue for Angle<$float> {
+            const ZERO: Self = Angle { radians: 0.0 };
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                Angle::radians(destination.radians - start.radians)
+            }
+
+            fn add(self, other: Self) -> Self {
+                Angle::radians(self.radians + other.radians)
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                Angle::radians(self.radians * scale as $float)
+            }
+        }
+    };
+}
+
+euclid_values!(f32);
+euclid_values!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::Linear;
+    use euclid::{Angle, Point2D, Size2D, default};
+
+    struct ScreenSpace;
+
+    #[test]
+    fn points_and_sizes() {
+        let start: Point2D<f32, ScreenSpace> = Point2D::new(1.0, 1.0);
+        let mut tween = Linear::new(start..=Point2D::new(3.0, 5.0), 2.0);
+        assert_eq!(tween.run(1.0), Point2D::new(2.0, 3.0));
+
+        let mut tween = Linear::new(Size2D::zero()..=default::Size2D::new(10.0, 20.0), 4.0);
+        assert_eq!(tween.run(1.0), Size2D::new(2.5, 5.0));
+    }
+
+    #[test]
+    fn angles() {
+        let mut tween = Linear::new(Angle::radians(0.0)..=Angle::radians(3.0), 2.0);
+        assert_eq!(tween.run(1.0), Angle::radians(1.5));
+    }
+}
@@ -0,0 +1,349 @@
+use crate::{Tween, TweenTime, TweenValue};
+#[cfg(feature = "std")]
+use std::{io, vec, vec::Vec};
+
+/// A [TweenValue] which can be written out as a fixed number of `f32` channels, so that
+/// it can be baked into a curve for a GPU.
+pub trait Channels: TweenValue {
+    /// The number of `f32`s this value is written as.
+    const CHANNELS: usize;
+
+    /// Writes this value into the first [CHANNELS](Self::CHANNELS) of `out`.
+    fn write_channels(self, out: &mut [f32]);
+}
+
+impl Channels for f32 {
+    const CHANNELS: usize = 1;
+
+    fn write_channels(self, out: &mut [f32]) {
+        out[0] = self;
+    }
+}
+
+impl Channels for f64 {
+    const CHANNELS: usize = 1;
+
+    fn write_channels(self, out: &mut [f32]) {
+        out[0] = self as f32;
+    }
+}
+
+impl<V: Channels, const N: usize> Channels for [V; N] {
+    const CHANNELS: usize = V::CHANNELS * N;
+
+    fn write_channels(self, out: &mut [f32]) {
+        for (value, out) in self.into_iter().zip(out.chunks_exact_mut(V::CHANNELS)) {
+            value.write_channels(out);
+        }
+    }
+}
+
+/// The layout of a curve baked by [CurveInfo::bake], which a shader needs to read it back.
+///
+/// This is `#[repr(C)]`, with only 32 bit fields, so it can be uploaded as it is, next to
+/// the samples, as a uniform.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveInfo {
+    /// The number of samples, which are evenly spaced from the start of the tween to its end.
+    pub samples: u32,
+    /// The number of `f32`s in each sample.
+    pub channels: u32,
+    /// The duration of the tween, as an `f32`.
+    pub duration: f32,
+    /// The number of samples in each unit of the tween's time.
+    pub sample_rate: f32,
+    /// The smallest channel of any sample, for normalizing into a texture.
+    pub min: f32,
+    /// The largest channel of any sample, for normalizing into a texture.
+    pub max: f32,
+}
+
+impl CurveInfo {
+    /// Bakes `samples` evenly spaced samples of `tween` into `out`, packed one after another
+    /// with no padding, and returns how they were laid out. This doesn't allocate, so `out`
+    /// can be a fixed size array.
+    ///
+    /// ```
+    /// # use tween::{CurveInfo, Linear};
+    /// let mut curve = [0.0; 6];
+    /// let info = CurveInfo::bake(&mut Linear::new([0.0f32, 10.0]..=[1.0, 0.0], 2.0f32), &mut curve, 3);
+    ///
+    /// assert_eq!(curve, [0.0, 10.0, 0.5, 5.0, 1.0, 0.0]);
+    /// assert_eq!((info.channels, info.sample_rate), (2, 1.0));
+    /// assert_eq!((info.min, info.max), (0.0, 10.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `samples` times the channels of the value.
+    pub fn bake<Tw>(tween: &mut Tw, out: &mut [f32], samples: usize) -> Self
+    where
+        Tw: Tween,
+        Tw::Value: Channels,
+    {
+        profile_span!("CurveInfo::bake");
+        profile_count!("samples", samples);
+
+        let channels = Tw::Value::CHANNELS;
+        let out = &mut out[..samples * channels];
+
+        let last = samples.saturating_sub(1).max(1) as f64;
+        for (i, sample) in out.chunks_exact_mut(channels).enumerate() {
+            tween.run_percent(i as f64 / last).write_channels(sample);
+        }
+
+        let (min, max) = out.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+        let duration = tween.duration().as_f64();
+
+        Self {
+            samples: samples as u32,
+            channels: channels as u32,
+            duration: duration as f32,
+            sample_rate: (samples.saturating_sub(1) as f64 / duration) as f32,
+            min,
+            max,
+        }
+    }
+}
+
+/// A curve baked from a tween into one tightly packed buffer of `f32`s, ready to upload
+/// as a 1D texture or a storage buffer, with the [CurveInfo] describing it.
+///
+/// ```
+/// # use tween::{BakedCurve, SineInOut};
+/// let curve = BakedCurve::new(&mut SineInOut::new(0.0f32..=1.0, 1.0f32), 256);
+///
+/// assert_eq!(curve.samples().len(), 256);
+/// assert_eq!(curve.info().sample_rate, 255.0);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BakedCurve {
+    samples: Vec<f32>,
+    info: CurveInfo,
+}
+
+#[cfg(feature = "std")]
+impl BakedCurve {
+    /// Bakes `samples` evenly spaced samples of `tween`, from its start to its end.
+    pub fn new<Tw>(tween: &mut Tw, samples: usize) -> Self
+    where
+        Tw: Tween,
+        Tw::Value: Channels,
+    {
+        let mut buffer = vec![0.0; samples * Tw::Value::CHANNELS];
+        let info = CurveInfo::bake(tween, &mut buffer, samples);
+
+        Self { samples: buffer, info }
+    }
+
+    /// The packed channels of every sample.
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    /// How the samples are laid out.
+    pub fn info(&self) -> CurveInfo {
+        self.info
+    }
+
+    /// Takes the packed samples out of the curve.
+    pub fn into_samples(self) -> Vec<f32> {
+        self.samples
+    }
+}
+
+/// The `(time, value)` pairs of a tween every so often, which are made by
+/// [Tween::samples](crate::Tween::samples).
+#[derive(Debug)]
+pub struct Samples<'a, Tw: Tween> {
+    tween: &'a mut Tw,
+    step: Tw::Time,
+    index: usize,
+    finished: bool,
+}
+
+impl<'a, Tw> Samples<'a, Tw>
+where
+    Tw: Tween,
+{
+    pub(crate) fn new(tween: &'a mut Tw, step: Tw::Time) -> Self {
+        assert!(!Tw::Time::ZERO.is_complete(step), "the step must be more than zero");
+
+        Self {
+            tween,
+            step,
+            index: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<Tw> Iterator for Samples<'_, Tw>
+where
+    Tw: Tween,
+{
+    type Item = (Tw::Time, Tw::Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        // each time is found from the start, so float steps don't drift as they add up
+        let time = self.step.scale(self.index as f64);
+        let duration = self.tween.duration();
+        if time.is_complete(duration) {
+            self.finished = true;
+            return Some((duration, *self.tween.range().end()));
+        }
+
+        self.index += 1;
+        Some((time, self.tween.run(time)))
+    }
+}
+
+impl<Tw: Tween> core::iter::FusedIterator for Samples<'_, Tw> {}
+
+#[cfg(feature = "std")]
+impl<Tw> Samples<'_, Tw>
+where
+    Tw: Tween,
+    Tw::Value: Channels,
+{
+    /// Writes the samples as CSV, with a `time` column and a column for each channel of the
+    /// value, such as to plot the curve in a spreadsheet. How often the tween is sampled is
+    /// the `step` it was sampled with.
+    ///
+    /// ```
+    /// # use tween::{QuadIn, Tween};
+    /// let mut csv = Vec::new();
+    /// QuadIn::new(0.0f32..=1.0, 1.0f32).samples(0.5).write_csv(&mut csv).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "time,value\n0,0\n0.5,0.25\n1,1\n");
+    /// ```
+    pub fn write_csv(self, mut out: impl io::Write) -> io::Result<()> {
+        let channels = Tw::Value::CHANNELS;
+        write!(out, "time")?;
+        if channels == 1 {
+            write!(out, ",value")?;
+        } else {
+            (0..channels).try_for_each(|channel| write!(out, ",value{channel}"))?;
+        }
+        writeln!(out)?;
+
+        let mut values = vec![0.0; channels];
+        for (time, value) in self {
+            value.write_channels(&mut values);
+
+            write!(out, "{}", time.as_f64() as f32)?;
+            values.iter().try_for_each(|value| write!(out, ",{value}"))?;
+            writeln!(out)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the samples as a JSON array of `{"time": .., "value": ..}` objects, such as to
+    /// plot the curve with a charting library. Values with more than one channel are written as
+    /// arrays, and numbers which aren't finite are written as `null`.
+    ///
+    /// ```
+    /// # use tween::{Linear, Tween};
+    /// let mut json = Vec::new();
+    /// Linear::new([0.0f32, 10.0]..=[1.0, 0.0], 1.0f32).samples(1.0).write_json(&mut json).unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(json).unwrap(),
+    ///     r#"[{"time":0,"value":[0,10]},{"time":1,"value":[1,0]}]"#
+    /// );
+    /// ```
+    pub fn write_json(self, mut out: impl io::Write) -> io::Result<()> {
+        let channels = Tw::Value::CHANNELS;
+        let number = |out: &mut dyn io::Write, number: f32| {
+            if number.is_finite() {
+                write!(out, "{number}")
+            } else {
+                write!(out, "null")
+            }
+        };
+
+        let mut values = vec![0.0; channels];
+        write!(out, "[")?;
+        for (index, (time, value)) in self.enumerate() {
+            value.write_channels(&mut values);
+
+            if index > 0 {
+                write!(out, ",")?;
+            }
+            write!(out, "{{\"time\":")?;
+            number(&mut out, time.as_f64() as f32)?;
+            write!(out, ",\"value\":")?;
+            if channels == 1 {
+                number(&mut out, values[0])?;
+            } else {
+                write!(out, "[")?;
+                for (channel, &value) in values.iter().enumerate() {
+                    if channel > 0 {
+                        write!(out, ",")?;
+                    }
+                    number(&mut out, value)?;
+                }
+                write!(out, "]")?;
+            }
+            write!(out, "}}")?;
+        }
+        write!(out, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MultiTween, Segment};
+
+    #[test]
+    fn bake_track() {
+        let segments = [Segment::linear(4.0f32, 2.0f32), Segment::linear(8.0, 2.0)];
+        let mut track = MultiTween::new(0.0, &segments);
+
+        let mut out = [0.0; 5];
+        let info = CurveInfo::bake(&mut track, &mut out, 5);
+
+        assert_eq!(out, [0.0, 2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((info.samples, info.duration, info.sample_rate), (5, 4.0, 1.0));
+        assert_eq!((info.min, info.max), (0.0, 8.0));
+    }
+
+    #[test]
+    fn samples() {
+        let mut tween = crate::Linear::new(0.0f32..=1.0, 0.3f32);
+        let mut samples = tween.samples(0.1);
+
+        assert_eq!(samples.next(), Some((0.0, 0.0)));
+        assert_eq!(samples.nth(1), Some((0.2, 0.2 / 0.3)));
+        assert_eq!(samples.next(), Some((0.3, 1.0)));
+        assert_eq!(samples.next(), None);
+
+        let mut empty = crate::Linear::new(1..=2, 0u32);
+        assert!(empty.samples(1).eq([(0, 2)]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_samples() {
+        // `1e300` is too big for an `f32`, so it's written as infinity
+        let mut tween = crate::Linear::new([0.0, 1e300]..=[1.0, 1e300], 2u32);
+
+        let mut csv = Vec::new();
+        tween.samples(1).write_csv(&mut csv).unwrap();
+        assert_eq!(csv, b"time,value0,value1\n0,0,inf\n1,0.5,inf\n2,1,inf\n");
+
+        let mut json = Vec::new();
+        tween.samples(2).write_json(&mut json).unwrap();
+        assert_eq!(json, br#"[{"time":0,"value":[0,null]},{"time":2,"value":[1,null]}]"#);
+    }
+}
@@ -0,0 +1,157 @@
+//! A flat C ABI over this library's easings, which is enabled by the `ffi` feature, so C and
+//! C++ engines, and Unity native plugins, can use them too.
+//!
+//! Tweens are made with [tween_create], which hands back an id, driven with [tween_update],
+//! and freed with [tween_destroy]. Every value and time is an `f32`, and easings are picked by
+//! their index in [Ease::ALL], from `0` for `linear` to `30` for `bounce_in_out`. The tweens
+//! live behind a lock, so they can be used from any thread.
+//!
+//! Build a library for C by giving cargo a crate type, and make a header for it with cbindgen:
+//!
+//! ```sh
+//! cargo rustc --release --features ffi --crate-type cdylib
+//! cbindgen --lang c --output tween.h
+//! ```
+//!
+//! ```c
+//! uint32_t fade = tween_create(5 /* quad_out */, 0.0f, 1.0f, 0.5f);
+//! while (!tween_is_finished(fade)) {
+//!     set_opacity(tween_update(fade, frame_time()));
+//! }
+//! tween_destroy(fade);
+//! ```
+
+use crate::{Ease, Eased, Tweener};
+use std::{
+    sync::{Mutex, MutexGuard, PoisonError},
+    vec::Vec,
+};
+
+/// A tween made through the C ABI, and the last value it was updated to.
+struct Slot {
+    tweener: Tweener<Eased<f32, f32>>,
+    value: f32,
+}
+
+/// Every tween made through the C ABI, where the id of a tween is its index plus one, so that
+/// `0` is never an id.
+static SLOTS: Mutex<Vec<Option<Slot>>> = Mutex::new(Vec::new());
+
+fn slots() -> MutexGuard<'static, Vec<Option<Slot>>> {
+    SLOTS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Runs `f` with the tween of `id`, if there is one.
+fn with_slot<R>(id: u32, f: impl FnOnce(&mut Slot) -> R) -> Option<R> {
+    let index = usize::try_from(id).ok()?.checked_sub(1)?;
+    slots().get_mut(index)?.as_mut().map(f)
+}
+
+/// Makes a tween from `start` to `end` over `duration`, eased by the easing at `ease` in
+/// [Ease::ALL], and returns its id. Returns `0` if there's no such easing.
+///
+/// Ids of destroyed tweens are given to new ones, so an id shouldn't be used once it's
+/// destroyed.
+#[no_mangle]
+pub extern "C" fn tween_create(ease: u32, start: f32, end: f32, duration: f32) -> u32 {
+    let Some(&ease) = usize::try_from(ease).ok().and_then(|ease| Ease::ALL.get(ease)) else {
+        return 0;
+    };
+
+    let slot = Slot {
+        tweener: Tweener::eased(start..=end, duration, ease),
+        value: start,
+    };
+
+    let mut slots = slots();
+    let index = match slots.iter().position(Option::is_none) {
+        Some(index) => {
+            slots[index] = Some(slot);
+            index
+        }
+        None => {
+            slots.push(Some(slot));
+            slots.len() - 1
+        }
+    };
+
+    u32::try_from(index + 1).unwrap_or(0)
+}
+
+/// Drives the tween of `id` forward by `delta`, and returns its new value. Once the tween has
+/// finished, this keeps returning its end value. Returns NaN if there's no such tween.
+#[no_mangle]
+pub extern "C" fn tween_update(id: u32, delta: f32) -> f32 {
+    with_slot(id, |slot| {
+        if let Some(value) = slot.tweener.update(delta) {
+            slot.value = value;
+        }
+        slot.value
+    })
+    .unwrap_or(f32::NAN)
+}
+
+/// The value of the tween of `id` at `time`, without driving it. Returns NaN if there's no
+/// such tween.
+#[no_mangle]
+pub extern "C" fn tween_value_at(id: u32, time: f32) -> f32 {
+    with_slot(id, |slot| slot.tweener.tween().clone().run(time)).unwrap_or(f32::NAN)
+}
+
+/// Returns `true` if the tween of `id` has finished, or if there's no such tween.
+#[no_mangle]
+pub extern "C" fn tween_is_finished(id: u32) -> bool {
+    with_slot(id, |slot| slot.tweener.is_finished()).unwrap_or(true)
+}
+
+/// Frees the tween of `id`. Returns `false` if there's no such tween.
+#[no_mangle]
+pub extern "C" fn tween_destroy(id: u32) -> bool {
+    let Some(index) = usize::try_from(id).ok().and_then(|id| id.checked_sub(1)) else {
+        return false;
+    };
+
+    slots().get_mut(index).and_then(Option::take).is_some()
+}
+
+/// Eases `percent`, from `0.0` to `1.0`, with the easing at `ease` in [Ease::ALL], without
+/// making a tween. Returns NaN if there's no such easing.
+#[no_mangle]
+pub extern "C" fn tween_ease(ease: u32, percent: f32) -> f32 {
+    usize::try_from(ease)
+        .ok()
+        .and_then(|ease| Ease::ALL.get(ease))
+        .map_or(f32::NAN, |ease| ease.function()(percent))
+}
+
+/// The number of easings, which are the indices from `0` up to it.
+#[no_mangle]
+pub extern "C" fn tween_ease_count() -> u32 {
+    Ease::ALL.len() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_update_destroy() {
+        let quad_in = Ease::ALL.iter().position(|&ease| ease == Ease::QuadIn).unwrap() as u32;
+        let id = tween_create(quad_in, 0.0, 100.0, 4.0);
+        assert_ne!(id, 0);
+
+        assert_eq!(tween_value_at(id, 2.0), 25.0);
+        assert_eq!(tween_update(id, 2.0), 25.0);
+        assert!(!tween_is_finished(id));
+        assert_eq!(tween_update(id, 8.0), 100.0);
+        assert_eq!(tween_update(id, 1.0), 100.0);
+        assert!(tween_is_finished(id));
+
+        assert!(tween_destroy(id));
+        assert!(!tween_destroy(id));
+        assert!(tween_update(id, 1.0).is_nan());
+
+        assert_eq!(tween_create(tween_ease_count(), 0.0, 1.0, 1.0), 0);
+        assert_eq!(tween_ease(quad_in, 0.5), 0.25);
+    }
+}
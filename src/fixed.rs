@@ -0,0 +1,68 @@
+use fixed::{
+    FixedI8, FixedI16, FixedI32, FixedI64, FixedU8, FixedU16, FixedU32, FixedU64,
+    types::extra::{LeEqU8, LeEqU16, LeEqU32, LeEqU64},
+};
+
+/// The easing multiplier as a fixed-point number with 32 fractional bits.
+fn fixed_scale(scale: f64) -> i128 {
+    (scale * (1u64 << 32) as f64) as i128
+}
+
+/// Implements [TweenValue](crate::TweenValue) for a fixed-point type. The easing multiplier is
+/// applied with an integer multiplication, so values are never converted to floats.
+///
+/// Deltas are computed with wrapping arithmetic and are read as signed when they are scaled, so
+/// unsigned values can tween downwards too, as long as the distance is less than half of their
+/// range.
+macro_rules! fixed_value {
+    ($fixed:ident, $bound:ident, $bits:ty, $signed:ty) => {
+        impl<Frac: $bound> crate::TweenValue for $fixed<Frac> {
+            const ZERO: Self = Self::ZERO;
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination.wrapping_sub(start)
+            }
+
+            fn add(self, other: Self) -> Self {
+                self.wrapping_add(other)
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                let bits = self.to_bits() as $signed as i128;
+                Self::from_bits(((bits * fixed_scale(scale)) >> 32) as $bits)
+            }
+        }
+    };
+}
+
+fixed_value!(FixedI8, LeEqU8, i8, i8);
+fixed_value!(FixedI16, LeEqU16, i16, i16);
+fixed_value!(FixedI32, LeEqU32, i32, i32);
+fixed_value!(FixedI64, LeEqU64, i64, i64);
+fixed_value!(FixedU8, LeEqU8, u8, i8);
+fixed_value!(FixedU16, LeEqU16, u16, i16);
+fixed_value!(FixedU32, LeEqU32, u32, i32);
+fixed_value!(FixedU64, LeEqU64, u64, i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Linear, QuadIn};
+    use fixed::types::{I16F16, U0F16};
+
+    #[test]
+    fn signed() {
+        let mut tween = Linear::new(I16F16::from_num(-10)..=I16F16::from_num(10), 4);
+        assert_eq!(tween.run(1), I16F16::from_num(-5));
+        assert_eq!(tween.run(4), I16F16::from_num(10));
+
+        let mut tween = QuadIn::new(I16F16::ZERO..=I16F16::from_num(100), 10);
+        assert_eq!(tween.run(5), I16F16::from_num(25));
+    }
+
+    #[test]
+    fn unsigned_downwards() {
+        let mut tween = Linear::new(U0F16::from_num(0.75)..=U0F16::from_num(0.25), 2);
+        assert_eq!(tween.run(1), U0F16::from_num(0.5));
+        assert_eq!(tween.run(2), U0F16::from_num(0.25));
+    }
+}
@@ -0,0 +1,355 @@
+//! The named curves of Flutter's `Curves` class, with Flutter's exact constants, so an app
+//! with a Flutter frontend and a Rust one moves the same in both.
+//!
+//! Flutter finds where along a cubic curve it is by bisection, to within `0.001`, rather than
+//! solving it exactly. These curves do the same, so they give Flutter's values, not the
+//! slightly different values of [CubicBezier](crate::CubicBezier).
+//!
+//! ```
+//! # use tween::{flutter::FlutterCurve, Tweener};
+//! let curve = FlutterCurve::from_name("fastOutSlowIn").unwrap();
+//! assert_eq!(curve, FlutterCurve::FastOutSlowIn);
+//!
+//! let mut tweener = Tweener::new(curve.tween(0.0f32..=100.0, 0.3f32));
+//! let _opacity = tweener.update(0.1);
+//! ```
+
+use crate::{Tween, TweenTime, TweenValue, math};
+use core::{f64::consts::PI, ops::RangeInclusive};
+
+/// How a [FlutterCurve] is calculated.
+enum Shape {
+    Linear,
+    Decelerate,
+    /// Flutter's `Cubic`, of the control points `(a, b)` and `(c, d)`.
+    Cubic([f64; 4]),
+    /// Flutter's `ThreePointCubic`, of the points `a1, b1, midpoint, a2, b2`.
+    ThreePointCubic([[f64; 2]; 5]),
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+}
+
+macro_rules! curves {
+    ($($name:ident => $text:literal, $shape:expr),+ $(,)?) => {
+        /// A curve of Flutter's `Curves` class.
+        #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum FlutterCurve {
+            $(
+                #[doc = concat!("Flutter's `Curves.", $text, "`.")]
+                $name,
+            )+
+        }
+
+        impl FlutterCurve {
+            /// Every curve, in the order Flutter declares them.
+            pub const ALL: &'static [FlutterCurve] = &[$(FlutterCurve::$name),+];
+
+            /// The name of this curve in Flutter, such as `"fastOutSlowIn"`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(FlutterCurve::$name => $text,)+
+                }
+            }
+
+            /// Finds the curve with the given Flutter [name](Self::name).
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($text => Some(FlutterCurve::$name),)+
+                    _ => None,
+                }
+            }
+
+            fn shape(self) -> Shape {
+                match self {
+                    $(FlutterCurve::$name => $shape,)+
+                }
+            }
+        }
+    };
+}
+
+curves! {
+    Linear => "linear", Shape::Linear,
+    Decelerate => "decelerate", Shape::Decelerate,
+    FastLinearToSlowEaseIn => "fastLinearToSlowEaseIn", Shape::Cubic([0.18, 1.0, 0.04, 1.0]),
+    FastEaseInToSlowEaseOut => "fastEaseInToSlowEaseOut", Shape::ThreePointCubic([
+        [0.056, 0.024],
+        [0.108, 0.3085],
+        [0.198, 0.541],
+        [0.3655, 1.0],
+        [0.5465, 0.989],
+    ]),
+    Ease => "ease", Shape::Cubic([0.25, 0.1, 0.25, 1.0]),
+    EaseIn => "easeIn", Shape::Cubic([0.42, 0.0, 1.0, 1.0]),
+    EaseInToLinear => "easeInToLinear", Shape::Cubic([0.67, 0.03, 0.65, 0.09]),
+    EaseInSine => "easeInSine", Shape::Cubic([0.47, 0.0, 0.745, 0.715]),
+    EaseInQuad => "easeInQuad", Shape::Cubic([0.55, 0.085, 0.68, 0.53]),
+    EaseInCubic => "easeInCubic", Shape::Cubic([0.55, 0.055, 0.675, 0.19]),
+    EaseInQuart => "easeInQuart", Shape::Cubic([0.895, 0.03, 0.685, 0.22]),
+    EaseInQuint => "easeInQuint", Shape::Cubic([0.755, 0.05, 0.855, 0.06]),
+    EaseInExpo => "easeInExpo", Shape::Cubic([0.95, 0.05, 0.795, 0.035]),
+    EaseInCirc => "easeInCirc", Shape::Cubic([0.6, 0.04, 0.98, 0.335]),
+    EaseInBack => "easeInBack", Shape::Cubic([0.6, -0.28, 0.735, 0.045]),
+    EaseOut => "easeOut", Shape::Cubic([0.0, 0.0, 0.58, 1.0]),
+    LinearToEaseOut => "linearToEaseOut", Shape::Cubic([0.35, 0.91, 0.33, 0.97]),
+    EaseOutSine => "easeOutSine", Shape::Cubic([0.39, 0.575, 0.565, 1.0]),
+    EaseOutQuad => "easeOutQuad", Shape::Cubic([0.25, 0.46, 0.45, 0.94]),
+    EaseOutCubic => "easeOutCubic", Shape::Cubic([0.215, 0.61, 0.355, 1.0]),
+    EaseOutQuart => "easeOutQuart", Shape::Cubic([0.165, 0.84, 0.44, 1.0]),
+    EaseOutQuint => "easeOutQuint", Shape::Cubic([0.23, 1.0, 0.32, 1.0]),
+    EaseOutExpo => "easeOutExpo", Shape::Cubic([0.19, 1.0, 0.22, 1.0]),
+    EaseOutCirc => "easeOutCirc", Shape::Cubic([0.075, 0.82, 0.165, 1.0]),
+    EaseOutBack => "easeOutBack", Shape::Cubic([0.175, 0.885, 0.32, 1.275]),
+    EaseInOut => "easeInOut", Shape::Cubic([0.42, 0.0, 0.58, 1.0]),
+    EaseInOutSine => "easeInOutSine", Shape::Cubic([0.445, 0.05, 0.55, 0.95]),
+    EaseInOutQuad => "easeInOutQuad", Shape::Cubic([0.455, 0.03, 0.515, 0.955]),
+    EaseInOutCubic => "easeInOutCubic", Shape::Cubic([0.645, 0.045, 0.355, 1.0]),
+    EaseInOutCubicEmphasized => "easeInOutCubicEmphasized", Shape::ThreePointCubic([
+        [0.05, 0.0],
+        [0.133333, 0.06],
+        [0.166666, 0.4],
+        [0.208333, 0.82],
+        [0.25, 1.0],
+    ]),
+    EaseInOutQuart => "easeInOutQuart", Shape::Cubic([0.77, 0.0, 0.175, 1.0]),
+    EaseInOutQuint => "easeInOutQuint", Shape::Cubic([0.86, 0.0, 0.07, 1.0]),
+    EaseInOutExpo => "easeInOutExpo", Shape::Cubic([1.0, 0.0, 0.0, 1.0]),
+    EaseInOutCirc => "easeInOutCirc", Shape::Cubic([0.785, 0.135, 0.15, 0.86]),
+    EaseInOutBack => "easeInOutBack", Shape::Cubic([0.68, -0.55, 0.265, 1.55]),
+    FastOutSlowIn => "fastOutSlowIn", Shape::Cubic([0.4, 0.0, 0.2, 1.0]),
+    SlowMiddle => "slowMiddle", Shape::Cubic([0.15, 0.85, 0.85, 0.15]),
+    BounceIn => "bounceIn", Shape::BounceIn,
+    BounceOut => "bounceOut", Shape::BounceOut,
+    BounceInOut => "bounceInOut", Shape::BounceInOut,
+    ElasticIn => "elasticIn", Shape::ElasticIn,
+    ElasticOut => "elasticOut", Shape::ElasticOut,
+    ElasticInOut => "elasticInOut", Shape::ElasticInOut,
+}
+
+impl FlutterCurve {
+    /// Eases `t`, from `0.0` to `1.0`, like Flutter's `Curve.transform`, which gives exactly `0.0`
+    /// and `1.0` at the ends. Other values of `t` are clamped.
+    pub fn transform(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        if t == 0.0 || t == 1.0 {
+            return t;
+        }
+
+        match self.shape() {
+            Shape::Linear => t,
+            Shape::Decelerate => {
+                let t = 1.0 - t;
+                1.0 - t * t
+            }
+            Shape::Cubic([a, b, c, d]) => cubic(a, b, c, d, t),
+            Shape::ThreePointCubic([a1, b1, midpoint, a2, b2]) => {
+                if t < midpoint[0] {
+                    let [scale_x, scale_y] = midpoint;
+                    let [a, b, c, d] = [a1[0] / scale_x, a1[1] / scale_y, b1[0] / scale_x, b1[1] / scale_y];
+                    cubic(a, b, c, d, t / scale_x) * scale_y
+                } else {
+                    let [scale_x, scale_y] = [1.0 - midpoint[0], 1.0 - midpoint[1]];
+                    let [a, b, c, d] = [
+                        (a2[0] - midpoint[0]) / scale_x,
+                        (a2[1] - midpoint[1]) / scale_y,
+                        (b2[0] - midpoint[0]) / scale_x,
+                        (b2[1] - midpoint[1]) / scale_y,
+                    ];
+                    cubic(a, b, c, d, (t - midpoint[0]) / scale_x) * scale_y + midpoint[1]
+                }
+            }
+            Shape::BounceIn => 1.0 - bounce(1.0 - t),
+            Shape::BounceOut => bounce(t),
+            Shape::BounceInOut => {
+                if t < 0.5 {
+                    (1.0 - bounce(1.0 - t * 2.0)) * 0.5
+                } else {
+                    bounce(t * 2.0 - 1.0) * 0.5 + 0.5
+                }
+            }
+            Shape::ElasticIn => {
+                let t = t - 1.0;
+                -math::powf_f64(2.0, 10.0 * t) * elastic_wave(t)
+            }
+            Shape::ElasticOut => math::powf_f64(2.0, -10.0 * t) * elastic_wave(t) + 1.0,
+            Shape::ElasticInOut => {
+                let t = 2.0 * t - 1.0;
+                if t < 0.0 {
+                    -0.5 * math::powf_f64(2.0, 10.0 * t) * elastic_wave(t)
+                } else {
+                    math::powf_f64(2.0, -10.0 * t) * elastic_wave(t) * 0.5 + 1.0
+                }
+            }
+        }
+    }
+
+    /// Creates a new tween out of a range with a duration, which is eased by this curve.
+    pub fn tween<V, T>(self, range: RangeInclusive<V>, duration: T) -> FlutterTween<V, T>
+    where
+        V: TweenValue,
+        T: TweenTime,
+    {
+        let (start, end) = range.into_inner();
+        FlutterTween {
+            curve: self,
+            start,
+            end,
+            duration,
+        }
+    }
+}
+
+/// Flutter's `Cubic.transform`, which bisects for the point of the curve within `0.001` of
+/// `t` across, and gives its height.
+fn cubic(a: f64, b: f64, c: f64, d: f64, t: f64) -> f64 {
+    if t == 0.0 || t == 1.0 {
+        return t;
+    }
+
+    let evaluate =
+        |p1: f64, p2: f64, m: f64| 3.0 * p1 * (1.0 - m) * (1.0 - m) * m + 3.0 * p2 * (1.0 - m) * m * m + m * m * m;
+
+    let (mut start, mut end) = (0.0, 1.0);
+    loop {
+        let midpoint = (start + end) / 2.0;
+        let estimate = evaluate(a, c, midpoint);
+        if (t - estimate).abs() < 0.001 {
+            return evaluate(b, d, midpoint);
+        }
+
+        if estimate < t {
+            start = midpoint;
+        } else {
+            end = midpoint;
+        }
+    }
+}
+
+/// Flutter's `_bounce`.
+fn bounce(mut t: f64) -> f64 {
+    if t < 1.0 / 2.75 {
+        return 7.5625 * t * t;
+    }
+    if t < 2.0 / 2.75 {
+        t -= 1.5 / 2.75;
+        return 7.5625 * t * t + 0.75;
+    }
+    if t < 2.5 / 2.75 {
+        t -= 2.25 / 2.75;
+        return 7.5625 * t * t + 0.9375;
+    }
+
+    t -= 2.625 / 2.75;
+    7.5625 * t * t + 0.984375
+}
+
+/// The sine wave of Flutter's elastic curves, with their default period of `0.4`.
+fn elastic_wave(t: f64) -> f64 {
+    const PERIOD: f64 = 0.4;
+    math::sin_cos_f64((t - PERIOD / 4.0) * (PI * 2.0) / PERIOD).0
+}
+
+/// A tween which is eased by a [FlutterCurve]. It's made with [FlutterCurve::tween].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlutterTween<V, T> {
+    curve: FlutterCurve,
+    start: V,
+    end: V,
+    duration: T,
+}
+
+impl<V, T> FlutterTween<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: T) -> V {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// The curve which eases this tween.
+    pub fn curve(&self) -> FlutterCurve {
+        self.curve
+    }
+}
+
+impl<V, T> Tween for FlutterTween<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    type Value = V;
+    type Time = T;
+
+    fn run(&mut self, new_time: T) -> V {
+        if let Some(held) = crate::tweens::held_value(self.range(), self.duration, new_time) {
+            return held;
+        }
+
+        self.run_percent(T::percent(self.duration, new_time))
+    }
+
+    fn run_percent(&mut self, percent: f64) -> V {
+        V::calculate_delta(self.end, self.start)
+            .scale(self.curve.transform(percent))
+            .add(self.start)
+    }
+
+    fn range(&self) -> RangeInclusive<V> {
+        self.start..=self.end
+    }
+
+    fn duration(&self) -> T {
+        self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn names() {
+        for &curve in FlutterCurve::ALL {
+            assert_eq!(FlutterCurve::from_name(curve.name()), Some(curve));
+            assert_eq!(curve.transform(0.0), 0.0);
+            assert_eq!(curve.transform(1.0), 1.0);
+        }
+        assert_eq!(FlutterCurve::from_name("fast_out_slow_in"), None);
+    }
+
+    #[test]
+    fn matches_flutter() {
+        let cases = [
+            (FlutterCurve::Decelerate, 0.5, 0.75),
+            (FlutterCurve::BounceOut, 0.5, 0.765625),
+            (FlutterCurve::BounceInOut, 0.25, 0.1171875),
+            (FlutterCurve::ElasticOut, 0.25, 1.125),
+            (FlutterCurve::ElasticIn, 0.75, -0.125),
+        ];
+        for (curve, t, value) in cases {
+            assert_abs_diff_eq!(curve.transform(t), value, epsilon = 1e-9);
+        }
+
+        // the cubic is only found to within 0.001 across, as in Flutter
+        let ease = FlutterCurve::FastOutSlowIn.transform(0.5);
+        assert_abs_diff_eq!(
+            ease,
+            crate::CubicBezier::new(0.4, 0.0, 0.2, 1.0).ease(0.5),
+            epsilon = 1e-2
+        );
+        assert_abs_diff_eq!(
+            FlutterCurve::EaseInOutCubicEmphasized.transform(0.166666),
+            0.4,
+            epsilon = 1e-2
+        );
+    }
+}
@@ -0,0 +1,388 @@
+//! Stateless easing functions, which take a percent of the way through an easing and give back
+//! how far along its curve that is, where `0.0` is the start and `1.0` the end.
+//!
+//! These are the same curves as the tweens of this library, but need no range or duration,
+//! so they can shape anything, such as a volume fade or a shader parameter. They work on
+//! either float width, so an `f32` pipeline never converts to an `f64` and back.
+//!
+//! The tweens don't share that: [TweenTime::percent](crate::TweenTime::percent) and
+//! [TweenValue::scale](crate::TweenValue::scale) work in `f64` for every time and value, so a
+//! tween of `f32`s still eases in `f64`, with a conversion on each side. Call these functions
+//! directly where that conversion matters.
+//!
+//! ```
+//! use tween::functions;
+//!
+//! assert_eq!(functions::quad_in(0.5f32), 0.25);
+//! assert_eq!(functions::quad_in(0.5f64), 0.25);
+//!
+//! let volume = 0.8 * functions::sine_out(0.25f32);
+//! # assert!(volume < 0.8);
+//!
+//! // they can be used wherever an easing function is taken, too
+//! let ease: fn(f64) -> f64 = functions::bounce_out;
+//! # assert_eq!(ease(1.0), 1.0);
+//! ```
+
+use crate::math;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+const BACK_CONST: f64 = 1.70158;
+const BACK_IN_OUT_CONST: f64 = BACK_CONST * 1.525;
+
+const MAGIC: f64 = 7.5625;
+const STAGE_ZERO: f64 = 1.0 / 2.75;
+const STAGE_ONE: f64 = 2.0 / 2.75;
+const STAGE_TWO: f64 = 2.5 / 2.75;
+
+/// A float which the easing functions can work in, which is `f32` or `f64`.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Zero, of this width.
+    const ZERO: Self;
+    /// One, of this width.
+    const ONE: Self;
+    /// Two, of this width.
+    const TWO: Self;
+    /// Pi, of this width.
+    const PI: Self;
+
+    /// Converts a constant to this width. This is how the easings write their literals, and
+    /// is folded away when they're compiled.
+    fn from_f64(value: f64) -> Self;
+
+    /// The square root of `self`.
+    fn sqrt(self) -> Self;
+
+    /// `self` raised to the power of `exponent`.
+    fn powf(self, exponent: Self) -> Self;
+
+    /// The sine and cosine of `self`, in radians.
+    fn sin_cos(self) -> (Self, Self);
+}
+
+impl Float for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const TWO: Self = 2.0;
+    const PI: Self = core::f32::consts::PI;
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn sqrt(self) -> Self {
+        math::sqrt(self)
+    }
+
+    fn powf(self, exponent: Self) -> Self {
+        math::powf(self, exponent)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        math::sin_cos(self)
+    }
+}
+
+impl Float for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const TWO: Self = 2.0;
+    const PI: Self = core::f64::consts::PI;
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn sqrt(self) -> Self {
+        math::sqrt_f64(self)
+    }
+
+    fn powf(self, exponent: Self) -> Self {
+        math::powf_f64(self, exponent)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        math::sin_cos_f64(self)
+    }
+}
+
+/// No easing at all, which gives back `t`.
+pub fn linear<F: Float>(t: F) -> F {
+    t
+}
+
+/// A sine easing in. See [SineIn](crate::SineIn).
+pub fn sine_in<F: Float>(t: F) -> F {
+    F::ONE - F::sin_cos(t * F::PI / F::TWO).1
+}
+
+/// A sine easing out. See [SineOut](crate::SineOut).
+pub fn sine_out<F: Float>(t: F) -> F {
+    F::sin_cos(t * F::PI / F::TWO).0
+}
+
+/// A sine easing in and out. See [SineInOut](crate::SineInOut).
+pub fn sine_in_out<F: Float>(t: F) -> F {
+    -(F::sin_cos(t * F::PI).1 - F::ONE) / F::TWO
+}
+
+/// A quadratic easing in. See [QuadIn](crate::QuadIn).
+pub fn quad_in<F: Float>(t: F) -> F {
+    t * t
+}
+
+/// A quadratic easing out. See [QuadOut](crate::QuadOut).
+pub fn quad_out<F: Float>(t: F) -> F {
+    -t * (t - F::TWO)
+}
+
+/// A quadratic easing in and out. See [QuadInOut](crate::QuadInOut).
+pub fn quad_in_out<F: Float>(t: F) -> F {
+    let t = t * F::TWO;
+    if t < F::ONE {
+        t * t / F::TWO
+    } else {
+        let t = t - F::ONE;
+        -(t * (t - F::TWO) - F::ONE) / F::TWO
+    }
+}
+
+/// A cubic easing in. See [CubicIn](crate::CubicIn).
+pub fn cubic_in<F: Float>(t: F) -> F {
+    t * t * t
+}
+
+/// A cubic easing out. See [CubicOut](crate::CubicOut).
+pub fn cubic_out<F: Float>(t: F) -> F {
+    let t = t - F::ONE;
+    t * t * t + F::ONE
+}
+
+/// A cubic easing in and out. See [CubicInOut](crate::CubicInOut).
+pub fn cubic_in_out<F: Float>(t: F) -> F {
+    let t = t * F::TWO;
+    if t < F::ONE {
+        t * t * t / F::TWO
+    } else {
+        let t = t - F::TWO;
+        (t * t * t + F::TWO) / F::TWO
+    }
+}
+
+/// A quartic easing in. See [QuartIn](crate::QuartIn).
+pub fn quart_in<F: Float>(t: F) -> F {
+    t * t * t * t
+}
+
+/// A quartic easing out. See [QuartOut](crate::QuartOut).
+pub fn quart_out<F: Float>(t: F) -> F {
+    let t = t - F::ONE;
+    -(t * t * t * t - F::ONE)
+}
+
+/// A quartic easing in and out. See [QuartInOut](crate::QuartInOut).
+pub fn quart_in_out<F: Float>(t: F) -> F {
+    let t = t * F::TWO;
+    if t < F::ONE {
+        t * t * t * t / F::TWO
+    } else {
+        let t = t - F::TWO;
+        -(t * t * t * t - F::TWO) / F::TWO
+    }
+}
+
+/// A quintic easing in. See [QuintIn](crate::QuintIn).
+pub fn quint_in<F: Float>(t: F) -> F {
+    t * t * t * t * t
+}
+
+/// A quintic easing out. See [QuintOut](crate::QuintOut).
+pub fn quint_out<F: Float>(t: F) -> F {
+    let t = t - F::ONE;
+    t * t * t * t * t + F::ONE
+}
+
+/// A quintic easing in and out. See [QuintInOut](crate::QuintInOut).
+pub fn quint_in_out<F: Float>(t: F) -> F {
+    let t = t * F::TWO;
+    if t < F::ONE {
+        t * t * t * t * t / F::TWO
+    } else {
+        let t = t - F::TWO;
+        (t * t * t * t * t + F::TWO) / F::TWO
+    }
+}
+
+/// An exponential easing in. See [ExpoIn](crate::ExpoIn).
+pub fn expo_in<F: Float>(t: F) -> F {
+    if t == F::ZERO {
+        F::ZERO
+    } else {
+        F::powf(F::TWO, F::from_f64(10.0) * (t - F::ONE))
+    }
+}
+
+/// An exponential easing out. See [ExpoOut](crate::ExpoOut).
+pub fn expo_out<F: Float>(t: F) -> F {
+    if t == F::ONE {
+        F::ONE
+    } else {
+        F::ONE - F::powf(F::TWO, F::from_f64(-10.0) * t)
+    }
+}
+
+/// An exponential easing in and out. See [ExpoInOut](crate::ExpoInOut).
+pub fn expo_in_out<F: Float>(t: F) -> F {
+    if t == F::ZERO || t == F::ONE {
+        return t;
+    }
+
+    let t = t * F::TWO;
+    if t < F::ONE {
+        F::powf(F::TWO, F::from_f64(10.0) * (t - F::ONE)) / F::TWO
+    } else {
+        (F::TWO - F::powf(F::TWO, F::from_f64(-10.0) * (t - F::ONE))) / F::TWO
+    }
+}
+
+/// A circular easing in. See [CircIn](crate::CircIn).
+pub fn circ_in<F: Float>(t: F) -> F {
+    F::ONE - F::sqrt(F::ONE - t * t)
+}
+
+/// A circular easing out. See [CircOut](crate::CircOut).
+pub fn circ_out<F: Float>(t: F) -> F {
+    let t = t - F::ONE;
+    F::sqrt(F::ONE - t * t)
+}
+
+/// A circular easing in and out. See [CircInOut](crate::CircInOut).
+pub fn circ_in_out<F: Float>(t: F) -> F {
+    let t = t * F::TWO;
+    if t < F::ONE {
+        (F::ONE - F::sqrt(F::ONE - t * t)) / F::TWO
+    } else {
+        let t = t - F::TWO;
+        (F::sqrt(F::ONE - t * t) + F::ONE) / F::TWO
+    }
+}
+
+/// An easing which pulls back a bit before going in. See [BackIn](crate::BackIn).
+pub fn back_in<F: Float>(t: F) -> F {
+    t * t * ((F::from_f64(BACK_CONST) + F::ONE) * t - F::from_f64(BACK_CONST))
+}
+
+/// An easing which overshoots a bit on its way out. See [BackOut](crate::BackOut).
+pub fn back_out<F: Float>(t: F) -> F {
+    let t = t - F::ONE;
+    t * t * ((F::from_f64(BACK_CONST) + F::ONE) * t + F::from_f64(BACK_CONST)) + F::ONE
+}
+
+/// An easing which pulls back and overshoots a bit. See [BackInOut](crate::BackInOut).
+pub fn back_in_out<F: Float>(t: F) -> F {
+    let t = t * F::TWO;
+    if t < F::ONE {
+        t * t * ((F::from_f64(BACK_IN_OUT_CONST) + F::ONE) * t - F::from_f64(BACK_IN_OUT_CONST)) / F::TWO
+    } else {
+        let t = t - F::TWO;
+        (t * t * ((F::from_f64(BACK_IN_OUT_CONST) + F::ONE) * t + F::from_f64(BACK_IN_OUT_CONST)) + F::TWO) / F::TWO
+    }
+}
+
+/// An elastic easing in. See [ElasticIn](crate::ElasticIn).
+pub fn elastic_in<F: Float>(t: F) -> F {
+    if t == F::ZERO || t == F::ONE {
+        return t;
+    }
+
+    let t = t - F::ONE;
+    -F::powf(F::TWO, F::from_f64(10.0) * t)
+        * F::sin_cos((t - F::from_f64(0.075)) * (F::TWO * F::PI) / F::from_f64(0.3)).0
+}
+
+/// An elastic easing out. See [ElasticOut](crate::ElasticOut).
+pub fn elastic_out<F: Float>(t: F) -> F {
+    if t == F::ZERO || t == F::ONE {
+        return t;
+    }
+
+    F::powf(F::TWO, F::from_f64(-10.0) * t)
+        * F::sin_cos((t - F::from_f64(0.075)) * (F::TWO * F::PI) / F::from_f64(0.3)).0
+        + F::ONE
+}
+
+/// An elastic easing in and out. See [ElasticInOut](crate::ElasticInOut).
+pub fn elastic_in_out<F: Float>(t: F) -> F {
+    if t == F::ZERO || t == F::ONE {
+        return t;
+    }
+
+    let t = t * F::TWO - F::ONE;
+    let sin = F::sin_cos((t - F::from_f64(0.1125)) * (F::TWO * F::PI) / F::from_f64(0.45)).0;
+    if t < F::ZERO {
+        -F::from_f64(0.5) * F::powf(F::TWO, F::from_f64(10.0) * t) * sin
+    } else {
+        F::from_f64(0.5) * F::powf(F::TWO, F::from_f64(-10.0) * t) * sin + F::ONE
+    }
+}
+
+/// A bouncing easing in. See [BounceIn](crate::BounceIn).
+pub fn bounce_in<F: Float>(t: F) -> F {
+    F::ONE - bounce_out(F::ONE - t)
+}
+
+/// A bouncing easing out. See [BounceOut](crate::BounceOut).
+pub fn bounce_out<F: Float>(t: F) -> F {
+    if t < F::from_f64(STAGE_ZERO) {
+        F::from_f64(MAGIC) * t * t
+    } else if t < F::from_f64(STAGE_ONE) {
+        let t = t - F::from_f64(1.5) / F::from_f64(2.75);
+        F::from_f64(MAGIC) * t * t + F::from_f64(0.75)
+    } else if t < F::from_f64(STAGE_TWO) {
+        let t = t - F::from_f64(2.25) / F::from_f64(2.75);
+        F::from_f64(MAGIC) * t * t + F::from_f64(0.9375)
+    } else {
+        let t = t - F::from_f64(2.625) / F::from_f64(2.75);
+        F::from_f64(MAGIC) * t * t + F::from_f64(0.984375)
+    }
+}
+
+/// A bouncing easing in and out. See [BounceInOut](crate::BounceInOut).
+pub fn bounce_in_out<F: Float>(t: F) -> F {
+    if t < F::from_f64(0.5) {
+        bounce_in(t * F::TWO) / F::TWO
+    } else {
+        bounce_out(t * F::TWO - F::ONE) / F::TWO + F::from_f64(0.5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Ease;
+
+    #[test]
+    fn matches_tweens() {
+        for &ease in Ease::ALL {
+            let function = ease.function();
+            let mut tween = ease.tween(0.0f32..=1.0, 20.0f32);
+            let mut wide = ease.tween(0.0f64..=1.0, 20.0f64);
+            for step in 0..=20 {
+                let t = step as f32 / 20.0;
+                let expected = crate::Tween::run_percent(&mut tween, t as f64);
+                assert!((function(t) - expected).abs() < 1e-5, "{:?} at {}", ease, t);
+
+                let t = t as f64;
+                let expected = crate::Tween::run_percent(&mut wide, t);
+                assert!((ease.function_f64()(t) - expected).abs() < 1e-9, "{:?} at {}", ease, t);
+            }
+        }
+    }
+}
@@ -1,3 +1,25 @@
+/// Implements [TweenValue](crate::TweenValue) for a glam type which can be added,
+/// subtracted, and scaled by its own float type.
+macro_rules! glam_value {
+    ($t:ty, $zero:expr, $float:ty) => {
+        impl crate::TweenValue for $t {
+            const ZERO: Self = $zero;
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * scale as $float
+            }
+        }
+    };
+}
+
 impl crate::TweenValue for glam::Vec2 {
     const ZERO: Self = Self::ZERO;
 
@@ -45,3 +67,141 @@ impl crate::TweenValue for glam::DVec2 {
         self * scale
     }
 }
+
+impl crate::TweenValue for glam::Vec3 {
+    const ZERO: Self = Self::ZERO;
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        destination - start
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        (self.as_dvec3() * scale).as_vec3()
+    }
+}
+
+impl crate::TweenValue for glam::Vec4 {
+    const ZERO: Self = Self::ZERO;
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        destination - start
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        (self.as_dvec4() * scale).as_vec4()
+    }
+}
+
+glam_value!(glam::Vec3A, glam::Vec3A::ZERO, f32);
+glam_value!(glam::DVec3, glam::DVec3::ZERO, f64);
+glam_value!(glam::DVec4, glam::DVec4::ZERO, f64);
+glam_value!(glam::Mat4, glam::Mat4::ZERO, f32);
+glam_value!(glam::DMat4, glam::DMat4::ZERO, f64);
+
+// Quaternions are tweened component-wise, which is cheap, but means that the in-between
+// values are generally *not* normalized. Normalize them before using them as rotations.
+glam_value!(glam::Quat, glam::Quat::from_xyzw(0.0, 0.0, 0.0, 0.0), f32);
+glam_value!(glam::DQuat, glam::DQuat::from_xyzw(0.0, 0.0, 0.0, 0.0), f64);
+
+impl From<glam::Quat> for crate::Rotation {
+    fn from(q: glam::Quat) -> Self {
+        Self::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<crate::Rotation> for glam::Quat {
+    fn from(rotation: crate::Rotation) -> Self {
+        glam::Quat::from_array(rotation.to_xyzw())
+    }
+}
+
+impl From<crate::Transform3D> for glam::Mat4 {
+    fn from(transform: crate::Transform3D) -> Self {
+        glam::Mat4::from_scale_rotation_translation(
+            transform.scale.into(),
+            transform.rotation.into(),
+            transform.translation.into(),
+        )
+    }
+}
+
+/// The matrix must not have any shear, which a [Transform3D](crate::Transform3D) can't hold.
+impl From<glam::Mat4> for crate::Transform3D {
+    fn from(matrix: glam::Mat4) -> Self {
+        let (scale, rotation, translation) = matrix.to_scale_rotation_translation();
+        Self::new(translation.into(), rotation.into(), scale.into())
+    }
+}
+
+impl From<crate::Transform3D> for glam::Affine3A {
+    fn from(transform: crate::Transform3D) -> Self {
+        glam::Affine3A::from_scale_rotation_translation(
+            transform.scale.into(),
+            transform.rotation.into(),
+            transform.translation.into(),
+        )
+    }
+}
+
+/// The transform must not have any shear, which a [Transform3D](crate::Transform3D) can't hold.
+impl From<glam::Affine3A> for crate::Transform3D {
+    fn from(affine: glam::Affine3A) -> Self {
+        let (scale, rotation, translation) = affine.to_scale_rotation_translation();
+        Self::new(translation.into(), rotation.into(), scale.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Linear, Tween};
+    use glam::{Mat4, Quat, Vec3, Vec4};
+
+    #[test]
+    fn vectors() {
+        let mut tween = Linear::new(Vec3::ZERO..=Vec3::new(2.0, 4.0, 8.0), 4.0);
+        assert_eq!(tween.run(1.0), Vec3::new(0.5, 1.0, 2.0));
+
+        let mut tween = Linear::new(Vec4::ZERO..=Vec4::ONE, 2.0);
+        assert_eq!(tween.run(1.0), Vec4::splat(0.5));
+    }
+
+    #[test]
+    fn quat_and_mat4() {
+        let mut tween = Linear::new(Quat::IDENTITY..=Quat::from_xyzw(1.0, 0.0, 0.0, 0.0), 2.0);
+        assert_eq!(tween.run(1.0), Quat::from_xyzw(0.5, 0.0, 0.0, 0.5));
+
+        let mut tween = Linear::new(Mat4::ZERO..=Mat4::IDENTITY, 2.0);
+        assert_eq!(tween.run(2.0), Mat4::IDENTITY);
+        assert_eq!(tween.range(), Mat4::ZERO..=Mat4::IDENTITY);
+    }
+
+    #[test]
+    fn rotations() {
+        let end = Quat::from_rotation_y(1.0);
+        let mut tween = Linear::new(crate::Rotation::IDENTITY..=end.into(), 2.0);
+
+        let halfway: Quat = tween.run(1.0).into();
+        assert!(halfway.abs_diff_eq(Quat::from_rotation_y(0.5), 1e-6));
+    }
+
+    #[test]
+    fn transforms() {
+        let matrix = Mat4::from_scale_rotation_translation(
+            Vec3::new(1.0, 2.0, 3.0),
+            Quat::from_rotation_z(1.0),
+            Vec3::new(4.0, 5.0, 6.0),
+        );
+
+        let mut tween = Linear::new(crate::Transform3D::IDENTITY..=matrix.into(), 2.0);
+        let end: Mat4 = tween.run(2.0).into();
+        assert!(end.abs_diff_eq(matrix, 1e-5));
+    }
+}
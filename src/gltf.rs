@@ -0,0 +1,241 @@
+//! Animations imported from glTF, so the animations of 3D assets are played by the same
+//! runtime as tweens made in code.
+//!
+//! Each channel of a glTF animation has a sampler, which is a list of keyframe times, its
+//! `input`, a value for each of them, its `output`, and how to interpolate between them. Once
+//! those accessors are read, with the `gltf` crate or any other loader, they make a [Sampler],
+//! which is [instantiated](Sampler::instantiate) into a [Sequence] that plays the channel.
+//!
+//! ```
+//! # use tween::{gltf::{Interpolation, Sampler}, Tween};
+//! let sampler = Sampler {
+//!     input: &[0.0, 1.0, 3.0],
+//!     output: &[[0.0f32, 0.0, 0.0], [1.0, 2.0, 0.0], [1.0, 2.0, 4.0]],
+//!     interpolation: Interpolation::Linear,
+//! };
+//!
+//! let mut translation = sampler.instantiate().unwrap();
+//! assert_eq!(translation.run(2.0), [1.0, 2.0, 2.0]);
+//! ```
+//!
+//! Rotations are quaternions, which are interpolated linearly here rather than spherically,
+//! so they should be normalized before they're used.
+
+use crate::{Hermite, Sequence, StepPosition, Steps, TimingFunction, TimingTween, Tween, TweenValue};
+use core::{fmt, ops::RangeInclusive};
+
+/// How a [Sampler] interpolates between its keyframes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+pub enum Interpolation {
+    /// Each keyframe holds its value until the next one, which is `STEP` in glTF.
+    Step,
+    /// Each keyframe moves straight to the next one, which is `LINEAR` in glTF.
+    Linear,
+    /// Each keyframe moves to the next one along a cubic Hermite curve, which leaves and
+    /// arrives with the tangents given by the keyframes. This is `CUBICSPLINE` in glTF.
+    CubicSpline,
+}
+
+impl Interpolation {
+    /// Finds the interpolation with the given glTF name, such as `"CUBICSPLINE"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "STEP" => Some(Self::Step),
+            "LINEAR" => Some(Self::Linear),
+            "CUBICSPLINE" => Some(Self::CubicSpline),
+            _ => None,
+        }
+    }
+
+    /// The number of outputs of a sampler with this interpolation for each keyframe, which is
+    /// three for `CUBICSPLINE`, as its outputs are an in tangent, a value, and an out tangent.
+    pub fn outputs_per_keyframe(self) -> usize {
+        match self {
+            Self::Step | Self::Linear => 1,
+            Self::CubicSpline => 3,
+        }
+    }
+}
+
+/// A glTF animation sampler, whose accessors have been read into slices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sampler<'a, V> {
+    /// The times of the keyframes, in seconds.
+    pub input: &'a [f32],
+    /// The values of the keyframes. For [Interpolation::CubicSpline], this is an in tangent, a
+    /// value, and an out tangent for each keyframe, one after another.
+    pub output: &'a [V],
+    /// How to interpolate between the keyframes.
+    pub interpolation: Interpolation,
+}
+
+/// Why a [Sampler] couldn't be instantiated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SamplerError {
+    /// The sampler has no keyframes.
+    NoKeyframes,
+    /// The sampler doesn't have the right number of outputs for its keyframes.
+    OutputCount {
+        /// The number of outputs for the keyframes and the interpolation.
+        expected: usize,
+        /// The number of outputs the sampler has.
+        found: usize,
+    },
+    /// A keyframe is at an earlier time than the keyframe before it.
+    UnorderedKeyframes {
+        /// The index of the keyframe which is out of order.
+        index: usize,
+    },
+}
+
+impl fmt::Display for SamplerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoKeyframes => f.write_str("the sampler has no keyframes"),
+            Self::OutputCount { expected, found } => write!(f, "expected {expected} outputs, found {found}"),
+            Self::UnorderedKeyframes { index } => write!(f, "keyframe {index} is before the keyframe before it"),
+        }
+    }
+}
+
+impl std::error::Error for SamplerError {}
+
+impl<V> Sampler<'_, V>
+where
+    V: TweenValue,
+{
+    /// Makes a [Sequence] which plays this sampler from the start of the animation. Until the
+    /// first keyframe, it holds the first value, and after the last, the last value, as glTF
+    /// does.
+    pub fn instantiate(&self) -> Result<Sequence<SamplerTween<V>>, SamplerError> {
+        let per_keyframe = self.interpolation.outputs_per_keyframe();
+        let expected = self.input.len() * per_keyframe;
+        if self.output.len() != expected {
+            return Err(SamplerError::OutputCount {
+                expected,
+                found: self.output.len(),
+            });
+        }
+
+        let Some(&first_time) = self.input.first() else {
+            return Err(SamplerError::NoKeyframes);
+        };
+
+        // the value of a cubic spline keyframe is in between its tangents
+        let value = |index: usize| self.output[index * per_keyframe + per_keyframe / 2];
+
+        let hold = TimingFunction::Linear.tween(value(0)..=value(0), first_time);
+        let mut sequence = Sequence::new(SamplerTween::Timing(hold));
+        for (index, pair) in self.input.windows(2).enumerate() {
+            let duration = pair[1] - pair[0];
+            if duration < 0.0 {
+                return Err(SamplerError::UnorderedKeyframes { index: index + 1 });
+            }
+
+            let range = value(index)..=value(index + 1);
+            sequence.push(match self.interpolation {
+                Interpolation::Step => {
+                    let step = TimingFunction::Steps(Steps::new(1, StepPosition::JumpEnd));
+                    SamplerTween::Timing(step.tween(range, duration))
+                }
+                Interpolation::Linear => SamplerTween::Timing(TimingFunction::Linear.tween(range, duration)),
+                Interpolation::CubicSpline => {
+                    let out_tangent = self.output[index * 3 + 2];
+                    let in_tangent = self.output[(index + 1) * 3];
+                    SamplerTween::Hermite(Hermite::with_velocities(range, out_tangent, in_tangent, duration))
+                }
+            });
+        }
+
+        Ok(sequence)
+    }
+}
+
+/// The tween between two keyframes of a [Sampler].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SamplerTween<V> {
+    /// A `STEP` or `LINEAR` keyframe.
+    Timing(TimingTween<V, f32>),
+    /// A `CUBICSPLINE` keyframe.
+    Hermite(Hermite<V, f32>),
+}
+
+impl<V> Tween for SamplerTween<V>
+where
+    V: TweenValue,
+{
+    type Value = V;
+    type Time = f32;
+
+    fn run(&mut self, new_time: f32) -> V {
+        match self {
+            Self::Timing(tween) => tween.run(new_time),
+            Self::Hermite(tween) => tween.run(new_time),
+        }
+    }
+
+    fn run_percent(&mut self, percent: f64) -> V {
+        match self {
+            Self::Timing(tween) => tween.run_percent(percent),
+            Self::Hermite(tween) => tween.run_percent(percent),
+        }
+    }
+
+    fn range(&self) -> RangeInclusive<V> {
+        match self {
+            Self::Timing(tween) => tween.range(),
+            Self::Hermite(tween) => tween.range(),
+        }
+    }
+
+    fn duration(&self) -> f32 {
+        match self {
+            Self::Timing(tween) => tween.duration(),
+            Self::Hermite(tween) => tween.duration(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instantiate() {
+        let input = [1.0, 2.0, 4.0];
+        let mut sampler = Sampler {
+            input: &input,
+            output: &[0.0, 10.0, 30.0],
+            interpolation: Interpolation::Step,
+        };
+
+        let mut step = sampler.instantiate().unwrap();
+        assert_eq!(step.duration(), 4.0);
+        assert_eq!(
+            [0.5, 1.5, 2.0, 3.9, 5.0].map(|time| step.run(time)),
+            [0.0, 0.0, 10.0, 10.0, 30.0]
+        );
+
+        sampler.interpolation = Interpolation::Linear;
+        let mut linear = sampler.instantiate().unwrap();
+        assert_eq!([1.5, 3.0].map(|time| linear.run(time)), [5.0, 20.0]);
+
+        sampler.interpolation = Interpolation::CubicSpline;
+        assert_eq!(
+            sampler.instantiate().unwrap_err(),
+            SamplerError::OutputCount { expected: 9, found: 3 }
+        );
+
+        let output = [0.0, 0.0, 5.0, -5.0, 10.0, 0.0, 0.0, 30.0, 0.0];
+        sampler.output = &output;
+        let mut spline = sampler.instantiate().unwrap();
+        assert_eq!(
+            spline.run(1.5),
+            Hermite::with_velocities(0.0..=10.0, 5.0, -5.0, 1.0).run(0.5)
+        );
+        assert_eq!(spline.run(2.0), 10.0);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{TweenTime, TweenValue, functions, math};
+use half::{bf16, f16};
+
+/// Implements [TweenValue], [TweenTime], and [Float](functions::Float) for a half float, which
+/// is widened to do its math, since half floats have no math of their own.
+macro_rules! half_float {
+    ($float:ty) => {
+        impl TweenValue for $float {
+            const ZERO: Self = <$float>::ZERO;
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                <$float>::from_f64(self.to_f64() * scale)
+            }
+        }
+
+        impl TweenTime for $float {
+            const ZERO: Self = <$float>::ZERO;
+
+            fn percent(duration: Self, current_time: Self) -> f64 {
+                current_time.to_f64() / duration.to_f64()
+            }
+
+            fn as_f64(self) -> f64 {
+                self.to_f64()
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn sub(self, other: Self) -> Self {
+                self - other
+            }
+
+            fn scale(self, multiplier: f64) -> Self {
+                <$float>::from_f64(self.to_f64() * multiplier)
+            }
+
+            fn is_complete(self, duration: Self) -> bool {
+                self >= duration
+            }
+        }
+
+        impl functions::Float for $float {
+            const ZERO: Self = <$float>::ZERO;
+            const ONE: Self = <$float>::ONE;
+            const TWO: Self = <$float>::from_f32_const(2.0);
+            const PI: Self = <$float>::PI;
+
+            fn from_f64(value: f64) -> Self {
+                <$float>::from_f64(value)
+            }
+
+            fn sqrt(self) -> Self {
+                <$float>::from_f32(math::sqrt(self.to_f32()))
+            }
+
+            fn powf(self, exponent: Self) -> Self {
+                <$float>::from_f32(math::powf(self.to_f32(), exponent.to_f32()))
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = math::sin_cos(self.to_f32());
+                (<$float>::from_f32(sin), <$float>::from_f32(cos))
+            }
+        }
+    };
+}
+
+half_float!(f16);
+half_float!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Linear, QuadIn, functions};
+    use half::{bf16, f16};
+
+    #[test]
+    fn half_floats() {
+        let mut tween = Linear::new(f16::from_f32(0.0)..=f16::from_f32(10.0), f16::from_f32(4.0));
+        assert_eq!(tween.run(f16::from_f32(1.0)), f16::from_f32(2.5));
+
+        let mut tween = QuadIn::new(bf16::from_f32(0.0)..=bf16::from_f32(8.0), 2u32);
+        assert_eq!(tween.run(1), bf16::from_f32(2.0));
+
+        assert_eq!(functions::quad_in(f16::from_f32(0.5)), f16::from_f32(0.25));
+        assert_eq!(functions::sine_out(f16::ONE), f16::ONE);
+    }
+}
@@ -4,7 +4,7 @@
 #![deny(rustdoc::all)]
 #![no_std]
 
-#[cfg(any(feature = "std"))]
+#[cfg(feature = "std")]
 #[macro_use]
 extern crate std;
 
@@ -16,22 +16,116 @@ compile_error!(
 #[macro_use]
 mod macros;
 
+mod adapters;
+pub mod android;
+#[cfg(feature = "std")]
+mod bank;
+mod color;
+#[cfg(feature = "std")]
+pub mod definitions;
+mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod flutter;
+pub mod functions;
+#[cfg(feature = "std")]
+pub mod gltf;
+#[cfg(feature = "std")]
+pub mod loop_driver;
+#[cfg(feature = "lottie")]
+pub mod lottie;
+#[cfg(feature = "std")]
+mod manager;
+mod math;
+mod path;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+mod q32;
+mod rng;
+#[cfg(all(test, feature = "serde"))]
+mod round_trip;
+mod time;
 mod tweener;
 mod tweens;
+#[cfg(feature = "std")]
+pub mod unity;
+mod values;
+
+#[cfg(feature = "bevy_math")]
+mod bevy_math;
+
+#[cfg(feature = "cgmath")]
+mod cgmath;
+
+#[cfg(feature = "easer")]
+mod easer;
+
+#[cfg(feature = "euclid")]
+mod euclid;
+
+#[cfg(feature = "fixed")]
+mod fixed;
 
 #[cfg(feature = "glam")]
 mod glam;
 
+#[cfg(feature = "half")]
+mod half;
+
+#[cfg(feature = "mint")]
+mod mint;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "num-complex")]
+mod num_complex;
+
+#[cfg(feature = "num-traits")]
+mod num_traits;
+
+#[cfg(feature = "palette")]
+mod palette;
+
+#[cfg(feature = "ultraviolet")]
+mod ultraviolet;
+
+#[cfg(feature = "wide")]
+mod wide;
+
+pub use adapters::*;
+#[cfg(feature = "std")]
+pub use bank::TweenBank;
+pub use color::*;
+#[cfg(feature = "easer")]
+pub use easer::{EaserMode, EaserTween};
+#[cfg(feature = "std")]
+pub use export::BakedCurve;
+pub use export::{Channels, CurveInfo, Samples};
+#[cfg(feature = "std")]
+pub use manager::*;
+#[cfg(feature = "num-traits")]
+pub use num_traits::NumFloat;
+pub use path::*;
+pub use q32::{Q32, Q32Tween};
+pub use time::{Beats, TempoChange, TempoMap, WrappingTime};
 pub use tweener::*;
 pub use tweens::*;
+pub use values::*;
+
+#[cfg(feature = "derive")]
+pub use tween_derive::TweenValue;
+
+// lets the derive macro's `::tween` paths work inside this crate too
+#[cfg(all(test, feature = "derive"))]
+extern crate self as tween;
 
 use core::ops::RangeInclusive;
-use ultraviolet::{Vec2, Vec3, Vec4};
 
 /// This is the core trait of the Library, which all `tweens` implement.
 ///
-/// If you choose to use a Tween directly, rather than through a [DeltaTweener]
-/// or [FixedDeltaTweener], you'll rarely deal with this directly.
+/// If you choose to use a Tween directly, rather than through a [Tweener]
+/// or [FixedTweener], you'll rarely deal with this directly.
 pub trait Tween: Sized {
     /// This is the value which we tween over time.
     type Value: TweenValue;
@@ -40,10 +134,79 @@ pub trait Tween: Sized {
     type Time: TweenTime;
 
     /// Run the given Tween with a new time.
+    ///
+    /// Times before zero hold the start value of the tween. Wrap a tween in an
+    /// [Extrapolate] to carry on its curve instead. A tween with a duration of zero has
+    /// already finished, so it always gives its end value.
     fn run(&mut self, new_time: Self::Time) -> Self::Value;
 
-    /// Get a reference to the Tween's range.
-    fn range(&self) -> &RangeInclusive<Self::Value>;
+    /// Run the given Tween at a percentage of the way through its duration, where `0.0` is
+    /// its start and `1.0` is its end. This is for callers which already track their own
+    /// progress, and would otherwise multiply it by the duration only for it to be divided
+    /// back out.
+    ///
+    /// By default, this scales the duration and runs the tween with that time, but the
+    /// easings of this library are evaluated from the percentage directly.
+    ///
+    /// ```
+    /// # use tween::{QuadIn, Tween};
+    /// let mut tween = QuadIn::new(0.0..=100.0, 60);
+    /// assert_eq!(tween.run_percent(0.5), 25.0);
+    /// ```
+    fn run_percent(&mut self, percent: f64) -> Self::Value {
+        let new_time = self.duration().scale(percent);
+        self.run(new_time)
+    }
+
+    /// Fill the first `steps` values of `buffer` with samples evenly spaced across the tween,
+    /// from its start to its end inclusive, in one pass. This is handy for uploading a curve to
+    /// a GPU, or for baking an animation strip ahead of time.
+    ///
+    /// A single step is the tween's start, and zero steps leaves the buffer alone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than `steps`.
+    ///
+    /// ```
+    /// # use tween::{Linear, Tween};
+    /// let mut strip = [0.0f32; 5];
+    /// Linear::new(0.0..=100.0, 2.0).bake_into(&mut strip, 5);
+    /// assert_eq!(strip, [0.0, 25.0, 50.0, 75.0, 100.0]);
+    /// ```
+    fn bake_into(&mut self, buffer: &mut [Self::Value], steps: usize) {
+        profile_span!("Tween::bake_into");
+        profile_count!("samples", steps);
+
+        let last = steps.saturating_sub(1).max(1) as f64;
+        for (i, value) in buffer[..steps].iter_mut().enumerate() {
+            *value = self.run_percent(i as f64 / last);
+        }
+    }
+
+    /// An iterator of `(time, value)` pairs every `step` across the tween, from its start up
+    /// to and including its end, for plotting a curve, checking it in tests, or writing it out
+    /// as rows. This doesn't allocate, and each value is only found once it's asked for.
+    ///
+    /// The last pair is always the end of the tween, even if `step` doesn't divide its
+    /// duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` isn't more than zero.
+    ///
+    /// ```
+    /// # use tween::{QuadIn, Tween};
+    /// let mut tween = QuadIn::new(0..=100, 10);
+    /// let samples: Vec<_> = tween.samples(4).collect();
+    /// assert_eq!(samples, [(0, 0), (4, 16), (8, 64), (10, 100)]);
+    /// ```
+    fn samples(&mut self, step: Self::Time) -> Samples<'_, Self> {
+        Samples::new(self, step)
+    }
+
+    /// Get the Tween's range.
+    fn range(&self) -> RangeInclusive<Self::Value>;
 
     /// Get a reference to the Tween's total duration.
     fn duration(&self) -> Self::Time;
@@ -84,6 +247,10 @@ pub trait TweenValue: Copy {
 /// A `TweenTime` is a representation of Time. The two most common will be `f32`/`f64` for
 /// seconds and `u32`/`u64`/`usize` for frames.
 ///
+/// Integers work just as well for milliseconds, or for the ticks of an embedded timer or an
+/// audio clock. The 64 bit integers are divided and scaled with integer math, rather than
+/// going through floats, so even huge tick counts stay exact.
+///
 /// If you want to implement your own time for duration, then you'll need to implement this
 /// trait somewhere.
 ///
@@ -112,11 +279,11 @@ pub trait TweenTime: Copy + PartialEq {
 declare_time!(u8);
 declare_time!(i8);
 declare_time!(i32);
-declare_time!(i64);
+declare_time!(wide i64);
 declare_time!(u32);
-declare_time!(u64);
-declare_time!(usize);
-declare_time!(isize);
+declare_time!(wide u64);
+declare_time!(wide usize);
+declare_time!(wide isize);
 declare_time!(float f32);
 declare_time!(float f64);
 
@@ -125,26 +292,8 @@ declare_value!(float f64);
 declare_value!(u8);
 declare_value!(i8);
 declare_value!(i32);
-declare_value!(i64);
+declare_value!(wide i64);
 declare_value!(u32);
-declare_value!(u64);
-declare_value!(usize);
-declare_value!(isize);
-
-        impl TweenValue for Vec3 {
-            const ZERO: Self = Vec3::new(0.0,0.0,0.0);
-
-            fn add(self, other: Self) -> Self {
-                self + other
-            }
-
-            fn calculate_delta(destination: Self, start: Self) -> Self {
-                destination - start
-            }
-
-            fn scale(self, scale: f64) -> Self {
-                (self * scale as f32) as Vec3
-            }
-        }
-
-
+declare_value!(wide u64);
+declare_value!(wide usize);
+declare_value!(wide isize);
@@ -0,0 +1,171 @@
+//! A driver for the fixed update of a game loop, which works the same in macroquad, ggez,
+//! winit, or a loop of your own.
+//!
+//! Every engine hands out a variable frame time, such as macroquad's `get_frame_time()`,
+//! ggez's `ctx.time.delta()`, or the time between two winit redraws. A [TickContext] gathers
+//! those frame times into fixed ticks, which keeps animations in step with a fixed rate
+//! simulation, and gives the [alpha](TickContext::alpha) to interpolate between the last two
+//! ticks when drawing.
+//!
+//! ```
+//! # use tween::{loop_driver::TickContext, Linear, TweenManager};
+//! let mut manager = TweenManager::new();
+//! manager.insert("x", Linear::new(0.0..=60.0, 1.0));
+//!
+//! let mut ticks = TickContext::new(1.0 / 60.0);
+//! let mut previous = 0.0;
+//! let mut current = 0.0;
+//! loop {
+//!     let frame_time = 1.0 / 144.0; // macroquad::time::get_frame_time(), say
+//!     ticks.tick(frame_time, |step| {
+//!         previous = current;
+//!         manager.update(step);
+//!         current = manager.get(&"x").unwrap();
+//!     });
+//!
+//!     let x = ticks.interpolate(previous, current);
+//!     // draw at `x`
+//! #   assert!((previous..=current).contains(&x));
+//!     if !manager.is_active(&"x") {
+//!         break;
+//!     }
+//! }
+//! ```
+//!
+//! Unlike [Accumulator](crate::Accumulator), which drives a single tween, a [TickContext]
+//! doesn't own anything it drives, so one context can tick a whole scene.
+
+use crate::{Tween, TweenManager, TweenTime, TweenValue};
+use core::hash::Hash;
+
+/// Gathers variable frame times into fixed ticks. See the [module](self) for how it's used.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TickContext<T> {
+    step: T,
+    residual: T,
+    max_ticks: u32,
+    ticks: u64,
+}
+
+impl<T> TickContext<T>
+where
+    T: TweenTime,
+{
+    /// Creates a new [TickContext], which ticks every `step`, and catches up at most eight
+    /// ticks in one frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, since every frame would be an endless number of ticks.
+    pub fn new(step: T) -> Self {
+        assert!(step != T::ZERO, "a tick context's step cannot be zero");
+
+        Self {
+            step,
+            residual: T::ZERO,
+            max_ticks: 8,
+            ticks: 0,
+        }
+    }
+
+    /// Sets the most ticks which are taken in one frame. After a long stall, such as the
+    /// window being dragged, the time past that is dropped, rather than the loop falling
+    /// further behind as it tries to catch up.
+    pub fn with_max_ticks(self, max_ticks: u32) -> Self {
+        Self { max_ticks, ..self }
+    }
+
+    /// Adds the time of a frame, calling `update` with the step for each tick which fits into
+    /// the time gathered so far. Returns the number of ticks taken.
+    pub fn tick(&mut self, frame_time: T, mut update: impl FnMut(T)) -> u32 {
+        self.residual = self.residual.add(frame_time);
+
+        let mut ticks = 0;
+        while self.residual.is_complete(self.step) {
+            if ticks == self.max_ticks {
+                self.residual = T::ZERO;
+                break;
+            }
+
+            self.residual = self.residual.sub(self.step);
+            update(self.step);
+            ticks += 1;
+        }
+
+        self.ticks += u64::from(ticks);
+        ticks
+    }
+
+    /// Adds the time of a frame, updating `manager` once for each tick, like [tick].
+    ///
+    /// [tick]: Self::tick
+    pub fn tick_manager<K, Tw>(&mut self, manager: &mut TweenManager<K, Tw>, frame_time: T) -> u32
+    where
+        K: Eq + Hash,
+        Tw: Tween<Time = T>,
+    {
+        self.tick(frame_time, |step| manager.update(step))
+    }
+
+    /// How far, from `0.0` to `1.0`, the time left over is between the last tick and the next
+    /// one.
+    pub fn alpha(&self) -> f64 {
+        T::percent(self.step, self.residual)
+    }
+
+    /// Interpolates between the values of the last two ticks by the [alpha](Self::alpha), which
+    /// is the value to draw.
+    pub fn interpolate<V: TweenValue>(&self, previous: V, current: V) -> V {
+        V::calculate_delta(current, previous).scale(self.alpha()).add(previous)
+    }
+
+    /// The number of ticks taken so far.
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// The fixed step of each tick.
+    pub fn step(&self) -> T {
+        self.step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_and_alpha() {
+        let mut context = TickContext::new(4u32);
+        let mut steps = 0;
+
+        assert_eq!(context.tick(10, |step| steps += step), 2);
+        assert_eq!(steps, 8);
+        assert_eq!(context.alpha(), 0.5);
+        assert_eq!(context.interpolate(10.0, 20.0), 15.0);
+
+        assert_eq!(context.tick(1, |step| steps += step), 0);
+        assert_eq!(context.tick(1, |step| steps += step), 1);
+        assert_eq!(context.ticks(), 3);
+        assert_eq!(context.alpha(), 0.0);
+    }
+
+    #[test]
+    fn drops_time_past_max_ticks() {
+        let mut context = TickContext::new(1.0).with_max_ticks(3);
+
+        assert_eq!(context.tick(10.5, |_| {}), 3);
+        assert_eq!(context.alpha(), 0.0);
+        assert_eq!(context.tick(0.5, |_| {}), 0);
+    }
+
+    #[test]
+    fn tick_manager() {
+        let mut manager = TweenManager::new();
+        manager.insert(0, crate::Linear::new(0..=10, 10));
+
+        let mut context = TickContext::new(2);
+        assert_eq!(context.tick_manager(&mut manager, 5), 2);
+        assert_eq!(manager.get(&0), Some(4));
+    }
+}
@@ -0,0 +1,276 @@
+//! Easing imported from Lottie animations, which are exported from After Effects with
+//! Bodymovin, and are enabled by the `lottie` feature.
+//!
+//! An animated property of a Lottie file has a list of keyframes under `k`, each of which eases
+//! into the next along a cubic bezier, whose control points are the `o` handle going out of
+//! the keyframe and the `i` handle going into the next one:
+//!
+//! ```json
+//! "k": [
+//!     { "t": 0, "s": [0, 0], "o": { "x": [0.333], "y": [0] }, "i": { "x": [0.667], "y": [1] } },
+//!     { "t": 30, "s": [100, 50], "h": 1 },
+//!     { "t": 60, "s": [0, 0] }
+//! ]
+//! ```
+//!
+//! Those keyframes are deserialized into a list of [Keyframe]s, with any serde format, and
+//! [instantiated](instantiate) into a [Sequence] of [TimingTween]s, which plays the property.
+//! Times in Lottie are in frames, so they're divided by the frame rate of the animation, its
+//! `fr`, to give seconds.
+//!
+//! ```
+//! # use tween::{lottie::{self, Handle, Keyframe}, Tween};
+//! let mut keyframes = [Keyframe::new(0.0, [0.0f32]), Keyframe::new(30.0, [100.0])];
+//! keyframes[0].out_handle = Some(Handle::new(0.42, 0.0));
+//! keyframes[0].in_handle = Some(Handle::new(0.58, 1.0));
+//!
+//! let mut sequence = lottie::instantiate(&keyframes, 60.0).unwrap();
+//! assert_eq!(sequence.duration(), 0.5);
+//! assert_eq!(sequence.run(0.25), [50.0]);
+//! ```
+
+use crate::{CubicBezier, Sequence, StepPosition, Steps, TimingFunction, TimingTween, TweenValue};
+use core::fmt;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::vec::Vec;
+
+/// One keyframe of an animated Lottie property.
+///
+/// Values are lists of numbers in Lottie, even for one dimensional properties like opacity,
+/// so `V` is an array, such as `[f32; 1]` for opacity or `[f32; 2]` for a position.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Keyframe<V> {
+    /// The frame of this keyframe.
+    #[serde(rename = "t")]
+    pub time: f64,
+    /// The value at this keyframe. Older versions of Bodymovin leave this out of the last
+    /// keyframe, since it's the end value of the keyframe before it.
+    #[serde(rename = "s", default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<V>,
+    /// The value which this keyframe eases into, which older versions of Bodymovin give rather
+    /// than the start of the next keyframe.
+    #[serde(rename = "e", default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<V>,
+    /// The handle going out of this keyframe, which is the first control point of its curve.
+    #[serde(rename = "o", default, skip_serializing_if = "Option::is_none")]
+    pub out_handle: Option<Handle>,
+    /// The handle going into the next keyframe, which is the second control point of this
+    /// keyframe's curve.
+    #[serde(rename = "i", default, skip_serializing_if = "Option::is_none")]
+    pub in_handle: Option<Handle>,
+    /// `1` if this keyframe holds its value until the next one, rather than easing into it.
+    #[serde(rename = "h", default)]
+    pub hold: u8,
+}
+
+/// A handle of a Lottie [Keyframe], which is a control point of its curve, with a coordinate
+/// for each dimension of the value, since After Effects can ease each one separately.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Handle {
+    /// How far across the curve the handle is, for each dimension.
+    #[serde(deserialize_with = "numbers")]
+    pub x: Vec<f64>,
+    /// How far up the curve the handle is, for each dimension.
+    #[serde(deserialize_with = "numbers")]
+    pub y: Vec<f64>,
+}
+
+impl<V> Keyframe<V> {
+    /// Creates a new keyframe, which eases linearly into the next.
+    pub fn new(time: f64, start: V) -> Self {
+        Self {
+            time,
+            start: Some(start),
+            end: None,
+            out_handle: None,
+            in_handle: None,
+            hold: 0,
+        }
+    }
+}
+
+impl Handle {
+    /// Creates a new handle, which is the same for every dimension.
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x: vec![x], y: vec![y] }
+    }
+
+    /// The first dimension of this handle, which is `(0, 0)` if it has none.
+    fn first(&self) -> (f64, f64) {
+        let first = |numbers: &[f64]| numbers.first().copied().unwrap_or_default();
+        (first(&self.x), first(&self.y))
+    }
+}
+
+/// Lottie writes the coordinates of a handle as a list, or as a lone number when the handle is
+/// the same for every dimension.
+fn numbers<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+    struct Numbers;
+
+    impl<'de> serde::de::Visitor<'de> for Numbers {
+        type Value = Vec<f64>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a number or a list of numbers")
+        }
+
+        fn visit_f64<E>(self, number: f64) -> Result<Vec<f64>, E> {
+            Ok(vec![number])
+        }
+
+        fn visit_i64<E>(self, number: i64) -> Result<Vec<f64>, E> {
+            Ok(vec![number as f64])
+        }
+
+        fn visit_u64<E>(self, number: u64) -> Result<Vec<f64>, E> {
+            Ok(vec![number as f64])
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<f64>, A::Error> {
+            let mut numbers = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+            while let Some(number) = seq.next_element()? {
+                numbers.push(number);
+            }
+
+            Ok(numbers)
+        }
+    }
+
+    deserializer.deserialize_any(Numbers)
+}
+
+/// Why a list of [Keyframe]s couldn't be instantiated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LottieError {
+    /// There are no keyframes.
+    NoKeyframes,
+    /// A keyframe has no value, and neither does the keyframe before it have an end value.
+    MissingValue {
+        /// The index of the keyframe.
+        index: usize,
+    },
+    /// A keyframe is at an earlier frame than the keyframe before it.
+    UnorderedKeyframes {
+        /// The index of the keyframe which is out of order.
+        index: usize,
+    },
+}
+
+impl fmt::Display for LottieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoKeyframes => f.write_str("there are no keyframes"),
+            Self::MissingValue { index } => write!(f, "keyframe {index} has no value"),
+            Self::UnorderedKeyframes { index } => write!(f, "keyframe {index} is before the keyframe before it"),
+        }
+    }
+}
+
+impl std::error::Error for LottieError {}
+
+/// The timing function of the curve out of `keyframe`.
+///
+/// After Effects can ease each dimension of a value separately, but a tween eases its whole
+/// value at once, so only the first dimension's handles are used.
+fn timing<V>(keyframe: &Keyframe<V>) -> TimingFunction {
+    if keyframe.hold == 1 {
+        return Steps::new(1, StepPosition::JumpEnd).into();
+    }
+
+    match (&keyframe.out_handle, &keyframe.in_handle) {
+        (Some(out_handle), Some(in_handle)) => {
+            let ((x1, y1), (x2, y2)) = (out_handle.first(), in_handle.first());
+            CubicBezier::new(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2).into()
+        }
+        _ => TimingFunction::Linear,
+    }
+}
+
+/// Makes a [Sequence] which plays an animated Lottie property from the start of the
+/// animation, in seconds at the given frame rate. Until the first keyframe, the property holds
+/// its value.
+pub fn instantiate<V>(keyframes: &[Keyframe<V>], frame_rate: f64) -> Result<Sequence<TimingTween<V, f64>>, LottieError>
+where
+    V: TweenValue,
+{
+    let first = keyframes.first().ok_or(LottieError::NoKeyframes)?;
+    let first_value = first.start.ok_or(LottieError::MissingValue { index: 0 })?;
+
+    let hold = TimingFunction::Linear.tween(first_value..=first_value, first.time / frame_rate);
+    let mut sequence = Sequence::new(hold);
+    for (index, pair) in keyframes.windows(2).enumerate() {
+        let [from, to] = [&pair[0], &pair[1]];
+        if to.time < from.time {
+            return Err(LottieError::UnorderedKeyframes { index: index + 1 });
+        }
+
+        let start = from.start.ok_or(LottieError::MissingValue { index })?;
+        let end = from
+            .end
+            .or(to.start)
+            .ok_or(LottieError::MissingValue { index: index + 1 })?;
+
+        let duration = (to.time - from.time) / frame_rate;
+        sequence.push(timing(from).tween(start..=end, duration));
+    }
+
+    Ok(sequence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tween;
+    use serde::de::value::{Error, MapDeserializer};
+
+    #[test]
+    fn instantiate_keyframes() {
+        let mut keyframes = vec![
+            Keyframe::new(10.0, [0.0, 0.0]),
+            Keyframe::new(20.0, [100.0, 50.0]),
+            Keyframe::new(40.0, [0.0, 0.0]),
+        ];
+        keyframes[0].out_handle = Some(Handle::new(0.42, 0.0));
+        keyframes[0].in_handle = Some(Handle::new(1.0, 1.0));
+        keyframes[1].hold = 1;
+
+        let mut sequence = instantiate(&keyframes, 10.0).unwrap();
+        assert_eq!(sequence.duration(), 4.0);
+        assert_eq!(sequence.run(0.5), [0.0, 0.0]);
+        assert_eq!(
+            sequence.run(1.5),
+            CubicBezier::EASE_IN.tween([0.0, 0.0]..=[100.0, 50.0], 1.0).run(0.5)
+        );
+        assert_eq!(sequence.run(3.5), [100.0, 50.0]);
+        assert_eq!(sequence.run(4.0), [0.0, 0.0]);
+
+        keyframes[2].start = None;
+        keyframes[1].end = Some([50.0, 50.0]);
+        assert_eq!(instantiate(&keyframes, 10.0).unwrap().run(4.0), [50.0, 50.0]);
+
+        keyframes[1].end = None;
+        assert_eq!(
+            instantiate(&keyframes, 10.0).unwrap_err(),
+            LottieError::MissingValue { index: 2 }
+        );
+        assert_eq!(
+            instantiate::<[f32; 2]>(&[], 10.0).unwrap_err(),
+            LottieError::NoKeyframes
+        );
+    }
+
+    #[test]
+    fn deserialize_handle() {
+        let lone = MapDeserializer::<_, Error>::new([("x", 0.25), ("y", 1.0)].into_iter());
+        assert_eq!(Handle::deserialize(lone), Ok(Handle::new(0.25, 1.0)));
+
+        let list = MapDeserializer::<_, Error>::new([("x", vec![0.25, 0.5]), ("y", vec![1.0, 0.0])].into_iter());
+        assert_eq!(
+            Handle::deserialize(list),
+            Ok(Handle {
+                x: vec![0.25, 0.5],
+                y: vec![1.0, 0.0]
+            })
+        );
+    }
+}
@@ -1,3 +1,19 @@
+/// Times the rest of the block as the span `$name`, when the `profiling` feature is on.
+macro_rules! profile_span {
+    ($name:literal) => {
+        #[cfg(feature = "profiling")]
+        let _span = crate::profiling::Span::new($name);
+    };
+}
+
+/// Counts the work of the current span, when the `profiling` feature is on.
+macro_rules! profile_count {
+    ($name:literal, $value:expr) => {
+        #[cfg(feature = "profiling")]
+        crate::profiling::count($name, $value);
+    };
+}
+
 /// This is internal to the library, but allows for simple numeric
 /// types to be made into a time value.
 macro_rules! declare_time {
@@ -30,6 +46,54 @@ macro_rules! declare_time {
             }
         }
     };
+    // like `declare_value!`, 64 bit integers don't fit in an f64's mantissa, so they're divided
+    // and scaled with integer math, which keeps huge tick counts exact.
+    (wide $t:ty) => {
+        impl TweenTime for $t {
+            const ZERO: Self = 0;
+
+            fn percent(duration: Self, current_time: Self) -> f64 {
+                if duration == 0 {
+                    return current_time as f64 / duration as f64;
+                }
+
+                let whole = current_time / duration;
+                let rest = current_time % duration;
+                whole as f64 + rest as f64 / duration as f64
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn sub(self, other: Self) -> Self {
+                self - other
+            }
+
+            fn as_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn scale(self, other: f64) -> Self {
+                // split the multiplier into a whole part and a 53 bit fraction, which are both exact,
+                // and both rounded toward zero like the float cast this replaces
+                const ONE: f64 = (1u64 << 53) as f64;
+                let whole = other as i128;
+                let fraction = ((other - whole as f64) * ONE) as i128;
+
+                // a huge multiplier overflows even an i128, so this saturates all the way
+                let time = self as i128;
+                let scaled = time
+                    .saturating_mul(whole)
+                    .saturating_add(time.saturating_mul(fraction) / ONE as i128);
+                scaled.clamp(Self::MIN as i128, Self::MAX as i128) as Self
+            }
+
+            fn is_complete(self, duration: Self) -> bool {
+                self >= duration
+            }
+        }
+    };
     (float $t:ty) => {
         impl TweenTime for $t {
             const ZERO: Self = 0.0;
@@ -82,6 +146,39 @@ macro_rules! declare_value {
         }
     };
 
+    // 64 bit integers don't fit in an f64's mantissa, so they're scaled with an `i128`
+    // multiplication instead of going through a float, which keeps every value exact at the
+    // ends of a tween and monotonic in between.
+    (wide $t:ty) => {
+        impl TweenValue for $t {
+            const ZERO: Self = 0;
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                // split the scale into a whole part and a 53 bit fraction, which are both exact,
+                // and both rounded toward zero like the float cast this replaces
+                const ONE: f64 = (1u64 << 53) as f64;
+                let whole = scale as i128;
+                let fraction = ((scale - whole as f64) * ONE) as i128;
+
+                // saturates all the way, like the float cast, so overshooting easings and even
+                // scales too large for an i128 stay in range
+                let value = self as i128;
+                let scaled = value
+                    .saturating_mul(whole)
+                    .saturating_add(value.saturating_mul(fraction) / ONE as i128);
+                scaled.clamp(Self::MIN as i128, Self::MAX as i128) as $t
+            }
+        }
+    };
+
     (float $t:ty) => {
         impl TweenValue for $t {
             const ZERO: Self = 0.0;
@@ -108,16 +205,62 @@ macro_rules! declare_tween {
         $(#[$struct_meta:meta])*
         pub struct $name:ident;
 
+        $update:item
+    ) => {
+        declare_tween!(
+            $(#[$struct_meta])*
+            pub struct $name;
+
+            fn run(&mut self, new_time: T) -> V {
+                if let Some(held) = crate::tweens::held_value(self.start..=self.end, self.duration, new_time) {
+                    return held;
+                }
+
+                self.run_percent(T::percent(self.duration, new_time))
+            }
+
+            $update
+        );
+    };
+
+    // tweens which can be more exact by working with the time itself can provide `run` too
+    (
+        $(#[$struct_meta:meta])*
+        pub struct $name:ident;
+
+        $run:item
+
         $update:item
     ) => {
         $(#[$struct_meta])*
-        #[derive(Debug, PartialEq, Eq, Clone)]
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                from = "crate::tweens::SavedTween<TValue, TTime>",
+                bound(deserialize = "TValue: TweenValue + serde::Deserialize<'de>, TTime: TweenTime + serde::Deserialize<'de>")
+            )
+        )]
         pub struct $name<TValue, TTime> {
-            range: RangeInclusive<TValue>,
+            start: TValue,
+            end: TValue,
+            #[cfg_attr(feature = "serde", serde(skip_serializing))]
             value_delta: TValue,
             duration: TTime,
         }
 
+        #[cfg(feature = "serde")]
+        impl<TValue, TTime> From<crate::tweens::SavedTween<TValue, TTime>> for $name<TValue, TTime>
+        where
+            TValue: TweenValue,
+            TTime: TweenTime,
+        {
+            fn from(saved: crate::tweens::SavedTween<TValue, TTime>) -> Self {
+                Self::new(saved.start..=saved.end, saved.duration)
+            }
+        }
+
         impl<TValue, TTime> $name<TValue, TTime>
         where
             TValue: TweenValue,
@@ -125,10 +268,21 @@ macro_rules! declare_tween {
         {
             /// Creates a new tween out of a range with a duration.
             pub fn new(range: RangeInclusive<TValue>, duration: TTime) -> Self {
-                let delta = TValue::calculate_delta(*range.end(), *range.start());
+                let value_delta = TValue::calculate_delta(*range.end(), *range.start());
+                Self::with_delta(range, value_delta, duration)
+            }
+
+            /// Creates a new tween out of a range with a duration, and the delta of that range,
+            /// which must be `TweenValue::calculate_delta(end, start)`.
+            ///
+            /// [new](Self::new) finds the delta itself, which a `const fn` can't, so this is how
+            /// tweens are kept in `const`s and `static`s. For ranges of primitive numbers,
+            /// [const_tween](crate::const_tween) finds the delta itself, so it can't be wrong.
+            pub const fn with_delta(range: RangeInclusive<TValue>, value_delta: TValue, duration: TTime) -> Self {
                 Self {
-                    range,
-                    value_delta: delta,
+                    start: *range.start(),
+                    end: *range.end(),
+                    value_delta,
                     duration,
                 }
             }
@@ -140,6 +294,10 @@ macro_rules! declare_tween {
                 <Self as Tween>::run(self, new_time)
             }
 
+            /// Run the given Tween at a percentage of the way through its duration.
+            pub fn run_percent(&mut self, percent: f64) -> <Self as Tween>::Value {
+                <Self as Tween>::run_percent(self, percent)
+            }
         }
 
         impl<V, T> Tween for $name<V, T>
@@ -150,10 +308,12 @@ macro_rules! declare_tween {
             type Value = V;
             type Time = T;
 
+            $run
+
             $update
 
-            fn range(&self) -> &RangeInclusive<V> {
-                &self.range
+            fn range(&self) -> RangeInclusive<V> {
+                self.start..=self.end
             }
 
             fn duration(&self) -> T {
@@ -173,13 +333,34 @@ macro_rules! declare_in_out_tween {
         $update:item
     ) => {
         $(#[$struct_meta])*
-        #[derive(Debug, PartialEq, Eq, Clone)]
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                from = "crate::tweens::SavedTween<TValue, TTime>",
+                bound(deserialize = "TValue: TweenValue + serde::Deserialize<'de>, TTime: TweenTime + serde::Deserialize<'de>")
+            )
+        )]
         pub struct $name<TValue, TTime> {
-            range: RangeInclusive<TValue>,
-            half_delta: TValue,
+            start: TValue,
+            end: TValue,
+            #[cfg_attr(feature = "serde", serde(skip_serializing))]
+            value_delta: TValue,
             duration: TTime,
         }
 
+        #[cfg(feature = "serde")]
+        impl<TValue, TTime> From<crate::tweens::SavedTween<TValue, TTime>> for $name<TValue, TTime>
+        where
+            TValue: TweenValue,
+            TTime: TweenTime,
+        {
+            fn from(saved: crate::tweens::SavedTween<TValue, TTime>) -> Self {
+                Self::new(saved.start..=saved.end, saved.duration)
+            }
+        }
+
         impl<TValue, TTime> $name<TValue, TTime>
         where
             TValue: TweenValue,
@@ -188,10 +369,20 @@ macro_rules! declare_in_out_tween {
             /// Creates a new tween out of a range with a duration.
             pub fn new(range: RangeInclusive<TValue>, duration: TTime) -> Self {
                 let value_delta = TValue::calculate_delta(*range.end(), *range.start());
-                let half_delta = TValue::scale(value_delta, 0.5);
+                Self::with_delta(range, value_delta, duration)
+            }
+
+            /// Creates a new tween out of a range with a duration, and the delta of that range,
+            /// which must be `TweenValue::calculate_delta(end, start)`.
+            ///
+            /// [new](Self::new) finds the delta itself, which a `const fn` can't, so this is how
+            /// tweens are kept in `const`s and `static`s. For ranges of primitive numbers,
+            /// [const_tween](crate::const_tween) finds the delta itself, so it can't be wrong.
+            pub const fn with_delta(range: RangeInclusive<TValue>, value_delta: TValue, duration: TTime) -> Self {
                 Self {
-                    range,
-                    half_delta,
+                    start: *range.start(),
+                    end: *range.end(),
+                    value_delta,
                     duration,
                 }
             }
@@ -205,10 +396,18 @@ macro_rules! declare_in_out_tween {
             type Value = V;
             type Time = T;
 
+            fn run(&mut self, new_time: T) -> V {
+                if let Some(held) = crate::tweens::held_value(self.start..=self.end, self.duration, new_time) {
+                    return held;
+                }
+
+                self.run_percent(T::percent(self.duration, new_time))
+            }
+
             $update
 
-            fn range(&self) -> &RangeInclusive<V> {
-                &self.range
+            fn range(&self) -> RangeInclusive<V> {
+                self.start..=self.end
             }
 
             fn duration(&self) -> T {
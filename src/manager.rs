@@ -0,0 +1,672 @@
+use crate::{CancelMode, Tween, TweenTime, TweenValue, Tweener};
+use core::{cmp::Reverse, hash::Hash};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+    vec::Vec,
+};
+
+mod record;
+pub use record::{Event, Recorder, Recording};
+
+/// What a [TweenManager] should do with the tween a property *already* has, when it is
+/// given a new one.
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Retarget<T> {
+    /// Kills the old tween, and starts the new tween right away. If the new tween doesn't
+    /// start where the old one was, the value will snap.
+    Kill,
+    /// Kills the old tween, but crossfades from the value it was at to the new tween over
+    /// the given window of time, so the value never snaps.
+    Blend(T),
+    /// Keeps the old tween, and queues the new tween up to start as soon as the old one (and
+    /// anything queued before it) has finished.
+    Queue,
+}
+
+/// A [TweenManager] drives many tweens at once, each assigned to a *property*, identified by
+/// a key `K`.
+///
+/// Each property has at most one tween at a time. Assigning a new tween to a property which
+/// already has one follows a [Retarget] policy, so an interrupted animation (such as a hover
+/// highlight which the cursor leaves halfway through) can blend smoothly into its
+/// replacement, rather than snapping, or wait in a first-in, first-out queue behind it.
+///
+/// ```
+/// # use tween::{Linear, Retarget, TweenManager};
+/// let mut manager = TweenManager::new();
+/// manager.insert("highlight", Linear::new(0.0..=1.0, 10.0));
+///
+/// manager.update(5.0);
+/// assert_eq!(manager.get(&"highlight"), Some(0.5));
+///
+/// // we fade back out, blending away from 0.5 over the next 2.0 units of time.
+/// manager.retarget("highlight", Linear::new(1.0..=0.0, 10.0), Retarget::Blend(2.0));
+/// assert_eq!(manager.get(&"highlight"), Some(0.5));
+///
+/// manager.update(1.0);
+/// assert_eq!(manager.get(&"highlight"), Some(0.7));
+/// ```
+pub struct TweenManager<K, Tw: Tween> {
+    entries: HashMap<K, Entry<Tw>>,
+    time_scale: f64,
+    group_time_scales: HashMap<u32, f64>,
+}
+
+struct Entry<Tw: Tween> {
+    tweener: Tweener<Tw>,
+    value: Tw::Value,
+    blend: Option<Blend<Tw::Value, Tw::Time>>,
+    priority: i32,
+    group: u32,
+    deferred: Tw::Time,
+    queue: VecDeque<Tw>,
+}
+
+struct Blend<V, T> {
+    from: V,
+    elapsed: T,
+    window: T,
+}
+
+impl<Tw: Tween> Entry<Tw> {
+    fn new(tween: Tw) -> Self {
+        Self {
+            value: *tween.range().start(),
+            tweener: Tweener::new(tween),
+            blend: None,
+            priority: 0,
+            group: 0,
+            deferred: Tw::Time::ZERO,
+            queue: VecDeque::new(),
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_current_finished() && self.queue.is_empty()
+    }
+
+    fn is_current_finished(&self) -> bool {
+        self.tweener.is_finished() && self.blend.is_none()
+    }
+
+    fn update(&mut self, delta: Tw::Time) {
+        if self.is_finished() {
+            return;
+        }
+
+        // catch up on any time we were deferred for
+        let delta = self.deferred.add(delta);
+        self.deferred = Tw::Time::ZERO;
+
+        let target = self
+            .tweener
+            .update(delta)
+            .unwrap_or(*self.tweener.tween().range().end());
+
+        self.value = match self.blend.as_mut() {
+            Some(blend) => {
+                blend.elapsed = blend.elapsed.add(delta);
+
+                if blend.elapsed.is_complete(blend.window) {
+                    self.blend = None;
+                    target
+                } else {
+                    let percent = Tw::Time::percent(blend.window, blend.elapsed);
+                    let delta = Tw::Value::calculate_delta(target, blend.from);
+
+                    blend.from.add(delta.scale(percent))
+                }
+            }
+            None => target,
+        };
+
+        // start the next queued tween, carrying over the time we overshot the last one by
+        while self.is_current_finished() {
+            let Some(next) = self.queue.pop_front() else {
+                break;
+            };
+
+            let overshoot = self.tweener.current_time().sub(self.tweener.tween().duration());
+            self.tweener = Tweener::new(next);
+            self.value = self
+                .tweener
+                .update(overshoot)
+                .unwrap_or(*self.tweener.tween().range().end());
+        }
+    }
+}
+
+impl<K, Tw> TweenManager<K, Tw>
+where
+    K: Eq + Hash,
+    Tw: Tween,
+{
+    /// Creates a new, empty [TweenManager].
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            time_scale: 1.0,
+            group_time_scales: HashMap::new(),
+        }
+    }
+
+    /// Assigns a tween to a property, killing whatever tween the property had before.
+    ///
+    /// This is the same as calling [retarget](Self::retarget) with [Retarget::Kill].
+    pub fn insert(&mut self, key: K, tween: Tw) {
+        self.retarget(key, tween, Retarget::Kill);
+    }
+
+    /// Assigns a tween to a property. If the property already had a tween, the `policy`
+    /// decides how the old tween hands over to the new one.
+    ///
+    /// [Retarget::Kill] and [Retarget::Blend] also throw away anything which was queued on the
+    /// property.
+    pub fn retarget(&mut self, key: K, tween: Tw, policy: Retarget<Tw::Time>) {
+        if let Retarget::Queue = policy {
+            if let Some(old) = self.entries.get_mut(&key).filter(|old| !old.is_finished()) {
+                old.queue.push_back(tween);
+                return;
+            }
+        }
+
+        let mut entry = Entry::new(tween);
+        let old = self.entries.get(&key);
+
+        if let Some(old) = old {
+            entry.priority = old.priority;
+            entry.group = old.group;
+        }
+
+        if let Retarget::Blend(window) = policy {
+            if let Some(old) = old {
+                entry.value = old.value;
+                entry.blend = Some(Blend {
+                    from: old.value,
+                    elapsed: Tw::Time::ZERO,
+                    window,
+                });
+            }
+        }
+
+        self.entries.insert(key, entry);
+    }
+
+    /// Queues a tween up on a property, to start once its current tween, and anything queued
+    /// before it, has finished. If the property has nothing running, the tween starts right
+    /// away.
+    ///
+    /// This is the same as calling [retarget](Self::retarget) with [Retarget::Queue].
+    ///
+    /// ```
+    /// # use tween::{Linear, TweenManager};
+    /// let mut manager = TweenManager::new();
+    /// manager.enqueue("dialog", Linear::new(0..=10, 10));
+    /// manager.enqueue("dialog", Linear::new(10..=20, 10));
+    /// assert_eq!(manager.queued(&"dialog"), 1);
+    ///
+    /// manager.update(15);
+    /// assert_eq!(manager.get(&"dialog"), Some(15));
+    /// assert_eq!(manager.queued(&"dialog"), 0);
+    /// ```
+    pub fn enqueue(&mut self, key: K, tween: Tw) {
+        self.retarget(key, tween, Retarget::Queue);
+    }
+
+    /// The number of tweens waiting in the queue of a property, not counting the one which
+    /// is currently running.
+    pub fn queued(&self, key: &K) -> usize {
+        self.entries.get(key).map_or(0, |entry| entry.queue.len())
+    }
+
+    /// Drives every tween in the manager forward X steps in time.
+    ///
+    /// The delta is scaled by the manager's [time scale](Self::set_time_scale), and by the
+    /// time scale of each property's [group](Self::set_group).
+    pub fn update(&mut self, delta: Tw::Time) {
+        profile_span!("TweenManager::update");
+        profile_count!("TweenManager::tweens", self.len());
+
+        let scale_of = |group| self.time_scale * group_time_scale(&self.group_time_scales, group);
+
+        for entry in self.entries.values_mut() {
+            entry.update(delta.scale(scale_of(entry.group)));
+        }
+    }
+
+    /// Drives the tweens in the manager forward X steps in time, but stops once `budget` has
+    /// been spent. Tweens are updated in order of their [priority](Self::set_priority), and
+    /// any tweens which didn't fit into the budget are deferred to the next update, where
+    /// they catch up on the time they missed. Returns the number of deferred tweens.
+    ///
+    /// At least one tween is always updated. Note that if you are *always* over budget,
+    /// the lowest priority tweens may never get to update.
+    ///
+    /// ```
+    /// # use tween::{Linear, TweenManager};
+    /// # use std::time::Duration;
+    /// let mut manager = TweenManager::new();
+    /// manager.insert("important", Linear::new(0..=10, 10));
+    /// manager.insert("background", Linear::new(0..=10, 10));
+    /// manager.set_priority(&"important", 1);
+    ///
+    /// // with no budget at all, only the most important tween updates
+    /// assert_eq!(manager.update_with_budget(2, Duration::ZERO), 1);
+    /// assert_eq!(manager.get(&"important"), Some(2));
+    /// assert_eq!(manager.get(&"background"), Some(0));
+    ///
+    /// // but with time to spare, the background tween catches up
+    /// manager.update_with_budget(2, Duration::from_secs(1));
+    /// assert_eq!(manager.get(&"important"), Some(4));
+    /// assert_eq!(manager.get(&"background"), Some(4));
+    /// ```
+    pub fn update_with_budget(&mut self, delta: Tw::Time, budget: Duration) -> usize {
+        self.update_with_budget_reporting(delta, budget, |_| {})
+    }
+
+    /// The same as [update_with_budget](Self::update_with_budget), but tells `on_deferred`
+    /// about every property which was deferred.
+    pub(crate) fn update_with_budget_reporting(
+        &mut self,
+        delta: Tw::Time,
+        budget: Duration,
+        mut on_deferred: impl FnMut(&K),
+    ) -> usize {
+        profile_span!("TweenManager::update_with_budget");
+        profile_count!("TweenManager::tweens", self.len());
+
+        let start = Instant::now();
+        let scale_of = |group| self.time_scale * group_time_scale(&self.group_time_scales, group);
+
+        let mut entries: Vec<_> = self
+            .entries
+            .iter_mut()
+            .filter(|(_, entry)| !entry.is_finished())
+            .collect();
+        entries.sort_by_key(|(_, entry)| Reverse(entry.priority));
+
+        let mut entries = entries.into_iter();
+        let mut deferred = 0;
+
+        for (_, entry) in &mut entries {
+            entry.update(delta.scale(scale_of(entry.group)));
+
+            if start.elapsed() >= budget {
+                break;
+            }
+        }
+
+        for (key, entry) in entries {
+            entry.deferred = entry.deferred.add(delta.scale(scale_of(entry.group)));
+            on_deferred(key);
+            deferred += 1;
+        }
+
+        deferred
+    }
+
+    /// Updates every property, except those which `is_deferred` picks, which are deferred
+    /// exactly as [update_with_budget](Self::update_with_budget) would have.
+    pub(crate) fn update_deferring(&mut self, delta: Tw::Time, is_deferred: impl Fn(&K) -> bool) {
+        let scale_of = |group| self.time_scale * group_time_scale(&self.group_time_scales, group);
+
+        for (key, entry) in self.entries.iter_mut() {
+            if entry.is_finished() {
+                continue;
+            }
+
+            let delta = delta.scale(scale_of(entry.group));
+            if is_deferred(key) {
+                entry.deferred = entry.deferred.add(delta);
+            } else {
+                entry.update(delta);
+            }
+        }
+    }
+
+    /// Sets the priority of a property, which decides which tweens are updated first in
+    /// [update_with_budget](Self::update_with_budget). Higher priorities update first, and
+    /// every property starts at `0`. The priority is kept when the property is given a new
+    /// tween.
+    ///
+    /// Does nothing if the property doesn't have a tween.
+    pub fn set_priority(&mut self, key: &K, priority: i32) {
+        if let Some(entry) = self.entries.get_mut(key) {
+            entry.priority = priority;
+        }
+    }
+
+    /// Sets the global time scale of the manager, which every delta is multiplied by. `0.5`
+    /// plays everything in slow motion, at half speed, while `2.0` plays everything twice as
+    /// fast. The time scale starts at `1.0`.
+    ///
+    /// ```
+    /// # use tween::{Linear, TweenManager};
+    /// let mut manager = TweenManager::new();
+    /// manager.insert("player", Linear::new(0.0..=10.0, 10.0));
+    /// manager.insert("hud", Linear::new(0.0..=10.0, 10.0));
+    ///
+    /// // slow motion for the whole game, but the hud ignores it
+    /// manager.set_time_scale(0.5);
+    /// manager.set_group(&"hud", 1);
+    /// manager.set_group_time_scale(1, 2.0);
+    ///
+    /// manager.update(2.0);
+    /// assert_eq!(manager.get(&"player"), Some(1.0));
+    /// assert_eq!(manager.get(&"hud"), Some(2.0));
+    /// ```
+    pub fn set_time_scale(&mut self, time_scale: f64) {
+        self.time_scale = time_scale;
+    }
+
+    /// The global time scale of the manager.
+    pub fn time_scale(&self) -> f64 {
+        self.time_scale
+    }
+
+    /// Puts a property into a group, whose [time scale](Self::set_group_time_scale) applies
+    /// on top of the global time scale. Every property starts in group `0`, and the group is
+    /// kept when the property is given a new tween.
+    ///
+    /// Does nothing if the property doesn't have a tween.
+    pub fn set_group(&mut self, key: &K, group: u32) {
+        if let Some(entry) = self.entries.get_mut(key) {
+            entry.group = group;
+        }
+    }
+
+    /// The group of a property, if it has a tween.
+    pub fn group(&self, key: &K) -> Option<u32> {
+        self.entries.get(key).map(|entry| entry.group)
+    }
+
+    /// Sets the time scale of a group. Every group's time scale starts at `1.0`.
+    pub fn set_group_time_scale(&mut self, group: u32, time_scale: f64) {
+        self.group_time_scales.insert(group, time_scale);
+    }
+
+    /// The time scale of a group.
+    pub fn group_time_scale(&self, group: u32) -> f64 {
+        group_time_scale(&self.group_time_scales, group)
+    }
+
+    /// The priority of a property, if it has a tween.
+    pub fn priority(&self, key: &K) -> Option<i32> {
+        self.entries.get(key).map(|entry| entry.priority)
+    }
+
+    /// The current value of a property, if it has a tween. Finished tweens keep their final
+    /// value until they are removed.
+    pub fn get(&self, key: &K) -> Option<Tw::Value> {
+        self.entries.get(key).map(|entry| entry.value)
+    }
+
+    /// Returns `true` if the property has a tween which is still running.
+    pub fn is_active(&self, key: &K) -> bool {
+        self.entries.get(key).is_some_and(|entry| !entry.is_finished())
+    }
+
+    /// Cancels the tween on a property, leaving it according to the given [CancelMode], and
+    /// throws away anything queued on it. Returns the value the property was left at, if it
+    /// had a tween.
+    pub fn cancel(&mut self, key: &K, mode: CancelMode) -> Option<Tw::Value> {
+        let entry = self.entries.get_mut(key)?;
+        entry.value = entry.tweener.cancel(mode);
+        entry.blend = None;
+        entry.queue.clear();
+
+        Some(entry.value)
+    }
+
+    /// Removes a property from the manager, returning its tweener.
+    pub fn remove(&mut self, key: &K) -> Option<Tweener<Tw>> {
+        self.entries.remove(key).map(|entry| entry.tweener)
+    }
+
+    /// Removes every property whose tween has finished.
+    pub fn clear_finished(&mut self) {
+        self.entries.retain(|_, entry| !entry.is_finished());
+    }
+
+    /// Iterates over every property and its current value.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, Tw::Value)> + '_ {
+        self.entries.iter().map(|(key, entry)| (key, entry.value))
+    }
+
+    /// The number of properties in the manager.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the manager has no properties.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<K, Tw> TweenManager<K, Tw>
+where
+    K: Eq + Hash + Sync,
+    Tw: Tween + Send,
+    Tw::Value: Send,
+    Tw::Time: Send + Sync,
+{
+    /// Drives every tween in the manager forward X steps in time, exactly like
+    /// [update](Self::update), but spreads the properties over every core with `rayon`.
+    ///
+    /// Every property is independent, and its value is kept in its own slot, so this is only
+    /// worth it for managers with many thousands of properties, where it scales with the
+    /// number of cores.
+    ///
+    /// ```
+    /// # use tween::{Linear, TweenManager};
+    /// let mut manager = TweenManager::new();
+    /// for key in 0..10_000 {
+    ///     manager.insert(key, Linear::new(0.0..=10.0, 10.0));
+    /// }
+    ///
+    /// manager.par_update(5.0);
+    /// assert!(manager.iter().all(|(_, value)| value == 5.0));
+    /// ```
+    pub fn par_update(&mut self, delta: Tw::Time) {
+        use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+        profile_span!("TweenManager::par_update");
+        profile_count!("TweenManager::tweens", self.len());
+
+        let time_scale = self.time_scale;
+        let group_time_scales = &self.group_time_scales;
+
+        self.entries.par_iter_mut().for_each(|(_, entry)| {
+            let scale = time_scale * group_time_scale(group_time_scales, entry.group);
+            entry.update(delta.scale(scale));
+        });
+    }
+}
+
+fn group_time_scale(group_time_scales: &HashMap<u32, f64>, group: u32) -> f64 {
+    group_time_scales.get(&group).copied().unwrap_or(1.0)
+}
+
+impl<K, Tw> Default for TweenManager<K, Tw>
+where
+    K: Eq + Hash,
+    Tw: Tween,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, Tw> core::fmt::Debug for TweenManager<K, Tw>
+where
+    K: core::fmt::Debug,
+    Tw: Tween,
+    Tw::Value: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|(key, entry)| (key, entry.value)))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_update() {
+        let mut manager = TweenManager::new();
+        let mut parallel = TweenManager::new();
+        for key in 0..1000 {
+            manager.insert(key, Linear::new(0..=key, 10));
+            parallel.insert(key, Linear::new(0..=key, 10));
+        }
+        manager.set_group(&7, 1);
+        parallel.set_group(&7, 1);
+        manager.set_group_time_scale(1, 0.5);
+        parallel.set_group_time_scale(1, 0.5);
+
+        for _ in 0..3 {
+            manager.update(3);
+            parallel.par_update(3);
+        }
+
+        for key in 0..1000 {
+            assert_eq!(manager.get(&key), parallel.get(&key));
+        }
+    }
+
+    #[test]
+    fn kill_snaps() {
+        let mut manager = TweenManager::new();
+        manager.insert(0, Linear::new(0..=10, 10));
+        manager.update(5);
+
+        manager.insert(0, Linear::new(20..=30, 10));
+        assert_eq!(manager.get(&0), Some(20));
+
+        manager.update(1);
+        assert_eq!(manager.get(&0), Some(21));
+    }
+
+    #[test]
+    fn blend_crossfades() {
+        let mut manager = TweenManager::new();
+        manager.insert(0, Linear::new(0.0..=10.0, 10.0));
+        manager.update(5.0);
+
+        manager.retarget(0, Linear::new(10.0..=0.0, 10.0), Retarget::Blend(2.0));
+        assert_eq!(manager.get(&0), Some(5.0));
+
+        // halfway between 5.0 and the new tween's 9.0
+        manager.update(1.0);
+        assert_eq!(manager.get(&0), Some(7.0));
+
+        // the blend is over, so we're fully on the new tween
+        manager.update(1.0);
+        assert_eq!(manager.get(&0), Some(8.0));
+    }
+
+    #[test]
+    fn finished_entries() {
+        let mut manager = TweenManager::new();
+        manager.insert("a", Linear::new(0..=10, 10));
+        manager.insert("b", Linear::new(0..=10, 20));
+
+        manager.update(10);
+        assert!(!manager.is_active(&"a"));
+        assert!(manager.is_active(&"b"));
+        assert_eq!(manager.get(&"a"), Some(10));
+
+        assert_eq!(manager.cancel(&"b", CancelMode::Revert), Some(0));
+        manager.clear_finished();
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn queue_plays_in_order() {
+        let mut manager = TweenManager::new();
+        manager.enqueue(0, Linear::new(0..=10, 10));
+        manager.enqueue(0, Linear::new(10..=20, 10));
+        manager.enqueue(0, Linear::new(20..=30, 10));
+        assert_eq!(manager.queued(&0), 2);
+
+        manager.update(10);
+        assert_eq!(manager.get(&0), Some(10));
+        assert_eq!(manager.queued(&0), 1);
+
+        // overshooting the second tween carries over into the third
+        manager.update(15);
+        assert_eq!(manager.get(&0), Some(25));
+        assert!(manager.is_active(&0));
+
+        manager.update(5);
+        assert!(!manager.is_active(&0));
+
+        // a finished property starts a queued tween straight away
+        manager.enqueue(0, Linear::new(0..=10, 10));
+        assert_eq!(manager.queued(&0), 0);
+        assert_eq!(manager.get(&0), Some(0));
+
+        // killing drops the queue
+        manager.enqueue(0, Linear::new(0..=10, 10));
+        manager.insert(0, Linear::new(0..=10, 10));
+        assert_eq!(manager.queued(&0), 0);
+    }
+
+    #[test]
+    fn time_scales() {
+        let mut manager = TweenManager::new();
+        manager.insert(0, Linear::new(0..=100, 100));
+        manager.insert(1, Linear::new(0..=100, 100));
+        manager.set_group(&1, 7);
+
+        manager.set_time_scale(2.0);
+        manager.update(5);
+        assert_eq!(manager.get(&0), Some(10));
+        assert_eq!(manager.get(&1), Some(10));
+
+        manager.set_group_time_scale(7, 0.0);
+        manager.update(5);
+        assert_eq!(manager.get(&0), Some(20));
+        assert_eq!(manager.get(&1), Some(10));
+
+        // the group survives retargeting
+        manager.insert(1, Linear::new(0..=100, 100));
+        assert_eq!(manager.group(&1), Some(7));
+        assert_eq!(manager.group_time_scale(7), 0.0);
+        assert_eq!(manager.group_time_scale(8), 1.0);
+    }
+
+    #[test]
+    fn budget_defers_by_priority() {
+        let mut manager = TweenManager::new();
+        manager.insert(0, Linear::new(0..=10, 10));
+        manager.insert(1, Linear::new(0..=10, 10));
+        manager.insert(2, Linear::new(0..=10, 10));
+        manager.set_priority(&2, 5);
+
+        assert_eq!(manager.update_with_budget(1, Duration::ZERO), 2);
+        assert_eq!(manager.get(&2), Some(1));
+        assert_eq!(manager.get(&0), Some(0));
+
+        manager.set_priority(&0, 10);
+        manager.update_with_budget(1, Duration::ZERO);
+        assert_eq!(manager.get(&0), Some(2));
+
+        // the priority survives retargeting
+        manager.insert(0, Linear::new(0..=10, 10));
+        assert_eq!(manager.priority(&0), Some(10));
+
+        assert_eq!(manager.update_with_budget(1, Duration::MAX), 0);
+        assert_eq!(manager.get(&0), Some(1));
+        assert_eq!(manager.get(&1), Some(3));
+        assert_eq!(manager.get(&2), Some(3));
+    }
+}
@@ -0,0 +1,373 @@
+use super::{Retarget, TweenManager};
+use crate::{CancelMode, Tween, Tweener};
+use core::{hash::Hash, ops::Deref, time::Duration};
+use std::vec::Vec;
+
+/// A single call which changed a [TweenManager], as logged by a [Recorder].
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "K: serde::Serialize, Tw: serde::Serialize, Tw::Time: serde::Serialize",
+        deserialize = "K: serde::Deserialize<'de>, Tw: serde::Deserialize<'de>, Tw::Time: serde::Deserialize<'de>"
+    ))
+)]
+pub enum Event<K, Tw: Tween> {
+    /// A tween was assigned to a property, with [TweenManager::retarget] (or
+    /// [insert](TweenManager::insert) or [enqueue](TweenManager::enqueue)).
+    Retarget {
+        /// The property the tween was assigned to.
+        key: K,
+        /// The tween, exactly as it was when it was assigned.
+        tween: Tw,
+        /// How the tween replaced the old one.
+        policy: Retarget<Tw::Time>,
+    },
+    /// The manager was updated with [TweenManager::update].
+    Update(Tw::Time),
+    /// The manager was updated with [TweenManager::update_with_budget]. Since the budget
+    /// depends on how fast the machine is, we record which properties were *actually*
+    /// deferred, rather than the budget.
+    UpdateWithBudget {
+        /// The delta given to the update.
+        delta: Tw::Time,
+        /// The properties which didn't fit into the budget.
+        deferred: Vec<K>,
+    },
+    /// A property was cancelled with [TweenManager::cancel].
+    Cancel {
+        /// The property which was cancelled.
+        key: K,
+        /// How it was cancelled.
+        mode: CancelMode,
+    },
+    /// A property was removed with [TweenManager::remove].
+    Remove(K),
+    /// Finished properties were removed with [TweenManager::clear_finished].
+    ClearFinished,
+    /// A property's priority was set with [TweenManager::set_priority].
+    SetPriority {
+        /// The property whose priority was set.
+        key: K,
+        /// The new priority.
+        priority: i32,
+    },
+    /// A property's group was set with [TweenManager::set_group].
+    SetGroup {
+        /// The property whose group was set.
+        key: K,
+        /// The new group.
+        group: u32,
+    },
+    /// The global time scale was set with [TweenManager::set_time_scale].
+    SetTimeScale(f64),
+    /// A group's time scale was set with [TweenManager::set_group_time_scale].
+    SetGroupTimeScale {
+        /// The group whose time scale was set.
+        group: u32,
+        /// The new time scale.
+        time_scale: f64,
+    },
+}
+
+/// A log of every [Event] which changed a [TweenManager], made by a [Recorder]. With the
+/// `serde` feature, a recording can be saved (as its list of events) and loaded back later.
+#[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        transparent,
+        bound(
+            serialize = "Event<K, Tw>: serde::Serialize",
+            deserialize = "Event<K, Tw>: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct Recording<K, Tw: Tween> {
+    events: Vec<Event<K, Tw>>,
+}
+
+impl<K, Tw> core::fmt::Debug for Recording<K, Tw>
+where
+    K: core::fmt::Debug,
+    Tw: Tween + core::fmt::Debug,
+    Tw::Time: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Recording").field("events", &self.events).finish()
+    }
+}
+
+impl<K, Tw> Recording<K, Tw>
+where
+    K: Eq + Hash + Clone,
+    Tw: Tween + Clone,
+{
+    /// Creates a new, empty [Recording].
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Creates a [Recording] out of a list of events, such as one which was saved to disk.
+    pub fn from_events(events: Vec<Event<K, Tw>>) -> Self {
+        Self { events }
+    }
+
+    /// The events in the recording, in the order they happened in.
+    pub fn events(&self) -> &[Event<K, Tw>] {
+        &self.events
+    }
+
+    /// Replays the recording onto a fresh [TweenManager], which ends up in exactly the same
+    /// state as the recorded one.
+    pub fn replay(&self) -> TweenManager<K, Tw> {
+        let mut manager = TweenManager::new();
+        self.replay_onto(&mut manager);
+
+        manager
+    }
+
+    /// Replays the recording onto an existing [TweenManager]. For the replay to match the
+    /// recording, the manager must be in the same state the recorded manager was in when the
+    /// recording started.
+    pub fn replay_onto(&self, manager: &mut TweenManager<K, Tw>) {
+        for event in self.events.iter().cloned() {
+            match event {
+                Event::Retarget { key, tween, policy } => manager.retarget(key, tween, policy),
+                Event::Update(delta) => manager.update(delta),
+                Event::UpdateWithBudget { delta, deferred } => {
+                    manager.update_deferring(delta, |key| deferred.contains(key))
+                }
+                Event::Cancel { key, mode } => {
+                    manager.cancel(&key, mode);
+                }
+                Event::Remove(key) => {
+                    manager.remove(&key);
+                }
+                Event::ClearFinished => manager.clear_finished(),
+                Event::SetPriority { key, priority } => manager.set_priority(&key, priority),
+                Event::SetGroup { key, group } => manager.set_group(&key, group),
+                Event::SetTimeScale(time_scale) => manager.set_time_scale(time_scale),
+                Event::SetGroupTimeScale { group, time_scale } => manager.set_group_time_scale(group, time_scale),
+            }
+        }
+    }
+}
+
+impl<K, Tw> Default for Recording<K, Tw>
+where
+    K: Eq + Hash + Clone,
+    Tw: Tween + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [Recorder] wraps a [TweenManager], and logs every call which changes it into a
+/// [Recording], which can be [replayed](Recording::replay) bit-for-bit later.
+///
+/// Tweens are recorded by value, so tweens which carry their own seeds, such as a
+/// [Jitter](crate::Jitter), replay exactly too. This makes recordings useful for reproducing
+/// animation bugs, and for keeping lockstep networked games in sync.
+///
+/// The [Recorder] dereferences to its [TweenManager], so everything which only *reads* the
+/// manager can be called on it directly.
+///
+/// ```
+/// # use tween::{Jitter, Linear, Recorder};
+/// let mut recorder = Recorder::new();
+/// recorder.insert(0, Jitter::new(Linear::new(0.0..=1.0, 10.0), 1234).duration_jitter(0.5..=1.5));
+/// recorder.update(3.0);
+/// recorder.update(2.5);
+///
+/// let (manager, recording) = recorder.finish();
+/// let replayed = recording.replay();
+///
+/// assert_eq!(manager.get(&0), replayed.get(&0));
+/// ```
+pub struct Recorder<K, Tw: Tween> {
+    manager: TweenManager<K, Tw>,
+    recording: Recording<K, Tw>,
+}
+
+impl<K, Tw> Recorder<K, Tw>
+where
+    K: Eq + Hash + Clone,
+    Tw: Tween + Clone,
+{
+    /// Creates a new [Recorder] around a fresh [TweenManager].
+    pub fn new() -> Self {
+        Self {
+            manager: TweenManager::new(),
+            recording: Recording::new(),
+        }
+    }
+
+    /// Records and calls [TweenManager::insert].
+    pub fn insert(&mut self, key: K, tween: Tw) {
+        self.retarget(key, tween, Retarget::Kill);
+    }
+
+    /// Records and calls [TweenManager::enqueue].
+    pub fn enqueue(&mut self, key: K, tween: Tw) {
+        self.retarget(key, tween, Retarget::Queue);
+    }
+
+    /// Records and calls [TweenManager::retarget].
+    pub fn retarget(&mut self, key: K, tween: Tw, policy: Retarget<Tw::Time>) {
+        self.record(Event::Retarget {
+            key: key.clone(),
+            tween: tween.clone(),
+            policy,
+        });
+        self.manager.retarget(key, tween, policy);
+    }
+
+    /// Records and calls [TweenManager::update].
+    pub fn update(&mut self, delta: Tw::Time) {
+        self.record(Event::Update(delta));
+        self.manager.update(delta);
+    }
+
+    /// Records and calls [TweenManager::update_with_budget].
+    pub fn update_with_budget(&mut self, delta: Tw::Time, budget: Duration) -> usize {
+        let mut deferred = Vec::new();
+        let count = self
+            .manager
+            .update_with_budget_reporting(delta, budget, |key| deferred.push(key.clone()));
+        self.record(Event::UpdateWithBudget { delta, deferred });
+
+        count
+    }
+
+    /// Records and calls [TweenManager::cancel].
+    pub fn cancel(&mut self, key: &K, mode: CancelMode) -> Option<Tw::Value> {
+        self.record(Event::Cancel { key: key.clone(), mode });
+        self.manager.cancel(key, mode)
+    }
+
+    /// Records and calls [TweenManager::remove].
+    pub fn remove(&mut self, key: &K) -> Option<Tweener<Tw>> {
+        self.record(Event::Remove(key.clone()));
+        self.manager.remove(key)
+    }
+
+    /// Records and calls [TweenManager::clear_finished].
+    pub fn clear_finished(&mut self) {
+        self.record(Event::ClearFinished);
+        self.manager.clear_finished();
+    }
+
+    /// Records and calls [TweenManager::set_priority].
+    pub fn set_priority(&mut self, key: &K, priority: i32) {
+        self.record(Event::SetPriority {
+            key: key.clone(),
+            priority,
+        });
+        self.manager.set_priority(key, priority);
+    }
+
+    /// Records and calls [TweenManager::set_group].
+    pub fn set_group(&mut self, key: &K, group: u32) {
+        self.record(Event::SetGroup {
+            key: key.clone(),
+            group,
+        });
+        self.manager.set_group(key, group);
+    }
+
+    /// Records and calls [TweenManager::set_time_scale].
+    pub fn set_time_scale(&mut self, time_scale: f64) {
+        self.record(Event::SetTimeScale(time_scale));
+        self.manager.set_time_scale(time_scale);
+    }
+
+    /// Records and calls [TweenManager::set_group_time_scale].
+    pub fn set_group_time_scale(&mut self, group: u32, time_scale: f64) {
+        self.record(Event::SetGroupTimeScale { group, time_scale });
+        self.manager.set_group_time_scale(group, time_scale);
+    }
+
+    /// The recording so far.
+    pub fn recording(&self) -> &Recording<K, Tw> {
+        &self.recording
+    }
+
+    /// Stops recording, handing back the manager and the finished recording.
+    pub fn finish(self) -> (TweenManager<K, Tw>, Recording<K, Tw>) {
+        (self.manager, self.recording)
+    }
+
+    fn record(&mut self, event: Event<K, Tw>) {
+        self.recording.events.push(event);
+    }
+}
+
+impl<K, Tw> Default for Recorder<K, Tw>
+where
+    K: Eq + Hash + Clone,
+    Tw: Tween + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, Tw: Tween> Deref for Recorder<K, Tw> {
+    type Target = TweenManager<K, Tw>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.manager
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[test]
+    fn replay_matches() {
+        let mut recorder = Recorder::new();
+        recorder.insert("a", Linear::new(0.0..=10.0, 10.0));
+        recorder.enqueue("a", Linear::new(10.0..=0.0, 10.0));
+        recorder.insert("b", Linear::new(0.0..=10.0, 10.0));
+        recorder.set_group(&"b", 1);
+        recorder.set_group_time_scale(1, 0.25);
+        recorder.update(0.1);
+        recorder.update_with_budget(0.3, Duration::ZERO);
+        recorder.retarget("b", Linear::new(5.0..=0.0, 3.0), Retarget::Blend(1.0));
+        recorder.update(12.7);
+        recorder.cancel(&"a", CancelMode::Freeze);
+        recorder.update(1.0);
+
+        let replayed = recorder.recording().replay();
+        assert_eq!(recorder.get(&"a"), replayed.get(&"a"));
+        assert_eq!(recorder.get(&"b"), replayed.get(&"b"));
+        assert_eq!(recorder.recording().events().len(), 11);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn save_and_load() {
+        let mut recorder = Recorder::new();
+        recorder.insert(0_u32, Linear::new(0.0..=10.0, 10.0));
+        recorder.retarget(1, Linear::new(5.0..=0.0, 3.0), Retarget::Blend(1.0));
+        recorder.set_group(&1, 2);
+        recorder.update(0.5);
+        recorder.update_with_budget(0.25, Duration::ZERO);
+        recorder.cancel(&0, CancelMode::Freeze);
+        recorder.set_time_scale(0.5);
+        recorder.clear_finished();
+
+        let loaded = crate::round_trip::round_trip(recorder.recording()).unwrap();
+        assert_eq!(&loaded, recorder.recording());
+
+        let replayed = loaded.replay();
+        assert_eq!(recorder.get(&0), replayed.get(&0));
+        assert_eq!(recorder.get(&1), replayed.get(&1));
+    }
+}
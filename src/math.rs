@@ -0,0 +1,111 @@
+//! Float functions which go through `std`, or through `libm` when `std` is disabled.
+
+pub(crate) fn powf(base: f32, exponent: f32) -> f32 {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::powf(base, exponent);
+
+    #[cfg(feature = "std")]
+    let o = base.powf(exponent);
+
+    o
+}
+
+pub(crate) fn cbrt(v: f32) -> f32 {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::cbrtf(v);
+
+    #[cfg(feature = "std")]
+    let o = v.cbrt();
+
+    o
+}
+
+pub(crate) fn sqrt(v: f32) -> f32 {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::sqrtf(v);
+
+    #[cfg(feature = "std")]
+    let o = v.sqrt();
+
+    o
+}
+
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::atan2f(y, x);
+
+    #[cfg(feature = "std")]
+    let o = y.atan2(x);
+
+    o
+}
+
+pub(crate) fn sin_cos(v: f32) -> (f32, f32) {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::sincosf(v);
+
+    #[cfg(feature = "std")]
+    let o = v.sin_cos();
+
+    o
+}
+
+pub(crate) fn floor(v: f64) -> f64 {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::floor(v);
+
+    #[cfg(feature = "std")]
+    let o = v.floor();
+
+    o
+}
+
+pub(crate) fn ceil(v: f64) -> f64 {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::ceil(v);
+
+    #[cfg(feature = "std")]
+    let o = v.ceil();
+
+    o
+}
+
+pub(crate) fn round(v: f64) -> f64 {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::round(v);
+
+    #[cfg(feature = "std")]
+    let o = v.round();
+
+    o
+}
+
+pub(crate) fn sqrt_f64(v: f64) -> f64 {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::sqrt(v);
+
+    #[cfg(feature = "std")]
+    let o = v.sqrt();
+
+    o
+}
+
+pub(crate) fn powf_f64(base: f64, exponent: f64) -> f64 {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::pow(base, exponent);
+
+    #[cfg(feature = "std")]
+    let o = base.powf(exponent);
+
+    o
+}
+
+pub(crate) fn sin_cos_f64(v: f64) -> (f64, f64) {
+    #[cfg(all(feature = "libm", not(feature = "std")))]
+    let o = libm::sincos(v);
+
+    #[cfg(feature = "std")]
+    let o = v.sin_cos();
+
+    o
+}
@@ -0,0 +1,103 @@
+use mint::{Point2, Point3, Quaternion, Vector2, Vector3, Vector4};
+
+/// Implements [TweenValue](crate::TweenValue) for a mint type by tweening each of its fields.
+/// mint types don't have any arithmetic of their own, so this is done field by field.
+macro_rules! mint_value {
+    ($t:ident { $($field:ident),+ }, $float:ty) => {
+        impl crate::TweenValue for $t<$float> {
+            const ZERO: Self = $t { $($field: 0.0),+ };
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                $t { $($field: destination.$field - start.$field),+ }
+            }
+
+            fn add(self, other: Self) -> Self {
+                $t { $($field: self.$field + other.$field),+ }
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                $t { $($field: self.$field * scale as $float),+ }
+            }
+        }
+    };
+}
+
+macro_rules! mint_values {
+    ($float:ty) => {
+        mint_value!(Vector2 { x, y }, $float);
+        mint_value!(Vector3 { x, y, z }, $float);
+        mint_value!(Vector4 { x, y, z, w }, $float);
+        mint_value!(Point2 { x, y }, $float);
+        mint_value!(Point3 { x, y, z }, $float);
+
+        // Quaternions are tweened component-wise, which is cheap, but means that the in-between
+        // values are generally *not* normalized. Normalize them before using them as rotations.
+        impl crate::TweenValue for Quaternion<$float> {
+            const ZERO: Self = Quaternion {
+                v: <Vector3<$float> as crate::TweenValue>::ZERO,
+                s: 0.0,
+            };
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                Quaternion {
+                    v: crate::TweenValue::calculate_delta(destination.v, start.v),
+                    s: destination.s - start.s,
+                }
+            }
+
+            fn add(self, other: Self) -> Self {
+                Quaternion {
+                    v: crate::TweenValue::add(self.v, other.v),
+                    s: self.s + other.s,
+                }
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                Quaternion {
+                    v: crate::TweenValue::scale(self.v, scale),
+                    s: self.s * scale as $float,
+                }
+            }
+        }
+    };
+}
+
+mint_values!(f32);
+mint_values!(f64);
+
+impl From<Quaternion<f32>> for crate::Rotation {
+    fn from(q: Quaternion<f32>) -> Self {
+        Self::from_xyzw(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+impl From<crate::Rotation> for Quaternion<f32> {
+    fn from(rotation: crate::Rotation) -> Self {
+        rotation.to_xyzw().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Linear;
+    use mint::{Point2, Quaternion, Vector3};
+
+    #[test]
+    fn vectors_and_points() {
+        let mut tween = Linear::new(Vector3::from([0.0, 0.0, 0.0])..=Vector3::from([2.0, 4.0, 8.0]), 4.0);
+        assert_eq!(tween.run(1.0), Vector3::from([0.5, 1.0, 2.0]));
+
+        let mut tween = Linear::new(Point2::from([1.0, 1.0])..=Point2::from([3.0, 5.0]), 2.0);
+        assert_eq!(tween.run(1.0), Point2::from([2.0, 3.0]));
+    }
+
+    #[test]
+    fn quaternions() {
+        let start = Quaternion::from([0.0, 0.0, 0.0, 1.0]);
+        let end = Quaternion::from([1.0, 0.0, 0.0, 0.0]);
+
+        let mut tween = Linear::new(start..=end, 2.0);
+        assert_eq!(tween.run(1.0), Quaternion::from([0.5, 0.0, 0.0, 0.5]));
+        assert_eq!(tween.run(2.0), end);
+    }
+}
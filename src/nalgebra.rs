@@ -0,0 +1,130 @@
+use nalgebra::{ArrayStorage, Point, Quaternion, SVector, UnitQuaternion};
+
+macro_rules! nalgebra_value {
+    ($float:ty) => {
+        impl<const D: usize> crate::TweenValue for SVector<$float, D> {
+            const ZERO: Self = SVector::from_array_storage(ArrayStorage([[0.0; D]; 1]));
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * scale as $float
+            }
+        }
+
+        impl<const D: usize> crate::TweenValue for Point<$float, D> {
+            const ZERO: Self = Point {
+                coords: <SVector<$float, D> as crate::TweenValue>::ZERO,
+            };
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                Point::from(destination - start)
+            }
+
+            fn add(self, other: Self) -> Self {
+                other + self.coords
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                Point::from(self.coords * scale as $float)
+            }
+        }
+
+        /// Rotations are tweened by slerping along the shortest arc, so every in-between value
+        /// is a valid rotation. The delta between two rotations is the rotation which takes
+        /// the start to the destination, and `ZERO` is the identity rotation.
+        impl crate::TweenValue for UnitQuaternion<$float> {
+            const ZERO: Self = UnitQuaternion::new_unchecked(Quaternion {
+                coords: SVector::from_array_storage(ArrayStorage([[0.0, 0.0, 0.0, 1.0]])),
+            });
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                start.rotation_to(&destination)
+            }
+
+            fn add(self, other: Self) -> Self {
+                self * other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self.powf(scale as $float)
+            }
+        }
+    };
+}
+
+nalgebra_value!(f32);
+nalgebra_value!(f64);
+
+impl From<UnitQuaternion<f32>> for crate::Rotation {
+    fn from(q: UnitQuaternion<f32>) -> Self {
+        Self::from_xyzw(q.i, q.j, q.k, q.w)
+    }
+}
+
+impl From<crate::Rotation> for UnitQuaternion<f32> {
+    fn from(rotation: crate::Rotation) -> Self {
+        let [x, y, z, w] = rotation.to_xyzw();
+        UnitQuaternion::new_unchecked(Quaternion::new(w, x, y, z))
+    }
+}
+
+impl From<nalgebra::Isometry3<f32>> for crate::Transform3D {
+    fn from(isometry: nalgebra::Isometry3<f32>) -> Self {
+        Self::new(
+            isometry.translation.vector.into(),
+            isometry.rotation.into(),
+            [1.0, 1.0, 1.0],
+        )
+    }
+}
+
+impl From<crate::Transform3D> for nalgebra::Matrix4<f32> {
+    fn from(transform: crate::Transform3D) -> Self {
+        let rotation: UnitQuaternion<f32> = transform.rotation.into();
+
+        nalgebra::Matrix4::new_translation(&transform.translation.into())
+            * rotation.to_homogeneous()
+            * nalgebra::Matrix4::new_nonuniform_scaling(&transform.scale.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Linear, QuadInOut, SineIn, Tween};
+    use approx::assert_relative_eq;
+    use core::f64::consts::FRAC_PI_2;
+    use nalgebra::{Point2, UnitQuaternion, Vector3};
+
+    #[test]
+    fn vectors_and_points() {
+        let mut tween = Linear::new(Vector3::zeros()..=Vector3::new(2.0, 4.0, 8.0), 4.0);
+        assert_eq!(tween.run(1.0), Vector3::new(0.5, 1.0, 2.0));
+
+        let mut tween = Linear::new(Point2::new(1.0, 1.0)..=Point2::new(3.0, 5.0), 2.0);
+        assert_eq!(tween.run(1.0), Point2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn rotations_slerp() {
+        let start = UnitQuaternion::from_euler_angles(0.0, 0.0, 0.0);
+        let end = UnitQuaternion::from_euler_angles(0.0, 0.0, FRAC_PI_2);
+
+        let mut tween = Linear::new(start..=end, 2.0);
+        let halfway = tween.run(1.0);
+        assert_relative_eq!(halfway.angle(), FRAC_PI_2 / 2.0, epsilon = 1e-9);
+        assert_relative_eq!(halfway.norm(), 1.0, epsilon = 1e-9);
+
+        let mut tween = QuadInOut::new(start..=end, 2.0);
+        assert_relative_eq!(tween.run(2.0), end, epsilon = 1e-9);
+
+        let mut tween = SineIn::new(end..=start, 2.0);
+        assert_relative_eq!(tween.run(2.0), start, epsilon = 1e-9);
+    }
+}
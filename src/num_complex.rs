@@ -0,0 +1,40 @@
+use num_complex::Complex;
+
+macro_rules! complex_value {
+    ($float:ty) => {
+        /// Both parts are tweened together, along a straight line through the complex plane.
+        impl crate::TweenValue for Complex<$float> {
+            const ZERO: Self = Complex::new(0.0, 0.0);
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * scale as $float
+            }
+        }
+    };
+}
+
+complex_value!(f32);
+complex_value!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Linear, QuadIn};
+    use num_complex::Complex;
+
+    #[test]
+    fn complex() {
+        let mut tween = Linear::new(Complex::new(-0.75, 0.0)..=Complex::new(-0.75, 0.2), 4.0);
+        assert_eq!(tween.run(2.0), Complex::new(-0.75, 0.1));
+
+        let mut tween = QuadIn::new(Complex::new(0.0f32, 0.0)..=Complex::new(4.0, -8.0), 2.0);
+        assert_eq!(tween.run(1.0), Complex::new(1.0, -2.0));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{TweenTime, TweenValue};
+use num_traits::{ConstZero, Float};
+
+/// A float of any type which implements num-traits' [Float], such as a software float or a
+/// float of a fixed width, which is both a [TweenValue] and a [TweenTime].
+///
+/// Its math is done in the float itself, and only the percents of tweens are `f64`s, so a
+/// wider float than `f64` keeps its precision across a tween.
+///
+/// ```
+/// # use tween::{NumFloat, Linear};
+/// let mut tween = Linear::new(NumFloat(0.0f64)..=NumFloat(10.0), NumFloat(4.0f64));
+/// assert_eq!(tween.run(NumFloat(1.0)), NumFloat(2.5));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+pub struct NumFloat<F>(pub F);
+
+impl<F: Float> NumFloat<F> {
+    /// Converts an `f64` to this float, which is NaN if it can't be.
+    fn from_f64(value: f64) -> F {
+        F::from(value).unwrap_or_else(F::nan)
+    }
+}
+
+impl<F> From<F> for NumFloat<F> {
+    fn from(value: F) -> Self {
+        Self(value)
+    }
+}
+
+impl<F: Float + ConstZero> TweenValue for NumFloat<F> {
+    const ZERO: Self = NumFloat(F::ZERO);
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        NumFloat(destination.0 - start.0)
+    }
+
+    fn add(self, other: Self) -> Self {
+        NumFloat(self.0 + other.0)
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        NumFloat(self.0 * Self::from_f64(scale))
+    }
+}
+
+impl<F: Float + ConstZero> TweenTime for NumFloat<F> {
+    const ZERO: Self = NumFloat(F::ZERO);
+
+    fn percent(duration: Self, current_time: Self) -> f64 {
+        (current_time.0 / duration.0).to_f64().unwrap_or(f64::NAN)
+    }
+
+    fn as_f64(self) -> f64 {
+        self.0.to_f64().unwrap_or(f64::NAN)
+    }
+
+    fn add(self, other: Self) -> Self {
+        NumFloat(self.0 + other.0)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        NumFloat(self.0 - other.0)
+    }
+
+    fn scale(self, multiplier: f64) -> Self {
+        NumFloat(self.0 * Self::from_f64(multiplier))
+    }
+
+    fn is_complete(self, duration: Self) -> bool {
+        self.0 >= duration.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QuadIn, Tweener};
+
+    #[test]
+    fn num_float() {
+        let mut tween = QuadIn::new(NumFloat(0.0f32)..=NumFloat(100.0), NumFloat(10.0f64));
+        assert_eq!(tween.run(NumFloat(5.0)), NumFloat(25.0));
+
+        let mut tweener = Tweener::new(QuadIn::new(NumFloat(0.0f64)..=NumFloat(1.0), NumFloat(2.0f32)));
+        assert_eq!(tweener.update(NumFloat(4.0)), Some(NumFloat(1.0)));
+    }
+}
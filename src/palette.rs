@@ -0,0 +1,170 @@
+use palette::{Alpha, Hsl, Hsv, Lab, LabHue, Lch, LinSrgb, Oklab, OklabHue, Oklch, RgbHue, Srgb, Xyz, rgb::Rgb};
+
+/// Implements [TweenValue](crate::TweenValue) for a palette color by tweening each of its
+/// fields. Hue fields take the shortest arc around the color wheel.
+macro_rules! palette_value {
+    (
+        [$($generic:ident)?] $t:ty, $zero:expr, $float:ty,
+        [$($field:ident),*] $(, $hue:ident: $hue_ty:ident)?
+    ) => {
+        impl<$($generic)?> crate::TweenValue for $t {
+            const ZERO: Self = $zero;
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                let mut delta = destination;
+                $(delta.$field = destination.$field - start.$field;)*
+                $(delta.$hue = $hue_ty::new((destination.$hue - start.$hue).into_degrees());)?
+                delta
+            }
+
+            fn add(self, other: Self) -> Self {
+                let mut sum = self;
+                $(sum.$field = self.$field + other.$field;)*
+                $(sum.$hue = self.$hue + other.$hue;)?
+                sum
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                let mut scaled = self;
+                $(scaled.$field = self.$field * scale as $float;)*
+                $(scaled.$hue = $hue_ty::new(self.$hue.into_inner() * scale as $float);)?
+                scaled
+            }
+        }
+    };
+}
+
+macro_rules! palette_values {
+    ($float:ty) => {
+        palette_value!([] LinSrgb<$float>, LinSrgb::new(0.0, 0.0, 0.0), $float, [red, green, blue]);
+        palette_value!(
+            [Wp] Xyz<Wp, $float>,
+            Xyz::new(0.0, 0.0, 0.0),
+            $float,
+            [x, y, z]
+        );
+        palette_value!([Wp] Lab<Wp, $float>, Lab::new(0.0, 0.0, 0.0), $float, [l, a, b]);
+        palette_value!([] Oklab<$float>, Oklab::new(0.0, 0.0, 0.0), $float, [l, a, b]);
+        palette_value!(
+            [S] Hsl<S, $float>,
+            Hsl::new_const(RgbHue::new(0.0), 0.0, 0.0),
+            $float,
+            [saturation, lightness],
+            hue: RgbHue
+        );
+        palette_value!(
+            [S] Hsv<S, $float>,
+            Hsv::new_const(RgbHue::new(0.0), 0.0, 0.0),
+            $float,
+            [saturation, value],
+            hue: RgbHue
+        );
+        palette_value!(
+            [Wp] Lch<Wp, $float>,
+            Lch::new_const(0.0, 0.0, LabHue::new(0.0)),
+            $float,
+            [l, chroma],
+            hue: LabHue
+        );
+        palette_value!(
+            [] Oklch<$float>,
+            Oklch::new_const(0.0, 0.0, OklabHue::new(0.0)),
+            $float,
+            [l, chroma],
+            hue: OklabHue
+        );
+
+        /// sRGB colors are decoded into linear light for every step of the tween and encoded
+        /// again afterwards, just like [Srgba](crate::Srgba), so the midpoints are gamma-correct.
+        impl crate::TweenValue for Srgb<$float> {
+            const ZERO: Self = Rgb::new(0.0, 0.0, 0.0);
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                let delta = LinSrgb::calculate_delta(destination.into_linear(), start.into_linear());
+                Srgb::from_linear(delta)
+            }
+
+            fn add(self, other: Self) -> Self {
+                Srgb::from_linear(self.into_linear::<$float>().add(other.into_linear()))
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                Srgb::from_linear(self.into_linear::<$float>().scale(scale))
+            }
+        }
+
+        /// The color is tweened as usual, and the alpha alongside it.
+        impl<C: crate::TweenValue> crate::TweenValue for Alpha<C, $float> {
+            const ZERO: Self = Alpha {
+                color: C::ZERO,
+                alpha: 0.0,
+            };
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                Alpha {
+                    color: C::calculate_delta(destination.color, start.color),
+                    alpha: destination.alpha - start.alpha,
+                }
+            }
+
+            fn add(self, other: Self) -> Self {
+                Alpha {
+                    color: self.color.add(other.color),
+                    alpha: self.alpha + other.alpha,
+                }
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                Alpha {
+                    color: self.color.scale(scale),
+                    alpha: self.alpha * scale as $float,
+                }
+            }
+        }
+    };
+}
+
+palette_values!(f32);
+palette_values!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Linear, QuadInOut, Rgba, Tween};
+    use approx::assert_relative_eq;
+    use palette::{Hsl, IntoColor, LinSrgb, Oklab, Srgb, Srgba};
+
+    #[test]
+    fn srgb_matches_linear() {
+        let start = Srgb::new(0.1f32, 0.9, 0.3);
+        let end = Srgb::new(0.8, 0.2, 0.6);
+
+        let mut ours = QuadInOut::new(start..=end, 10.0);
+        let mut theirs = QuadInOut::new(start.into_linear()..=end.into_linear(), 10.0);
+
+        for time in 0..=10 {
+            let time = time as f32;
+            let linear: LinSrgb = ours.run(time).into_linear();
+            assert_relative_eq!(linear, theirs.run(time), epsilon = 1e-5);
+        }
+
+        let crate_rgba = Rgba::from_srgb(0.1, 0.9, 0.3, 1.0);
+        let mut tween = Linear::new(Srgba::new(0.1, 0.9, 0.3, 1.0)..=Srgba::new(0.1, 0.9, 0.3, 0.0), 2.0);
+        let halfway = tween.run(1.0);
+        assert_relative_eq!(halfway.alpha, 0.5);
+        assert_relative_eq!(halfway.color.into_linear().red, crate_rgba.r, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn hues_and_labs() {
+        let mut tween = Linear::new(Hsl::new_srgb(350.0f64, 1.0, 0.5)..=Hsl::new_srgb(30.0, 0.5, 0.5), 4.0);
+        let quarter = tween.run(1.0);
+        assert_relative_eq!(quarter.hue.into_positive_degrees(), 0.0, epsilon = 1e-9);
+        assert_relative_eq!(quarter.saturation, 0.875);
+
+        let start: Oklab = Srgb::new(0.0f32, 0.0, 1.0).into_linear().into_color();
+        let end: Oklab = Srgb::new(1.0f32, 1.0, 1.0).into_linear().into_color();
+        let mut tween = Linear::new(start..=end, 2.0);
+        assert_relative_eq!(tween.run(1.0).l, (start.l + end.l) / 2.0);
+        assert_eq!(tween.range(), start..=end);
+    }
+}
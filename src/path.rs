@@ -0,0 +1,159 @@
+mod arc_length;
+mod bezier;
+mod catmull_rom;
+
+pub use arc_length::ArcLength;
+pub use bezier::BezierPath;
+pub use catmull_rom::CatmullRom;
+
+use crate::{Progress, Tween, TweenValue, math};
+use core::ops::RangeInclusive;
+
+/// A [Path] is a curve which can be followed from its start, at a progress of `0.0`, to its
+/// end, at a progress of `1.0`. Use [Path::tween] to follow it over time with an easing.
+pub trait Path {
+    /// The points the path passes through.
+    type Point: TweenValue;
+
+    /// The point at the given progress along the path. Progress outside of `0.0..=1.0`, which
+    /// overshooting easings produce, carries on past the ends of the path.
+    fn point_at(&self, progress: f64) -> Self::Point;
+
+    /// Creates a [PathTween] which follows this path over the given duration, with progress
+    /// along it eased by a constructor for an easing. The constructor is given the range
+    /// `0.0..=1.0` and the duration, so any tween's `new` function, like `SineIn::new`, can be
+    /// passed in directly.
+    fn tween<Ease>(
+        self,
+        ease: impl FnOnce(RangeInclusive<f64>, Ease::Time) -> Ease,
+        duration: Ease::Time,
+    ) -> PathTween<Self, Ease>
+    where
+        Self: Sized,
+        Ease: Tween<Value = f64>,
+    {
+        PathTween::new(self, Progress::new(ease, duration))
+    }
+}
+
+/// A [Distance] is a [TweenValue] with a straight line distance between any two values, which
+/// paths that space themselves out by distance, like [CatmullRom], need.
+///
+/// This is implemented for floats, arrays of them, and ultraviolet's `Vec3`. For your own
+/// values, any metric works, as long as it's never negative and is zero between equal values.
+pub trait Distance: TweenValue {
+    /// The distance between `self` and `other`.
+    fn distance(self, other: Self) -> f64;
+}
+
+impl Distance for f32 {
+    fn distance(self, other: Self) -> f64 {
+        (self - other).abs() as f64
+    }
+}
+
+impl Distance for f64 {
+    fn distance(self, other: Self) -> f64 {
+        (self - other).abs()
+    }
+}
+
+impl<V: Distance, const N: usize> Distance for [V; N] {
+    fn distance(self, other: Self) -> f64 {
+        let squared: f64 = self
+            .iter()
+            .zip(other)
+            .map(|(a, b)| {
+                let distance = a.distance(b);
+                distance * distance
+            })
+            .sum();
+
+        math::sqrt_f64(squared)
+    }
+}
+
+/// A [PathTween] moves along a [Path] over time, with an easing controlling the progress
+/// along it. It's made with [Path::tween].
+///
+/// ```
+/// # use tween::{BezierPath, Path, QuadInOut, Tweener};
+/// let points = [[0.0, 0.0], [0.0, 10.0], [10.0, 10.0], [10.0, 0.0]];
+/// let mut tweener = Tweener::new(BezierPath::new(&points).tween(QuadInOut::new, 2.0));
+///
+/// assert_eq!(tweener.update(1.0), Some([5.0, 7.5]));
+/// assert_eq!(tweener.update(1.0), Some([10.0, 0.0]));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct PathTween<P: Path, Ease> {
+    path: P,
+    progress: Progress<Ease>,
+    range: RangeInclusive<P::Point>,
+}
+
+impl<P, Ease> PathTween<P, Ease>
+where
+    P: Path,
+    Ease: Tween<Value = f64>,
+{
+    /// Creates a new [PathTween] which follows the path with the given eased progress.
+    pub fn new(path: P, progress: Progress<Ease>) -> Self {
+        let range = path.point_at(0.0)..=path.point_at(1.0);
+
+        Self { path, progress, range }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: Ease::Time) -> P::Point {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// Allows inspection of the path.
+    pub fn path(&self) -> &P {
+        &self.path
+    }
+}
+
+impl<P, Ease> Tween for PathTween<P, Ease>
+where
+    P: Path,
+    Ease: Tween<Value = f64>,
+{
+    type Value = P::Point;
+    type Time = Ease::Time;
+
+    fn run(&mut self, new_time: Self::Time) -> Self::Value {
+        let progress = self.progress.run(new_time);
+
+        self.path.point_at(progress)
+    }
+
+    fn run_percent(&mut self, percent: f64) -> Self::Value {
+        let progress = self.progress.run_percent(percent);
+
+        self.path.point_at(progress)
+    }
+
+    fn range(&self) -> RangeInclusive<Self::Value> {
+        self.range.clone()
+    }
+
+    fn duration(&self) -> Self::Time {
+        self.progress.duration()
+    }
+}
+
+/// Linearly interpolates between two values.
+pub(crate) fn lerp<V: TweenValue>(start: V, end: V, percent: f64) -> V {
+    V::calculate_delta(end, start).scale(percent).add(start)
+}
+
+/// Splits progress along a path into the index of one of its `segments`, and the progress
+/// within that segment. Progress outside of `0.0..=1.0` stays on the first or last segment.
+pub(crate) fn segment_at(progress: f64, segments: usize) -> (usize, f64) {
+    let scaled = progress * segments as f64;
+    // `as` saturates the negative progress of overshooting easings to zero
+    let index = (crate::math::floor(scaled) as usize).min(segments - 1);
+
+    (index, scaled - index as f64)
+}
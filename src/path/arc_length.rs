@@ -0,0 +1,138 @@
+use super::{Distance, Path};
+
+/// An [ArcLength] wraps another [Path], so that progress along it is the *distance* traveled
+/// along it, rather than its own parameter. This moves along the path at a constant speed,
+/// where paths like [BezierPath](crate::BezierPath) would otherwise speed up and slow down
+/// with the spacing of their points.
+///
+/// The path's length is measured once, when the [ArcLength] is created, by sampling it at
+/// `N` evenly spaced points into a table. The `64` samples of [ArcLength::new] are plenty for
+/// a few curves. Longer or more winding paths may want more, with
+/// `ArcLength::<_, 256>::with_samples(path)`.
+///
+/// ```
+/// # use tween::{ArcLength, BezierPath, Path};
+/// // the control points are bunched up at the start, so the curve starts off slowly
+/// let points = [0.0f32, 0.0, 0.0, 9.0];
+/// let bezier = BezierPath::new(&points);
+/// assert!(bezier.point_at(0.5) < 2.0);
+///
+/// let constant_speed = ArcLength::new(bezier);
+/// assert!((constant_speed.point_at(0.5) - 4.5).abs() < 0.1);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ArcLength<P, const N: usize = 64> {
+    path: P,
+    lengths: [f64; N],
+}
+
+impl<P> ArcLength<P>
+where
+    P: Path,
+    P::Point: Distance,
+{
+    /// Measures the length of the path with 64 samples, so it can be followed at a constant
+    /// speed.
+    pub fn new(path: P) -> Self {
+        Self::with_samples(path)
+    }
+}
+
+impl<P, const N: usize> ArcLength<P, N>
+where
+    P: Path,
+    P::Point: Distance,
+{
+    /// Measures the length of the path with `N` samples, so it can be followed at a constant
+    /// speed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is less than two.
+    pub fn with_samples(path: P) -> Self {
+        assert!(N >= 2, "an `ArcLength` needs at least two samples");
+
+        let mut lengths = [0.0; N];
+        let mut last = path.point_at(0.0);
+        for i in 1..N {
+            let point = path.point_at(i as f64 / (N - 1) as f64);
+            lengths[i] = lengths[i - 1] + last.distance(point);
+            last = point;
+        }
+
+        Self { path, lengths }
+    }
+
+    /// The total length of the path.
+    pub fn length(&self) -> f64 {
+        self.lengths[N - 1]
+    }
+
+    /// Allows inspection of the inner path.
+    pub fn path(&self) -> &P {
+        &self.path
+    }
+}
+
+impl<P, const N: usize> Path for ArcLength<P, N>
+where
+    P: Path,
+    P::Point: Distance,
+{
+    type Point = P::Point;
+
+    fn point_at(&self, progress: f64) -> Self::Point {
+        let target = progress * self.length();
+
+        // the first sample which is at least as far along, which progress outside of
+        // `0.0..=1.0` finds at the ends of the table, so it carries on past them
+        let next = self.lengths.partition_point(|&length| length < target).clamp(1, N - 1);
+        let (start, end) = (self.lengths[next - 1], self.lengths[next]);
+
+        let local = if end > start {
+            (target - start) / (end - start)
+        } else {
+            0.0
+        };
+        self.path.point_at((next as f64 - 1.0 + local) / (N - 1) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BezierPath, CatmullRom, Linear, Tweener};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn constant_speed() {
+        let points = [[0.0f32, 0.0], [0.0, 1.0], [1.0, 1.0], [8.0, 8.0]];
+        let bezier = BezierPath::new(&points);
+        let path = ArcLength::<_, 256>::with_samples(bezier);
+
+        // the distance covered in each tenth of the progress, as a ratio of the shortest one
+        let spread = |path: &dyn Path<Point = [f32; 2]>| {
+            let steps: [f64; 10] = core::array::from_fn(|i| {
+                let t = i as f64 / 10.0;
+                path.point_at(t).distance(path.point_at(t + 0.1))
+            });
+            let shortest = steps.iter().copied().fold(f64::INFINITY, f64::min);
+            steps.iter().copied().fold(0.0, f64::max) / shortest
+        };
+
+        assert!(spread(&bezier) > 3.0);
+        assert!(spread(&path) < 1.05);
+    }
+
+    #[test]
+    fn straight_lines() {
+        let waypoints = [[0.0, 0.0], [3.0, 0.0], [3.0, 40.0]];
+        let path = ArcLength::new(CatmullRom::new(&waypoints));
+        assert!(path.length() > 43.0);
+
+        let mut tweener =
+            Tweener::new(ArcLength::new(BezierPath::new(&[0.0f64, 0.0, 1.0, 10.0])).tween(Linear::new, 2));
+        assert_abs_diff_eq!(tweener.update(1).unwrap(), 5.0, epsilon = 1e-2);
+        assert_eq!(tweener.update(1), Some(10.0));
+    }
+}
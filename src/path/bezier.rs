@@ -0,0 +1,105 @@
+use super::{Path, lerp, segment_at};
+use crate::TweenValue;
+
+/// A path made of cubic bezier curves, joined end to end.
+///
+/// The points are the start of the path, followed by the two control points and the end point
+/// of each curve in turn, so a path of `n` curves has `3 * n + 1` points. Every curve takes an
+/// equal share of the progress along the path, no matter how long it is.
+///
+/// ```
+/// # use tween::{BezierPath, Linear, Path};
+/// // a curve rising from the origin, then a straight line along the x axis
+/// let points = [0.0, 0.0, 4.0, 4.0, 4.0, 6.0, 6.0];
+/// let mut tween = BezierPath::new(&points).tween(Linear::new, 2.0);
+///
+/// assert_eq!(tween.run(0.5), 2.0);
+/// assert_eq!(tween.run(1.5), 5.0);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BezierPath<'a, V> {
+    points: &'a [V],
+}
+
+impl<'a, V: TweenValue> BezierPath<'a, V> {
+    /// Creates a new path out of its points.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless there are `3 * n + 1` points, for at least one curve.
+    pub fn new(points: &'a [V]) -> Self {
+        assert!(
+            points.len() >= 4 && points.len() % 3 == 1,
+            "a `BezierPath` needs `3 * n + 1` points"
+        );
+
+        Self { points }
+    }
+
+    /// The number of curves in the path.
+    pub fn curves(&self) -> usize {
+        self.points.len() / 3
+    }
+
+    /// The points of the path.
+    pub fn points(&self) -> &'a [V] {
+        self.points
+    }
+}
+
+impl<V: TweenValue> Path for BezierPath<'_, V> {
+    type Point = V;
+
+    fn point_at(&self, progress: f64) -> V {
+        let (curve, t) = segment_at(progress, self.curves());
+        let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| self.points[curve * 3 + i]);
+
+        // de Casteljau's algorithm, which only needs lerps, so it works for every value
+        let (a, b, c) = (lerp(p0, p1, t), lerp(p1, p2, t), lerp(p2, p3, t));
+        let (d, e) = (lerp(a, b, t), lerp(b, c, t));
+
+        lerp(d, e, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BackIn, Linear, Tween, Tweener};
+    use approx::assert_ulps_eq;
+
+    #[test]
+    fn cubic_curves() {
+        let points = [
+            [0.0f32, 0.0],
+            [0.0, 8.0],
+            [8.0, 8.0],
+            [8.0, 0.0],
+            [8.0, -8.0],
+            [16.0, -8.0],
+            [16.0, 0.0],
+        ];
+        let path = BezierPath::new(&points);
+        assert_eq!(path.curves(), 2);
+
+        assert_eq!(path.point_at(0.0), [0.0, 0.0]);
+        assert_eq!(path.point_at(0.25), [4.0, 6.0]);
+        assert_eq!(path.point_at(0.5), [8.0, 0.0]);
+        assert_eq!(path.point_at(0.75), [12.0, -6.0]);
+        assert_eq!(path.point_at(1.0), [16.0, 0.0]);
+
+        let mut tweener = Tweener::new(path.tween(Linear::new, 4));
+        assert_eq!(tweener.update(1), Some([4.0, 6.0]));
+        assert_eq!(tweener.update(3), Some([16.0, 0.0]));
+    }
+
+    #[test]
+    fn overshoots_past_the_ends() {
+        let points = [0.0, 1.0, 2.0, 3.0];
+        let mut tween = BezierPath::new(&points).tween(BackIn::new, 1.0);
+
+        assert_eq!(tween.range(), 0.0..=3.0);
+        assert!(tween.run(0.3) < 0.0);
+        assert_ulps_eq!(tween.run(1.0), 3.0);
+    }
+}
@@ -0,0 +1,116 @@
+use super::{Distance, Path, lerp, segment_at};
+use crate::math;
+
+/// A path which passes smoothly through every one of a list of waypoints, such as the stops
+/// of a camera fly-through or a patrol route.
+///
+/// This is a centripetal Catmull-Rom spline, which spaces out its curves by the distance
+/// between the waypoints, so it never loops or forms cusps, even when the waypoints are
+/// bunched up unevenly. Every pair of neighbouring waypoints takes an equal share of the
+/// progress along the path.
+///
+/// ```
+/// # use tween::{CatmullRom, Linear, Path};
+/// let waypoints = [[0.0, 0.0], [10.0, 10.0], [20.0, 0.0]];
+/// let mut tween = CatmullRom::new(&waypoints).tween(Linear::new, 2.0);
+///
+/// assert_eq!(tween.run(1.0), [10.0, 10.0]);
+/// assert_eq!(tween.run(2.0), [20.0, 0.0]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CatmullRom<'a, V> {
+    waypoints: &'a [V],
+}
+
+impl<'a, V: Distance> CatmullRom<'a, V> {
+    /// Creates a new path through the given waypoints.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two waypoints.
+    pub fn new(waypoints: &'a [V]) -> Self {
+        assert!(waypoints.len() >= 2, "a `CatmullRom` needs at least two waypoints");
+
+        Self { waypoints }
+    }
+
+    /// The waypoints of the path.
+    pub fn waypoints(&self) -> &'a [V] {
+        self.waypoints
+    }
+}
+
+impl<V: Distance> Path for CatmullRom<'_, V> {
+    type Point = V;
+
+    fn point_at(&self, progress: f64) -> V {
+        let last = self.waypoints.len() - 1;
+        let (index, u) = segment_at(progress, last);
+
+        let (p1, p2) = (self.waypoints[index], self.waypoints[index + 1]);
+        // the ends are continued by reflecting their neighbours
+        let p0 = if index == 0 {
+            lerp(p2, p1, 2.0)
+        } else {
+            self.waypoints[index - 1]
+        };
+        let p3 = if index + 1 == last {
+            lerp(p1, p2, 2.0)
+        } else {
+            self.waypoints[index + 2]
+        };
+
+        // the knots are spaced by the square root of the distance between the points, which
+        // the waypoints of a zero length segment would otherwise divide by
+        let knot = |a: V, b: V| math::sqrt_f64(a.distance(b)).max(f64::EPSILON);
+        let t1 = knot(p0, p1);
+        let t2 = t1 + knot(p1, p2);
+        let t3 = t2 + knot(p2, p3);
+        let t = t1 + (t2 - t1) * u;
+
+        // the Barry-Goldman pyramid, which only needs lerps
+        let a1 = lerp(p0, p1, t / t1);
+        let a2 = lerp(p1, p2, (t - t1) / (t2 - t1));
+        let a3 = lerp(p2, p3, (t - t2) / (t3 - t2));
+        let b1 = lerp(a1, a2, t / t2);
+        let b2 = lerp(a2, a3, (t - t1) / (t3 - t1));
+
+        lerp(b1, b2, (t - t1) / (t2 - t1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn passes_through_waypoints() {
+        let waypoints = [[0.0, 0.0], [1.0, 3.0], [1.5, 3.5], [8.0, -2.0], [9.0, 0.0]];
+        let path = CatmullRom::new(&waypoints);
+
+        for (i, waypoint) in waypoints.iter().enumerate() {
+            let point = path.point_at(i as f64 / 4.0);
+            assert_abs_diff_eq!(point[0], waypoint[0], epsilon = 1e-5);
+            assert_abs_diff_eq!(point[1], waypoint[1], epsilon = 1e-5);
+        }
+
+        // evenly spaced points in a line are followed at a constant speed
+        let path = CatmullRom::new(&[0.0f64, 1.0, 2.0, 3.0]);
+        assert_abs_diff_eq!(path.point_at(0.5), 1.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(path.point_at(0.9), 2.7, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn bunched_waypoints() {
+        // a uniform spline would overshoot the short segment in the middle
+        let path = CatmullRom::new(&[0.0f64, 10.0, 10.1, 20.0]);
+        for i in 0..=100 {
+            let point = path.point_at(1.0 / 3.0 + i as f64 / 300.0);
+            assert!((10.0..=10.1).contains(&point), "{point} left the segment");
+        }
+
+        let path = CatmullRom::new(&[[1.0f32, 1.0], [1.0, 1.0], [2.0, 1.0]]);
+        assert!(path.point_at(0.25)[0].is_finite());
+    }
+}
@@ -0,0 +1,155 @@
+//! Hooks for seeing what this library's updates cost in a frame profiler, which are enabled by
+//! the `profiling` feature.
+//!
+//! Rather than depending on one profiler, spans and counters are handed to a [Profiler] which
+//! is set once, at startup, so they can be forwarded to `tracing`, `puffin`, Tracy, or a frame
+//! timer of your own. Until a profiler is set, each span costs one atomic load.
+//!
+//! These are the spans, each of which is named after the function it times:
+//!
+//! - `TweenManager::update`, `TweenManager::update_with_budget`, and
+//!   `TweenManager::par_update`, with the counter `TweenManager::tweens` of the properties in
+//!   the manager.
+//! - `TweenBank::update_all`, with the counter `TweenBank::tweens`.
+//! - `CurveInfo::bake` and `Tween::bake_into`, with the counter `samples`.
+//!
+//! ```
+//! use tween::profiling::{self, Profiler};
+//!
+//! struct Print;
+//!
+//! impl Profiler for Print {
+//!     fn begin(&self, name: &'static str) {
+//!         println!("begin {name}");
+//!     }
+//!
+//!     fn end(&self, name: &'static str) {
+//!         println!("end {name}");
+//!     }
+//! }
+//!
+//! profiling::set_profiler(&Print).ok();
+//! ```
+
+use std::sync::OnceLock;
+
+static PROFILER: OnceLock<&'static dyn Profiler> = OnceLock::new();
+
+/// Receives the spans and counters of this library. See the [module](self) for what they are.
+pub trait Profiler: Sync {
+    /// Called as the span `name` begins.
+    fn begin(&self, name: &'static str);
+
+    /// Called as the span `name`, the last to begin on this thread, ends.
+    fn end(&self, name: &'static str);
+
+    /// Called with a count of the work in the current span, such as the number of tweens
+    /// updated. This does nothing by default.
+    fn count(&self, name: &'static str, value: usize) {
+        let _ = (name, value);
+    }
+}
+
+/// Sets the profiler which every span and counter is sent to. This can only be done once, so
+/// if a profiler has already been set, `profiler` is handed back as the error.
+pub fn set_profiler(profiler: &'static dyn Profiler) -> Result<(), &'static dyn Profiler> {
+    PROFILER.set(profiler)
+}
+
+/// Times the span `name` from when it's made until it's dropped.
+pub(crate) struct Span(&'static str);
+
+impl Span {
+    pub(crate) fn new(name: &'static str) -> Self {
+        if let Some(profiler) = PROFILER.get() {
+            profiler.begin(name);
+        }
+
+        Self(name)
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(profiler) = PROFILER.get() {
+            profiler.end(self.0);
+        }
+    }
+}
+
+pub(crate) fn count(name: &'static str, value: usize) {
+    if let Some(profiler) = PROFILER.get() {
+        profiler.count(name, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Tween, TweenManager};
+    use std::{
+        format,
+        string::String,
+        sync::Mutex,
+        thread::{self, ThreadId},
+        vec::Vec,
+    };
+
+    /// Records the events of every thread, since other tests update managers at the same time.
+    struct Record(Mutex<Vec<(ThreadId, String)>>);
+
+    impl Record {
+        fn push(&self, event: String) {
+            self.0.lock().unwrap().push((thread::current().id(), event));
+        }
+    }
+
+    impl Profiler for Record {
+        fn begin(&self, name: &'static str) {
+            self.push(format!("begin {name}"));
+        }
+
+        fn end(&self, name: &'static str) {
+            self.push(format!("end {name}"));
+        }
+
+        fn count(&self, name: &'static str, value: usize) {
+            self.push(format!("{name} {value}"));
+        }
+    }
+
+    #[test]
+    fn spans_and_counts() {
+        static RECORD: Record = Record(Mutex::new(Vec::new()));
+        assert!(set_profiler(&RECORD).is_ok());
+        assert!(set_profiler(&RECORD).is_err());
+
+        let mut manager = TweenManager::new();
+        manager.insert(0, Linear::new(0.0..=1.0, 1.0));
+        manager.insert(1, Linear::new(0.0..=1.0, 1.0));
+        manager.update(0.5);
+        Linear::new(0.0..=1.0, 1.0).bake_into(&mut [0.0; 4], 4);
+
+        let this_thread = thread::current().id();
+        let events: Vec<_> = RECORD
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(thread, _)| *thread == this_thread)
+            .map(|(_, event)| event.clone())
+            .collect();
+
+        assert_eq!(
+            events,
+            [
+                "begin TweenManager::update",
+                "TweenManager::tweens 2",
+                "end TweenManager::update",
+                "begin Tween::bake_into",
+                "samples 4",
+                "end Tween::bake_into",
+            ]
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use crate::{Ease, Tween, TweenTime, TweenValue, functions::Float};
+use core::ops::{Add, Div, Mul, Neg, RangeInclusive, Sub};
+
+/// One, with the 62 fractional bits the trigonometry and exponentials are worked out in.
+const ONE_62: i128 = 1 << 62;
+/// `π / 2`, with 62 fractional bits.
+const HALF_PI_62: i128 = 0x6487_ED51_10B4_611A;
+/// The natural log of two, with 62 fractional bits.
+const LN_2_62: i128 = 0x2C5C_85FD_F473_DE6B;
+
+/// A Q32.32 fixed-point number, an `i64` with 32 integer bits and 32 fractional bits, whose
+/// math is all done with integers.
+///
+/// Floats can give different results on different platforms and compilers, especially for
+/// functions like `sin` and `powf`, which breaks lockstep multiplayer games that drive gameplay
+/// from animations. Every operation on a [Q32] is exact integer math, so the same inputs give
+/// bit-identical outputs everywhere. It implements [Float], so every one of the easing
+/// [functions](crate::functions) can be evaluated in it, and [Q32Tween] tweens with it.
+///
+/// ```
+/// # use tween::{functions, Q32};
+/// let eased = functions::sine_in_out(Q32::from_ratio(1, 4));
+/// assert_eq!(eased.to_bits(), 628_983_398);
+/// assert!((eased.to_f64() - functions::sine_in_out(0.25f64)).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Q32(i64);
+
+impl Q32 {
+    /// Zero.
+    pub const ZERO: Self = Self(0);
+    /// One.
+    pub const ONE: Self = Self(1 << 32);
+    /// The largest value, just under `2^31`.
+    pub const MAX: Self = Self(i64::MAX);
+    /// The smallest value, `-2^31`.
+    pub const MIN: Self = Self(i64::MIN);
+
+    /// Creates a number from its raw bits, where `1 << 32` is one.
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// The raw bits of this number, where `1 << 32` is one.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Creates a number from an integer.
+    pub const fn from_int(value: i32) -> Self {
+        Self((value as i64) << 32)
+    }
+
+    /// `numerator / denominator`, rounded towards negative infinity, such as the elapsed
+    /// ticks of a tween over its duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub const fn from_ratio(numerator: i64, denominator: i64) -> Self {
+        Self((((numerator as i128) << 32).div_euclid(denominator as i128)) as i64)
+    }
+
+    /// Converts a float to the nearest number. Converting is exact IEEE math, so it is the
+    /// same everywhere.
+    pub fn from_f64(value: f64) -> Self {
+        let scaled = value * (1u64 << 32) as f64;
+        Self((scaled + if scaled < 0.0 { -0.5 } else { 0.5 }) as i64)
+    }
+
+    /// Converts this number to a float.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << 32) as f64
+    }
+
+    /// `2^self`.
+    fn exp2(self) -> Self {
+        let whole = self.0 >> 32;
+        if whole >= 31 {
+            return Self::MAX;
+        }
+
+        // `e^(fraction * ln 2)`, as a Taylor series
+        let y = ((self.0 & 0xFFFF_FFFF) as i128) << 30;
+        let y = mul_62(y, LN_2_62);
+        let mut fraction = ONE_62;
+        for k in (1..=14).rev() {
+            fraction = ONE_62 + mul_62(y, fraction) / k;
+        }
+
+        let shift = 30 - whole;
+        if shift >= 126 {
+            return Self::ZERO;
+        }
+
+        let half = if shift > 0 { 1 << (shift - 1) } else { 0 };
+        Self(((fraction + half) >> shift) as i64)
+    }
+
+    /// `log2(self)`, for positive numbers.
+    fn log2(self) -> Self {
+        let top = 63 - self.0.leading_zeros() as i64;
+        let whole = top - 32;
+
+        // normalized into `[1, 2)`, whose bits of `log2` are found by squaring it
+        let mut y = (self.0 as i128) << (62 - top);
+        let mut fraction = 0;
+        for bit in (0..32).rev() {
+            y = mul_62(y, y);
+            if y >= 2 * ONE_62 {
+                y >>= 1;
+                fraction |= 1 << bit;
+            }
+        }
+
+        Self((whole << 32) | fraction)
+    }
+}
+
+/// Multiplies two numbers with 62 fractional bits.
+fn mul_62(a: i128, b: i128) -> i128 {
+    (a * b) >> 62
+}
+
+impl Add for Q32 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0.wrapping_add(other.0))
+    }
+}
+
+impl Sub for Q32 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl Mul for Q32 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(((self.0 as i128 * other.0 as i128) >> 32) as i64)
+    }
+}
+
+impl Div for Q32 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self((((self.0 as i128) << 32) / other.0 as i128) as i64)
+    }
+}
+
+impl Neg for Q32 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+impl Float for Q32 {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+    const TWO: Self = Self::from_int(2);
+    const PI: Self = Self(13_493_037_705);
+
+    fn from_f64(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+
+    /// The square root of `self`, or zero for negative numbers.
+    fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+
+        Self((((self.0 as u128) << 32).isqrt()) as i64)
+    }
+
+    /// `self` raised to the power of `exponent`, or zero if `self` isn't positive.
+    fn powf(self, exponent: Self) -> Self {
+        if self.0 <= 0 {
+            Self::ZERO
+        } else if self == Self::TWO {
+            exponent.exp2()
+        } else {
+            (exponent * self.log2()).exp2()
+        }
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        // reduced to within `π / 4` of a multiple of `π / 2`
+        let x = (self.0 as i128) << 30;
+        let quadrant = (x + HALF_PI_62 / 2).div_euclid(HALF_PI_62);
+        let r = x - quadrant * HALF_PI_62;
+        let r2 = mul_62(r, r);
+
+        let (mut sin, mut cos) = (ONE_62, ONE_62);
+        for divisor in [156, 110, 72, 42, 20, 6] {
+            sin = ONE_62 - mul_62(r2, sin) / divisor;
+        }
+        for divisor in [182, 132, 90, 56, 30, 12, 2] {
+            cos = ONE_62 - mul_62(r2, cos) / divisor;
+        }
+
+        let to_q32 = |v: i128| Self(((v + (1 << 29)) >> 30) as i64);
+        let (sin, cos) = (to_q32(mul_62(r, sin)), to_q32(cos));
+        match quadrant.rem_euclid(4) {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        }
+    }
+}
+
+impl TweenValue for Q32 {
+    const ZERO: Self = Self::ZERO;
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        destination - start
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        self * Self::from_f64(scale)
+    }
+}
+
+/// A [Q32Tween] eases a range of [Q32]s with one of the [Ease]s, doing all of its easing
+/// in fixed point, so it gives the same values on every platform.
+///
+/// The time is only divided by the duration to find how far through the tween it is, which
+/// is an exact IEEE operation, and for integer times, such as the ticks of a lockstep
+/// simulation, is the same everywhere too.
+///
+/// ```
+/// # use tween::{Ease, Q32, Q32Tween};
+/// let mut tween = Q32Tween::new(Q32::ZERO..=Q32::from_int(100), 60u32, Ease::ElasticOut);
+///
+/// assert_eq!(tween.run(60), Q32::from_int(100));
+/// assert!((tween.run(15).to_f64() - 100.0 * tween::functions::elastic_out(0.25f64)).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Q32Tween<T> {
+    start: Q32,
+    end: Q32,
+    duration: T,
+    ease: Ease,
+}
+
+impl<T> Q32Tween<T>
+where
+    T: TweenTime,
+{
+    /// Creates a new tween out of a range with a duration, which is eased by `ease`.
+    pub fn new(range: RangeInclusive<Q32>, duration: T, ease: Ease) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            start,
+            end,
+            duration,
+            ease,
+        }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: T) -> Q32 {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// The easing of this tween.
+    pub fn ease(&self) -> Ease {
+        self.ease
+    }
+
+    /// The value at `percent` of the way through, which is exactly the end once finished.
+    fn eased(&self, percent: Q32) -> Q32 {
+        if percent >= Q32::ONE {
+            return self.end;
+        }
+
+        (self.end - self.start) * self.ease.function_q32()(percent) + self.start
+    }
+}
+
+impl<T> Tween for Q32Tween<T>
+where
+    T: TweenTime,
+{
+    type Value = Q32;
+    type Time = T;
+
+    fn run(&mut self, new_time: T) -> Q32 {
+        if let Some(held) = crate::tweens::held_value(self.range(), self.duration, new_time) {
+            return held;
+        }
+
+        self.eased(Q32::from_f64(T::percent(self.duration, new_time)))
+    }
+
+    fn run_percent(&mut self, percent: f64) -> Q32 {
+        self.eased(Q32::from_f64(percent))
+    }
+
+    fn range(&self) -> RangeInclusive<Q32> {
+        self.start..=self.end
+    }
+
+    fn duration(&self) -> T {
+        self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_floats() {
+        for &ease in Ease::ALL {
+            for i in 0..=64 {
+                let t = i as f64 / 64.0;
+                let fixed = ease.function_q32()(Q32::from_f64(t)).to_f64();
+                let float = ease.function_f64()(t);
+
+                assert!(
+                    (fixed - float).abs() < 1e-6,
+                    "{} at {t}: {fixed} vs {float}",
+                    ease.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn math() {
+        assert_eq!(Q32::from_int(9).sqrt(), Q32::from_int(3));
+        assert_eq!(Q32::TWO.powf(Q32::from_int(-3)), Q32::from_ratio(1, 8));
+        assert_eq!(Q32::from_int(-7) / Q32::TWO, Q32::from_ratio(-7, 2));
+
+        let (sin, cos) = Q32::from_f64(-2.5).sin_cos();
+        let (float_sin, float_cos) = crate::math::sin_cos_f64(-2.5);
+        assert!((sin.to_f64() - float_sin).abs() < 1e-9);
+        assert!((cos.to_f64() - float_cos).abs() < 1e-9);
+        assert!((Q32::from_int(3).powf(Q32::from_ratio(1, 2)).to_f64() - crate::math::sqrt_f64(3.0)).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,46 @@
+/// A tiny, seedable random number generator (SplitMix64), used wherever the library needs
+/// reproducible randomness. It is *not* suitable for anything security related.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a new generator from a seed. The same seed always produces the same numbers.
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random `u64`.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random `f64` in `0.0..1.0`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        // the top 53 bits fill an f64's mantissa exactly
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let mut a = SplitMix64::new(7);
+        let mut b = SplitMix64::new(7);
+
+        for _ in 0..16 {
+            let value = a.next_f64();
+            assert_eq!(value, b.next_f64());
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! A serde format for the tests, which saves a value into a tree of [Value]s and loads it back,
+//! so that types can be round tripped without a format crate.
+
+use serde::{
+    de::{
+        self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess, Visitor,
+        value::{Error, MapDeserializer, SeqDeserializer},
+    },
+    ser::{self, Serialize},
+};
+use std::{boxed::Box, string::String, string::ToString, vec::Vec};
+
+/// Saves `value`, and loads it back.
+pub(crate) fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Result<T, Error> {
+    T::deserialize(value.serialize(Saver)?)
+}
+
+/// A saved value.
+#[derive(Debug, Clone)]
+pub(crate) enum Value {
+    Unit,
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Str(String),
+    Option(Option<Box<Value>>),
+    Seq(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+    Variant(&'static str, Box<Value>),
+}
+
+struct Saver;
+
+/// Collects the parts of a sequence, map, or struct, and the variant it's in, if any.
+struct Collector {
+    variant: Option<&'static str>,
+    items: Vec<Value>,
+    entries: Vec<(Value, Value)>,
+}
+
+impl Collector {
+    fn new(variant: Option<&'static str>) -> Self {
+        Self {
+            variant,
+            items: Vec::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    fn seq(self) -> Value {
+        Self::wrap(self.variant, Value::Seq(self.items))
+    }
+
+    fn map(self) -> Value {
+        Self::wrap(self.variant, Value::Map(self.entries))
+    }
+
+    fn wrap(variant: Option<&'static str>, value: Value) -> Value {
+        match variant {
+            Some(variant) => Value::Variant(variant, Box::new(value)),
+            None => value,
+        }
+    }
+
+    fn field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        self.entries
+            .push((Value::Str(key.to_string()), value.serialize(Saver)?));
+        Ok(())
+    }
+}
+
+impl ser::Serializer for Saver {
+    type Ok = Value;
+    type Error = Error;
+    type SerializeSeq = Collector;
+    type SerializeTuple = Collector;
+    type SerializeTupleStruct = Collector;
+    type SerializeTupleVariant = Collector;
+    type SerializeMap = Collector;
+    type SerializeStruct = Collector;
+    type SerializeStructVariant = Collector;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Ok(Value::I64(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Ok(Value::I64(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Ok(Value::I64(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(Value::I64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Ok(Value::U64(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Ok(Value::U64(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Ok(Value::U64(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Ok(Value::U64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        Ok(Value::F64(v.into()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(Value::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::Str(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::Str(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        Ok(Value::Seq(v.iter().map(|&byte| Value::U64(byte.into())).collect()))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::Option(None))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        Ok(Value::Option(Some(Box::new(value.serialize(Saver)?))))
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value, Error> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> Result<Value, Error> {
+        Ok(Value::Variant(variant, Box::new(Value::Unit)))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _: &'static str, value: &T) -> Result<Value, Error> {
+        value.serialize(Saver)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        Ok(Value::Variant(variant, Box::new(value.serialize(Saver)?)))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Collector, Error> {
+        Ok(Collector::new(None))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Collector, Error> {
+        Ok(Collector::new(None))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Collector, Error> {
+        Ok(Collector::new(None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Collector, Error> {
+        Ok(Collector::new(Some(variant)))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Collector, Error> {
+        Ok(Collector::new(None))
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Collector, Error> {
+        Ok(Collector::new(None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Collector, Error> {
+        Ok(Collector::new(Some(variant)))
+    }
+}
+
+macro_rules! collect_items {
+    ($($trait:ident::$method:ident),+) => {
+        $(
+            impl ser::$trait for Collector {
+                type Ok = Value;
+                type Error = Error;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+                    self.items.push(value.serialize(Saver)?);
+                    Ok(())
+                }
+
+                fn end(self) -> Result<Value, Error> {
+                    Ok(self.seq())
+                }
+            }
+        )+
+    };
+}
+
+collect_items!(
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field
+);
+
+impl ser::SerializeMap for Collector {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.items.push(key.serialize(Saver)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .items
+            .pop()
+            .ok_or_else(|| <Error as ser::Error>::custom("a value without a key"))?;
+        self.entries.push((key, value.serialize(Saver)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.map())
+    }
+}
+
+impl ser::SerializeStruct for Collector {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.map())
+    }
+}
+
+impl ser::SerializeStructVariant for Collector {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.map())
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Value {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Value::Unit => visitor.visit_unit(),
+            Value::Bool(v) => visitor.visit_bool(v),
+            Value::I64(v) => visitor.visit_i64(v),
+            Value::U64(v) => visitor.visit_u64(v),
+            Value::F64(v) => visitor.visit_f64(v),
+            Value::Str(v) => visitor.visit_string(v),
+            Value::Option(None) => visitor.visit_none(),
+            Value::Option(Some(v)) => visitor.visit_some(*v),
+            Value::Seq(items) => visitor.visit_seq(SeqDeserializer::new(items.into_iter())),
+            Value::Map(entries) => visitor.visit_map(MapDeserializer::new(entries.into_iter())),
+            Value::Variant(..) => visitor.visit_enum(self),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct newtype_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> EnumAccess<'de> for Value {
+    type Error = Error;
+    type Variant = Value;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> Result<(S::Value, Value), Error> {
+        let Value::Variant(variant, value) = self else {
+            return Err(<Error as de::Error>::custom("expected a variant"));
+        };
+
+        Ok((seed.deserialize(variant.into_deserializer())?, *value))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Value {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> Result<S::Value, Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, _: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+}
@@ -0,0 +1,233 @@
+use crate::TweenTime;
+use core::time::Duration;
+
+mod beats;
+
+pub use beats::{Beats, TempoChange, TempoMap};
+
+/// Durations can be fed straight in as time, such as the elapsed time since a previous
+/// frame. Like their [TweenValue](crate::TweenValue) implementation, they saturate rather than
+/// going negative.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use tween::{Linear, Tweener};
+/// let mut tweener = Tweener::new(Linear::new(0.0..=10.0, Duration::from_secs(2)));
+///
+/// assert_eq!(tweener.update(Duration::from_millis(500)), Some(2.5));
+/// assert_eq!(tweener.update(Duration::from_millis(1500)), Some(10.0));
+/// ```
+impl TweenTime for Duration {
+    const ZERO: Self = Duration::ZERO;
+
+    fn percent(duration: Self, current_time: Self) -> f64 {
+        current_time.as_secs_f64() / duration.as_secs_f64()
+    }
+
+    fn as_f64(self) -> f64 {
+        self.as_secs_f64()
+    }
+
+    fn add(self, other: Self) -> Self {
+        self.saturating_add(other)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self.saturating_sub(other)
+    }
+
+    fn scale(self, multiplier: f64) -> Self {
+        let secs = self.as_secs_f64() * multiplier;
+        if secs > 0.0 {
+            Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    fn is_complete(self, duration: Self) -> bool {
+        self >= duration
+    }
+}
+
+/// Adds `delta` onto an accumulated `time` with compensated (Kahan) summation, where `carry`
+/// keeps the rounding error of every addition so far and is fed back into the next one.
+///
+/// Summing many small float deltas rounds a little on every frame, and those errors pile up
+/// into visible drift once the total grows to hours. With the carry, the total stays within
+/// a rounding of the true sum. Integer times never round, so their carry is always zero.
+pub(crate) fn accumulate<T: TweenTime>(time: &mut T, carry: &mut T, delta: T) {
+    let delta = delta.sub(*carry);
+    let sum = time.add(delta);
+    *carry = sum.sub(*time).sub(delta);
+    *time = sum;
+}
+
+/// Splits a `time` which has run past the end of a loop of `duration` into the number of times
+/// it wrapped, and how far into its last loop it is.
+///
+/// The wraps are found by division rather than by taking off one `duration` at a time, which
+/// would run for as many loops as were skipped, or forever for a float time so large that
+/// taking off a loop doesn't change it. Such a time is left at the start of a loop. A loop with
+/// no duration wraps only once.
+pub(crate) fn wrap<T: TweenTime>(time: T, duration: T) -> (usize, T) {
+    if duration == T::ZERO {
+        return (1, T::ZERO);
+    }
+
+    let mut wraps = 0u128;
+    let mut rest = time;
+    // every division is rounded down a little, so it never takes off more loops than there are,
+    // and the next one takes off what that left
+    for _ in 0..8 {
+        if !rest.is_complete(duration) {
+            break;
+        }
+
+        let percent = T::percent(duration, rest);
+        let loops = crate::math::floor(percent - percent / (1u64 << 40) as f64).max(1.0);
+        if !loops.is_finite() {
+            wraps = u128::MAX;
+            break;
+        }
+
+        rest = rest.sub(duration.scale(loops));
+        wraps = wraps.saturating_add(loops as u128);
+    }
+
+    // a float time can still round just below the start of its loop
+    if !rest.is_complete(T::ZERO) {
+        rest = rest.add(duration);
+        wraps -= 1;
+    }
+    if rest.is_complete(duration) || !rest.is_complete(T::ZERO) {
+        rest = T::ZERO;
+    }
+
+    (wraps.clamp(1, usize::MAX as u128) as usize, rest)
+}
+
+/// A time in integer ticks, such as milliseconds, which wraps back to zero every `PERIOD`
+/// ticks, like the hardware counters of many embedded devices.
+///
+/// Subtracting one time from another gives the ticks elapsed between them, even across the
+/// wrap, so a [Clock](crate::Clock) of these can loop forever, never losing precision the way
+/// an ever growing float would. Every tween run against the clock must be shorter than
+/// `PERIOD`.
+///
+/// ```
+/// # use tween::{Clock, Linear, WrappingTime};
+/// // a millisecond counter which wraps every hour
+/// type Millis = WrappingTime<3_600_000>;
+///
+/// let mut clock = Clock::new();
+/// clock.tick(Millis::new(3_599_000));
+///
+/// let mut fade = clock.tweener(Linear::new(0.0..=1.0, Millis::new(2000)));
+/// clock.tick(Millis::new(1500));
+///
+/// assert_eq!(clock.now(), Millis::new(500));
+/// assert_eq!(fade.sample(&clock), Some(0.75));
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct WrappingTime<const PERIOD: u64> {
+    ticks: u64,
+}
+
+impl<const PERIOD: u64> WrappingTime<PERIOD> {
+    /// Creates a new time, wrapping `ticks` into the period.
+    pub const fn new(ticks: u64) -> Self {
+        Self { ticks: ticks % PERIOD }
+    }
+
+    /// The ticks since the counter last wrapped, in `0..PERIOD`.
+    pub const fn ticks(self) -> u64 {
+        self.ticks
+    }
+}
+
+impl<const PERIOD: u64> TweenTime for WrappingTime<PERIOD> {
+    const ZERO: Self = Self { ticks: 0 };
+
+    fn percent(duration: Self, current_time: Self) -> f64 {
+        u64::percent(duration.ticks, current_time.ticks)
+    }
+
+    fn as_f64(self) -> f64 {
+        self.ticks as f64
+    }
+
+    fn add(self, other: Self) -> Self {
+        let sum = self.ticks as u128 + other.ticks as u128;
+        Self::new((sum % PERIOD as u128) as u64)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        let difference = self.ticks as u128 + PERIOD as u128 - other.ticks as u128;
+        Self::new((difference % PERIOD as u128) as u64)
+    }
+
+    fn scale(self, multiplier: f64) -> Self {
+        Self::new(self.ticks.scale(multiplier))
+    }
+
+    fn is_complete(self, duration: Self) -> bool {
+        self.ticks >= duration.ticks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Clock, Jitter, QuadIn, Tween, Tweener};
+
+    #[test]
+    fn durations() {
+        let mut tween = QuadIn::new(0.0..=100.0, Duration::from_secs(10));
+        assert_eq!(tween.run(Duration::from_secs(5)), 25.0);
+        assert_eq!(tween.run(Duration::from_millis(10_000)), 100.0);
+
+        let mut tweener = Tweener::new(Jitter::new(tween, 0).duration_jitter(2.0..=2.0));
+        assert_eq!(tweener.tween().duration(), Duration::from_secs(20));
+        assert_eq!(tweener.update(Duration::from_secs(10)), Some(25.0));
+        assert_eq!(tweener.update(Duration::MAX), Some(100.0));
+    }
+
+    #[test]
+    fn wrapping() {
+        type Byte = WrappingTime<256>;
+        assert_eq!(Byte::new(300).ticks(), 44);
+        assert_eq!(Byte::new(250).add(Byte::new(10)), Byte::new(4));
+        assert_eq!(Byte::new(4).sub(Byte::new(250)), Byte::new(10));
+
+        type Max = WrappingTime<{ u64::MAX }>;
+        assert_eq!(Max::new(u64::MAX - 1).add(Max::new(3)), Max::new(2));
+        assert_eq!(Max::new(2).sub(Max::new(u64::MAX - 1)), Max::new(3));
+
+        let mut clock = Clock::new();
+        clock.tick(Byte::new(200));
+        let mut tweener = clock.tweener(QuadIn::new(0..=100, Byte::new(100)));
+        for _ in 0..10 {
+            clock.tick(Byte::new(5));
+        }
+        assert_eq!(tweener.sample(&clock), Some(25));
+        clock.tick(Byte::new(50));
+        assert_eq!(tweener.sample(&clock), Some(100));
+    }
+
+    #[test]
+    fn wide_ticks() {
+        // a float would round this to `2^63`
+        assert_eq!((u64::MAX - 1).scale(0.5), (1 << 63) - 1);
+        assert_eq!(10u64.scale(-1.0), 0);
+        assert_eq!(i64::MAX.scale(3.0), i64::MAX);
+
+        assert_eq!(u64::percent(1 << 10, (1 << 62) + 512), (1u64 << 52) as f64 + 0.5);
+        assert_eq!(i64::percent(4, -6), -1.5);
+
+        // milliseconds since the epoch
+        let start: u64 = 1_760_000_000_000;
+        let mut tween = QuadIn::new(0..=1000, 20_000u64);
+        assert_eq!(tween.run((start + 10_000).sub(start)), 250);
+    }
+}
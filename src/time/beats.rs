@@ -0,0 +1,172 @@
+use crate::TweenTime;
+
+/// A time in musical beats, so tweens can be authored against a song rather than a stopwatch.
+/// A [TempoMap] turns the seconds of a transport clock into beats.
+///
+/// ```
+/// # use tween::{Beats, Linear, TempoChange, TempoMap, Tween};
+/// // a four beat pulse, in a song which doubles its tempo at beat 4
+/// let mut pulse = Linear::new(0.0..=1.0, Beats(4.0));
+/// let tempos = [TempoChange::new(0.0, 120.0), TempoChange::new(4.0, 240.0)];
+/// let song = TempoMap::new(&tempos);
+///
+/// assert_eq!(pulse.run(song.beats_at(1.0)), 0.5);
+/// assert_eq!(song.beats_at(2.5), Beats(6.0));
+/// ```
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Default)]
+pub struct Beats(pub f64);
+
+impl Beats {
+    /// Creates a time from whole bars, with the given number of beats in each bar.
+    pub fn bars(bars: f64, beats_per_bar: f64) -> Self {
+        Self(bars * beats_per_bar)
+    }
+}
+
+impl TweenTime for Beats {
+    const ZERO: Self = Self(0.0);
+
+    fn percent(duration: Self, current_time: Self) -> f64 {
+        current_time.0 / duration.0
+    }
+
+    fn as_f64(self) -> f64 {
+        self.0
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    fn scale(self, multiplier: f64) -> Self {
+        Self(self.0 * multiplier)
+    }
+
+    fn is_complete(self, duration: Self) -> bool {
+        self.0 >= duration.0
+    }
+}
+
+/// A change to a new tempo, at a given beat of a [TempoMap].
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub struct TempoChange {
+    /// The beat this tempo starts at.
+    pub beat: f64,
+    /// The tempo from this beat on, in beats per minute.
+    pub bpm: f64,
+}
+
+impl TempoChange {
+    /// Creates a new change to `bpm`, starting at `beat`.
+    pub const fn new(beat: f64, bpm: f64) -> Self {
+        Self { beat, bpm }
+    }
+}
+
+/// The tempos of a song over time, which converts between the seconds of a transport clock
+/// and [Beats].
+///
+/// Tempos change instantly at each [TempoChange]. The first tempo also runs backwards to
+/// the start of the song, however late its beat is, and the last runs on forever.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TempoMap<'a> {
+    changes: &'a [TempoChange],
+}
+
+impl<'a> TempoMap<'a> {
+    /// Creates a new tempo map out of its changes, sorted by their beats.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no changes, or if they aren't sorted by their beats.
+    pub fn new(changes: &'a [TempoChange]) -> Self {
+        assert!(!changes.is_empty(), "a tempo map needs at least one tempo");
+        assert!(
+            changes.windows(2).all(|w| w[0].beat <= w[1].beat),
+            "tempo changes must be sorted by their beats"
+        );
+
+        Self { changes }
+    }
+
+    /// The beat the transport is on, `seconds` into the song.
+    pub fn beats_at(&self, seconds: f64) -> Beats {
+        let mut start = Beats::ZERO;
+        let mut elapsed = 0.0;
+
+        for (i, change) in self.changes.iter().enumerate() {
+            let Some(next) = self.changes.get(i + 1) else {
+                break;
+            };
+
+            let length = (next.beat - start.0) * 60.0 / change.bpm;
+            if seconds < elapsed + length {
+                break;
+            }
+
+            elapsed += length;
+            start = Beats(next.beat);
+        }
+
+        let tempo = self.tempo_at(start);
+        Beats(start.0 + (seconds - elapsed) * tempo / 60.0)
+    }
+
+    /// How many seconds into the song the transport reaches `beats`.
+    pub fn seconds_at(&self, beats: Beats) -> f64 {
+        let mut seconds = 0.0;
+        let mut from = 0.0;
+
+        for (i, change) in self.changes.iter().enumerate() {
+            let to = self.changes.get(i + 1).map_or(f64::INFINITY, |next| next.beat);
+            if beats.0 < to || to.is_infinite() {
+                return seconds + (beats.0 - from) * 60.0 / change.bpm;
+            }
+
+            seconds += (to - from) * 60.0 / change.bpm;
+            from = to;
+        }
+
+        seconds
+    }
+
+    /// The tempo at `beats`, in beats per minute.
+    pub fn tempo_at(&self, beats: Beats) -> f64 {
+        let later = self.changes[1..].partition_point(|change| change.beat <= beats.0);
+        self.changes[later].bpm
+    }
+
+    /// The changes of this tempo map.
+    pub fn changes(&self) -> &'a [TempoChange] {
+        self.changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tempo_changes() {
+        let changes = [
+            TempoChange::new(0.0, 60.0),
+            TempoChange::new(2.0, 120.0),
+            TempoChange::new(6.0, 30.0),
+        ];
+        let song = TempoMap::new(&changes);
+
+        assert_eq!(song.beats_at(1.0), Beats(1.0));
+        assert_eq!(song.beats_at(3.0), Beats(4.0));
+        assert_eq!(song.beats_at(6.0), Beats(7.0));
+        assert_eq!(song.tempo_at(Beats(5.0)), 120.0);
+
+        for seconds in [0.0, 1.5, 2.0, 3.5, 4.0, 10.0] {
+            assert_eq!(song.seconds_at(song.beats_at(seconds)), seconds);
+        }
+        assert_eq!(Beats::bars(2.0, 3.0), Beats(6.0));
+    }
+}
@@ -1,4 +1,69 @@
-use crate::{Tween, TweenTime};
+use crate::{
+    Ease, Eased, Tween, TweenTime, TweenValue,
+    time::{accumulate, wrap},
+};
+use core::ops::RangeInclusive;
+
+mod accumulator;
+mod clock;
+#[cfg(feature = "std")]
+mod hooks;
+mod pool;
+#[cfg(feature = "std")]
+mod realtime;
+mod retarget;
+#[cfg(feature = "std")]
+mod shared;
+mod targeted;
+
+pub use accumulator::Accumulator;
+pub use clock::{Clock, ClockedTweener};
+#[cfg(feature = "std")]
+pub use hooks::{HookedLooper, HookedTweener};
+pub use pool::StaticPool;
+#[cfg(feature = "std")]
+pub use realtime::RealtimeTweener;
+pub use retarget::Retargetable;
+#[cfg(feature = "std")]
+pub use shared::SharedTweener;
+#[cfg(feature = "async")]
+pub use shared::Values;
+pub use targeted::{FnLens, Lens, Targeted, TweenBy};
+
+/// How a tween should be left when it is cancelled before it has completed.
+///
+/// ```
+/// # use tween::{CancelMode, Linear, Tweener};
+/// let mut tweener = Tweener::new(Linear::new(0..=10, 10));
+/// tweener.update(4);
+///
+/// assert_eq!(tweener.clone().cancel(CancelMode::Complete), 10);
+/// assert_eq!(tweener.clone().cancel(CancelMode::Freeze), 4);
+/// assert_eq!(tweener.cancel(CancelMode::Revert), 0);
+///
+/// // once cancelled, a tweener is finished
+/// assert!(tweener.is_finished());
+/// ```
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CancelMode {
+    /// Jumps straight to the end value of the tween.
+    Complete,
+    /// Keeps the tween's current value.
+    Freeze,
+    /// Jumps back to the start value of the tween.
+    Revert,
+}
+
+/// Finds the value a tween should be left at when it is cancelled at the given time.
+pub(crate) fn cancelled_value<T: Tween>(tween: &mut T, time: T::Time, fused: bool, mode: CancelMode) -> T::Value {
+    match mode {
+        CancelMode::Complete => *tween.range().end(),
+        CancelMode::Freeze if fused => *tween.range().end(),
+        CancelMode::Freeze => tween.run(time),
+        CancelMode::Revert => *tween.range().start(),
+    }
+}
 
 /// A delta tweener is "drives" a tween for you, allowing
 /// you to provide *deltas* in time, rather than new time values.
@@ -10,6 +75,11 @@ use crate::{Tween, TweenTime};
 /// If, on the other hand, you use a *fixed* time loop, see [FixedTweener],
 /// which provides a simpler interface, and implements Iterator.
 ///
+/// Deltas are summed with their rounding error carried over, so even a tween which runs for
+/// hours on `f32` deltas doesn't drift from the time that has really passed. For such long
+/// tweens, prefer an `f64` time though, since an `f32` can only tell apart times a few
+/// milliseconds from each other once it has counted a few hours.
+///
 /// ```
 /// # use tween::{Tweener, Linear};
 ///
@@ -22,12 +92,44 @@ use crate::{Tween, TweenTime};
 /// assert_eq!(delta_tweener.update(100), None); // tween is done forever now.
 /// ```
 #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tweener<T: Tween> {
     tween: T,
     last_time: T::Time,
+    // the rounding error of `last_time`, see `accumulate`
+    carry: T::Time,
     fused: bool,
 }
 
+impl<V, T> Tweener<Eased<V, T>>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Creates a new [Tweener] out of an easing picked at runtime. See [Ease].
+    pub fn eased(range: RangeInclusive<V>, duration: T, ease: Ease) -> Self {
+        Self::new(ease.tween(range, duration))
+    }
+
+    /// Switches the tween to another easing mid-flight, keeping its range and duration, and
+    /// the time it has already run for. See [Eased::set_ease].
+    ///
+    /// ```
+    /// # use tween::{Ease, Tweener};
+    /// let mut tweener = Tweener::eased(0.0..=100.0, 4.0, Ease::QuadOut);
+    /// assert_eq!(tweener.update(2.0), Some(75.0));
+    ///
+    /// // the drag was let go, so it bounces the rest of the way
+    /// tweener.set_ease(Ease::BounceOut);
+    /// assert_eq!(tweener.tween().ease(), Ease::BounceOut);
+    /// assert_eq!(tweener.update(1.0), Some(97.265625));
+    /// assert_eq!(tweener.current_time(), 3.0);
+    /// ```
+    pub fn set_ease(&mut self, ease: Ease) {
+        self.tween.set_ease(ease);
+    }
+}
+
 impl<T> Tweener<T>
 where
     T: Tween,
@@ -37,6 +139,7 @@ where
         Self {
             tween,
             last_time: T::Time::ZERO,
+            carry: T::Time::ZERO,
             fused: false,
         }
     }
@@ -47,7 +150,7 @@ where
     /// receive the max value of the tween.
     pub fn update(&mut self, delta: T::Time) -> Option<T::Value> {
         if !self.fused {
-            self.last_time = self.last_time.add(delta);
+            accumulate(&mut self.last_time, &mut self.carry, delta);
 
             if self.last_time.is_complete(self.tween.duration()) {
                 self.fused = true;
@@ -60,51 +163,171 @@ where
         }
     }
 
-    // /// Converts this tweener to a [Looper].
-    // pub fn looper(self) -> Looper<T, TValue, TTime> {
-    //     Looper::new(self)
-    // }
+    /// Restarts the [Tweener] from the beginning, so that it will play again even
+    /// if it had already finished.
+    ///
+    /// ```
+    /// # use tween::{Tweener, Linear};
+    /// let mut tweener = Tweener::new(Linear::new(0..=2, 2));
+    ///
+    /// assert_eq!(tweener.update(2), Some(2));
+    /// assert_eq!(tweener.update(1), None);
+    ///
+    /// tweener.restart();
+    /// assert_eq!(tweener.update(1), Some(1));
+    /// ```
+    pub fn restart(&mut self) {
+        self.last_time = T::Time::ZERO;
+        self.carry = T::Time::ZERO;
+        self.fused = false;
+    }
+
+    /// Jumps the [Tweener] back to the start of its tween, but keeps its playing
+    /// state. A tweener which is still running will keep running from the start,
+    /// whereas a tweener which has already finished will *stay* finished. If you
+    /// want to replay a finished tweener, use [restart](Self::restart).
+    pub fn rewind(&mut self) {
+        self.last_time = T::Time::ZERO;
+        self.carry = T::Time::ZERO;
+    }
+
+    /// Allows inspections of a given tween.
+    pub fn tween(&self) -> &T {
+        &self.tween
+    }
+
+    /// The current time of the tween.
+    pub fn current_time(&self) -> T::Time {
+        self.last_time
+    }
+
+    /// Returns `true` if the [Tweener] has completed, and will only return `None`
+    /// from now on.
+    pub fn is_finished(&self) -> bool {
+        self.fused
+    }
+
+    /// Cancels the [Tweener], finishing it immediately. Returns the value the tween should
+    /// be left at, according to the given [CancelMode].
+    pub fn cancel(&mut self, mode: CancelMode) -> T::Value {
+        let value = cancelled_value(&mut self.tween, self.last_time, self.fused, mode);
+        self.fused = true;
+
+        value
+    }
+
+    /// Converts this tweener to a [Looper].
+    pub fn looper(self) -> Looper<T> {
+        Looper::new(self)
+    }
+}
+
+/// A [Looper] is a wrapper around a [Tweener], which makes it so that
+/// every time the tweener *would* fuse (end), it instead loops.
+///
+/// ```
+/// # use tween::{Tweener, Linear};
+/// let mut looper = Tweener::new(Linear::new(0..=2, 2)).looper();
+///
+/// assert_eq!(looper.update(1), Some(1));
+/// assert_eq!(looper.update(1), Some(2));
+/// assert_eq!(looper.update(1), Some(1));
+/// assert_eq!(looper.loops(), 1);
+/// ```
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "Tweener<T>: serde::Serialize",
+        deserialize = "Tweener<T>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Looper<T: Tween> {
+    tweener: Tweener<T>,
+    loops: usize,
 }
 
-// /// A [Looper] is a wrapper around a [Tweener], which makes it so that
-// /// every time the tweener *would*
-// pub struct Looper<T, TValue, TTime>(Tweener<T, TValue, TTime>);
-
-// impl<T, TValue, TTime> Looper<T, TValue, TTime>
-// where
-//     TValue: TweenValue,
-//     TTime: TweenTime,
-//     T: Tween<Time = TTime, Value = TValue>,
-// {
-//     /// Creates a new Looper around a [Tweener].
-//     ///
-//     /// If the [Tweener] is *already* fused, this will reset it to starting
-//     /// values.
-//     pub fn new(mut delta_tweener: Tweener<T, TValue, TTime>) -> Self {
-//         // unfuse it...
-//         if delta_tweener.fused {
-//             delta_tweener.last_time = TTime::ZERO;
-//             delta_tweener.fused = false;
-//         }
-
-//         Self(delta_tweener)
-//     }
-
-//     /// Drives the inner [Tweener] forward X steps in time, looping if required.
-//     ///
-//     /// If the delta given is great enough, you may loop around several times.
-//     pub fn update(&mut self, delta: TTime) -> Option<TValue> {
-//         let output = self.0.update(delta).unwrap(); // we make sure this ALWAYS returns `some`.
-
-//         // catch the fused here...
-//         if self.0.fused {
-//             self.0.last_time = TTime::ZERO;
-//             self.0.fused = false;
-//         }
-
-//         Some(output)
-//     }
-// }
+impl<T> core::fmt::Debug for Looper<T>
+where
+    T: Tween + core::fmt::Debug,
+    T::Time: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Looper")
+            .field("tweener", &self.tweener)
+            .field("loops", &self.loops)
+            .finish()
+    }
+}
+
+impl<T> Looper<T>
+where
+    T: Tween,
+{
+    /// Creates a new Looper around a [Tweener].
+    ///
+    /// If the [Tweener] is *already* fused, this will reset it to starting
+    /// values.
+    pub fn new(mut tweener: Tweener<T>) -> Self {
+        // unfuse it...
+        if tweener.fused {
+            tweener.restart();
+        }
+
+        Self { tweener, loops: 0 }
+    }
+
+    /// Drives the inner [Tweener] forward X steps in time, looping if required.
+    ///
+    /// If the delta given is great enough, you may loop around several times. Every wrap is
+    /// counted, and the time past the last one is carried into the next loop.
+    ///
+    /// ```
+    /// # use tween::{Tweener, Linear};
+    /// let mut looper = Tweener::new(Linear::new(0..=2, 2)).looper();
+    ///
+    /// assert_eq!(looper.update(5), Some(2));
+    /// assert_eq!(looper.loops(), 2);
+    /// assert_eq!(looper.tweener().current_time(), 1);
+    /// ```
+    pub fn update(&mut self, delta: T::Time) -> Option<T::Value> {
+        let output = self.tweener.update(delta); // we make sure this ALWAYS returns `some`.
+
+        // catch the fused here...
+        if self.tweener.fused {
+            let (wraps, overflow) = wrap(self.tweener.last_time, self.tweener.tween.duration());
+
+            self.tweener.restart();
+            self.tweener.last_time = overflow;
+            self.loops = self.loops.saturating_add(wraps);
+        }
+
+        output
+    }
+
+    /// The number of times this [Looper] has wrapped back around to its start.
+    pub fn loops(&self) -> usize {
+        self.loops
+    }
+
+    /// Restarts the [Looper] from the beginning, resetting the elapsed time and
+    /// the loop counter.
+    pub fn restart(&mut self) {
+        self.tweener.restart();
+        self.loops = 0;
+    }
+
+    /// Jumps back to the start of the current loop, but keeps the loop counter.
+    pub fn rewind(&mut self) {
+        self.tweener.rewind();
+    }
+
+    /// Allows inspections of the inner [Tweener].
+    pub fn tweener(&self) -> &Tweener<T> {
+        &self.tweener
+    }
+}
 
 /// A FixedTweener "drives" a tween for you, allowing you provide *deltas*
 /// instead of concrete values, per call. Moreover, a FixedTweener always works on
@@ -127,9 +350,12 @@ where
 /// assert_eq!(fixed_tweener.next(), None);
 /// ```
 #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedTweener<T: Tween> {
     tween: T,
     last_time: T::Time,
+    // the rounding error of `last_time`, see `accumulate`
+    carry: T::Time,
     delta: T::Time,
     fused: bool,
 }
@@ -144,6 +370,7 @@ where
         Self {
             tween,
             last_time: T::Time::ZERO,
+            carry: T::Time::ZERO,
             delta,
             fused: false,
         }
@@ -159,10 +386,41 @@ where
         self.last_time
     }
 
-    // /// Converts this tweener to a [FixedLooper].
-    // pub fn looper(self) -> FixedLooper<T, TValue, TTime> {
-    //     FixedLooper::new(self)
-    // }
+    /// Restarts the [FixedTweener] from the beginning, so that it will play again even
+    /// if it had already finished.
+    pub fn restart(&mut self) {
+        self.last_time = T::Time::ZERO;
+        self.carry = T::Time::ZERO;
+        self.fused = false;
+    }
+
+    /// Jumps the [FixedTweener] back to the start of its tween, but keeps its playing
+    /// state. A finished tweener will *stay* finished. If you want to replay a finished
+    /// tweener, use [restart](Self::restart).
+    pub fn rewind(&mut self) {
+        self.last_time = T::Time::ZERO;
+        self.carry = T::Time::ZERO;
+    }
+
+    /// Returns `true` if the [FixedTweener] has completed, and will only return `None`
+    /// from now on.
+    pub fn is_finished(&self) -> bool {
+        self.fused
+    }
+
+    /// Cancels the [FixedTweener], finishing it immediately. Returns the value the tween
+    /// should be left at, according to the given [CancelMode].
+    pub fn cancel(&mut self, mode: CancelMode) -> T::Value {
+        let value = cancelled_value(&mut self.tween, self.last_time, self.fused, mode);
+        self.fused = true;
+
+        value
+    }
+
+    /// Converts this tweener to a [FixedLooper].
+    pub fn looper(self) -> FixedLooper<T> {
+        FixedLooper::new(self)
+    }
 }
 
 impl<T> Iterator for FixedTweener<T>
@@ -173,7 +431,7 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         if !self.fused {
-            self.last_time = self.last_time.add(self.delta);
+            accumulate(&mut self.last_time, &mut self.carry, self.delta);
 
             if self.last_time.is_complete(self.tween.duration()) {
                 self.fused = true;
@@ -187,49 +445,94 @@ where
     }
 }
 
-// /// A [FixedLooper] is a wrapper around a [FixedTweener], which makes it so that
-// /// every time the tweener *would* fuse (end), it instead loops.
-// pub struct FixedLooper<T, TValue, TTime>(FixedTweener<T, TValue, TTime>);
-
-// impl<T, TValue, TTime> FixedLooper<T, TValue, TTime>
-// where
-//     T: Tween,
-//     TValue: TweenValue,
-//     TTime: TweenTime,
-// {
-//     /// Creates a new FixedLooper. If the tweener is already complete, then it will
-//     /// reset it.
-//     pub fn new(mut tweener: FixedTweener<T, TValue, TTime>) -> Self {
-//         // unfuse it...
-//         if tweener.fused {
-//             tweener.last_time = TTime::ZERO;
-//             tweener.fused = false;
-//         }
-
-//         Self(tweener)
-//     }
-// }
-
-// impl<T, TValue, TTime> Iterator for FixedLooper<T, TValue, TTime>
-// where
-//     TValue: TweenValue,
-//     TTime: TweenTime,
-//     T: Tween<Time = TTime, Value = TValue>,
-// {
-//     type Item = TValue;
-
-//     fn next(&mut self) -> Option<Self::Item> {
-//         let output = self.0.next().unwrap(); // we make sure this ALWAYS returns `some`.
-
-//         // catch the fused here...
-//         if self.0.fused {
-//             self.0.last_time = TTime::ZERO;
-//             self.0.fused = false;
-//         }
-
-//         Some(output)
-//     }
-// }
+/// A [FixedLooper] is a wrapper around a [FixedTweener], which makes it so that
+/// every time the tweener *would* fuse (end), it instead loops.
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "FixedTweener<T>: serde::Serialize",
+        deserialize = "FixedTweener<T>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct FixedLooper<T: Tween> {
+    tweener: FixedTweener<T>,
+    loops: usize,
+}
+
+impl<T> core::fmt::Debug for FixedLooper<T>
+where
+    T: Tween + core::fmt::Debug,
+    T::Time: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedLooper")
+            .field("tweener", &self.tweener)
+            .field("loops", &self.loops)
+            .finish()
+    }
+}
+
+impl<T> FixedLooper<T>
+where
+    T: Tween,
+{
+    /// Creates a new FixedLooper. If the tweener is already complete, then it will
+    /// reset it.
+    pub fn new(mut tweener: FixedTweener<T>) -> Self {
+        // unfuse it...
+        if tweener.fused {
+            tweener.restart();
+        }
+
+        Self { tweener, loops: 0 }
+    }
+
+    /// The number of times this [FixedLooper] has wrapped back around to its start.
+    pub fn loops(&self) -> usize {
+        self.loops
+    }
+
+    /// Restarts the [FixedLooper] from the beginning, resetting the elapsed time and
+    /// the loop counter.
+    pub fn restart(&mut self) {
+        self.tweener.restart();
+        self.loops = 0;
+    }
+
+    /// Jumps back to the start of the current loop, but keeps the loop counter.
+    pub fn rewind(&mut self) {
+        self.tweener.rewind();
+    }
+
+    /// Allows inspections of the inner [FixedTweener].
+    pub fn tweener(&self) -> &FixedTweener<T> {
+        &self.tweener
+    }
+}
+
+impl<T> Iterator for FixedLooper<T>
+where
+    T: Tween,
+{
+    type Item = T::Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let output = self.tweener.next(); // we make sure this ALWAYS returns `some`.
+
+        // catch the fused here...
+        if self.tweener.fused {
+            let (wraps, overflow) = wrap(self.tweener.last_time, self.tweener.tween.duration());
+
+            self.tweener.restart();
+            self.tweener.last_time = overflow;
+            self.loops = self.loops.saturating_add(wraps);
+        }
+
+        output
+    }
+}
 
 #[cfg(feature = "std")]
 #[cfg(test)]
@@ -245,23 +548,133 @@ mod tests {
         assert_eq!(*values, [10, 20, 30, 40, 50, 60, 70, 80, 90, 100]);
     }
 
-    // #[test]
-    // fn fixed_tweener_loop() {
-    //     let mut looper = FixedTweener::new(Linear::new(0..=2, 2), 1).looper();
+    #[test]
+    fn fixed_tweener_loop() {
+        let mut looper = FixedTweener::new(Linear::new(0..=2, 2), 1).looper();
+
+        assert_eq!(looper.next().unwrap(), 1);
+        assert_eq!(looper.next().unwrap(), 2);
+        assert_eq!(looper.next().unwrap(), 1);
+        assert_eq!(looper.next().unwrap(), 2);
+        assert_eq!(looper.loops(), 2);
+    }
 
-    //     assert_eq!(looper.next().unwrap(), 1);
-    //     assert_eq!(looper.next().unwrap(), 2);
-    //     assert_eq!(looper.next().unwrap(), 1);
-    //     assert_eq!(looper.next().unwrap(), 2);
-    // }
+    #[test]
+    fn tweener_loop() {
+        let mut looper = Tweener::new(Linear::new(0..=2, 2)).looper();
+
+        assert_eq!(looper.update(1).unwrap(), 1);
+        assert_eq!(looper.update(1).unwrap(), 2);
+        assert_eq!(looper.update(1).unwrap(), 1);
+        assert_eq!(looper.update(1).unwrap(), 2);
+        assert_eq!(looper.loops(), 2);
+    }
+
+    #[test]
+    fn cancel() {
+        let mut tweener = FixedTweener::new(Linear::new(0..=10, 10), 3);
+        tweener.next();
+        assert_eq!(tweener.clone().cancel(CancelMode::Freeze), 3);
+        assert_eq!(tweener.cancel(CancelMode::Complete), 10);
+        assert_eq!(tweener.next(), None);
+
+        let mut tweener = Tweener::new(Linear::new(0..=10, 10));
+        assert_eq!(tweener.cancel(CancelMode::Freeze), 0);
+
+        let mut tweener = Tweener::new(Linear::new(0..=10, 10));
+        tweener.update(20);
+        assert_eq!(tweener.cancel(CancelMode::Freeze), 10);
+    }
+
+    #[test]
+    fn long_running() {
+        // an hour at 60 frames a second, which plainly summed would drift by seconds
+        let mut tweener = Tweener::new(Linear::new(0.0..=1.0, 7200.0f32));
+        for _ in 0..216_000 {
+            tweener.update(1.0 / 60.0);
+        }
+        assert!((tweener.current_time() - 3600.0).abs() < 0.001);
 
-    // #[test]
-    // fn tweener_loop() {
-    //     let mut looper = Tweener::new(Linear::new(0..=2, 2)).looper();
+        let mut tweener = FixedTweener::new(Linear::new(0.0..=1.0, 7200.0f32), 1.0 / 60.0);
+        tweener.nth(215_999);
+        assert!((tweener.current_time() - 3600.0).abs() < 0.001);
+    }
 
-    //     assert_eq!(looper.update(1).unwrap(), 1);
-    //     assert_eq!(looper.update(1).unwrap(), 2);
-    //     assert_eq!(looper.update(1).unwrap(), 1);
-    //     assert_eq!(looper.update(1).unwrap(), 2);
-    // }
+    #[test]
+    fn restart_and_rewind() {
+        let mut tweener = Tweener::new(Linear::new(0..=4, 4));
+        assert_eq!(tweener.update(3), Some(3));
+
+        tweener.rewind();
+        assert_eq!(tweener.update(1), Some(1));
+
+        assert_eq!(tweener.update(10), Some(4));
+        tweener.rewind();
+        assert_eq!(tweener.update(1), None);
+
+        tweener.restart();
+        assert_eq!(tweener.update(1), Some(1));
+
+        let mut looper = tweener.looper();
+        looper.update(3);
+        assert_eq!(looper.loops(), 1);
+        looper.rewind();
+        assert_eq!(looper.loops(), 1);
+        looper.restart();
+        assert_eq!(looper.loops(), 0);
+        assert_eq!(looper.update(2), Some(2));
+    }
+
+    #[test]
+    fn loop_past_several_durations() {
+        let mut looper = Tweener::new(Linear::new(0..=2, 2)).looper();
+
+        assert_eq!(looper.update(5), Some(2));
+        assert_eq!(looper.loops(), 2);
+        assert_eq!(looper.update(0), Some(1));
+        assert_eq!(looper.update(4), Some(2));
+        assert_eq!(looper.loops(), 4);
+        assert_eq!(looper.update(1), Some(2));
+        assert_eq!(looper.loops(), 5);
+    }
+
+    #[test]
+    fn loop_past_huge_delta() {
+        // subtracting a loop from this time doesn't change it, so it can't be wrapped one loop
+        // at a time
+        let mut looper = Tweener::new(Linear::new(0.0f32..=1.0, 1.0f32)).looper();
+        assert_eq!(looper.update(1.0e10), Some(1.0));
+        assert!(looper.loops().abs_diff(10_000_000_000) <= 1);
+        assert_eq!(looper.tweener().current_time(), 0.0);
+
+        let mut looper = Tweener::new(Linear::new(0.0f32..=1.0, 1.0f32)).looper();
+        assert_eq!(looper.update(f32::INFINITY), Some(1.0));
+        assert_eq!(looper.tweener().current_time(), 0.0);
+
+        let mut looper = Tweener::new(Linear::new(0..=10, 3u64)).looper();
+        looper.update(u64::MAX);
+        assert_eq!(looper.loops() as u64, u64::MAX / 3);
+        assert_eq!(looper.tweener().current_time(), u64::MAX % 3);
+    }
+
+    #[test]
+    fn fixed_loop_carries_overflow() {
+        let mut looper = FixedTweener::new(Linear::new(0..=4, 4), 3).looper();
+
+        assert_eq!(looper.next(), Some(3));
+        assert_eq!(looper.next(), Some(4));
+        assert_eq!(looper.tweener().current_time(), 2);
+        assert_eq!(looper.next(), Some(4));
+        assert_eq!(looper.next(), Some(4));
+        assert_eq!(looper.loops(), 3);
+        assert_eq!(looper.tweener().current_time(), 0);
+
+        // fifteen ticks of time in, three whole loops and three ticks into the next
+        assert_eq!(looper.next(), Some(3));
+        assert_eq!(looper.loops(), 3);
+
+        let mut looper = FixedTweener::new(Linear::new(0.0f32..=1.0, 1.0f32), 2.5e9).looper();
+        assert_eq!(looper.next(), Some(1.0));
+        assert!(looper.loops().abs_diff(2_500_000_000) <= 1);
+    }
 }
@@ -0,0 +1,158 @@
+use crate::{Tween, TweenTime, TweenValue};
+
+/// An [Accumulator] drives a tween at a *fixed* simulation rate, while being fed a *variable*
+/// real delta time.
+///
+/// Every call to [update](Self::update) adds the real delta to an internal accumulator, and
+/// steps the tween forward in fixed `step` sized increments for as long as the accumulator
+/// allows. Whatever time is left over (the residual) is used to interpolate between the
+/// last two simulated values, which gives you a smooth value to render with. This is the
+/// classic "fix your timestep" game loop, and keeps animations in lockstep with a
+/// fixed-rate physics simulation.
+///
+/// ```
+/// # use tween::{Accumulator, Linear};
+/// // we simulate at steps of 2.0, but our frames are only 1.0 long.
+/// let mut accumulator = Accumulator::new(Linear::new(0.0..=8.0, 8.0), 2.0);
+///
+/// // not enough time has passed to take a step, so we're halfway between the start and
+/// // the first step.
+/// assert_eq!(accumulator.update(1.0), Some(0.0));
+/// assert_eq!(accumulator.alpha(), 0.5);
+///
+/// // now we take our first step
+/// assert_eq!(accumulator.update(1.0), Some(0.0));
+/// assert_eq!(accumulator.simulated_value(), 2.0);
+///
+/// // and now we render halfway between the first and the second step
+/// assert_eq!(accumulator.update(3.0), Some(3.0));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Accumulator<T: Tween> {
+    tween: T,
+    step: T::Time,
+    simulated_time: T::Time,
+    residual: T::Time,
+    previous: T::Value,
+    current: T::Value,
+    fused: bool,
+}
+
+impl<T> Accumulator<T>
+where
+    T: Tween,
+{
+    /// Creates a new [Accumulator], which will step the given tween forward by `step` at a
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, since the accumulator could never drain.
+    pub fn new(tween: T, step: T::Time) -> Self {
+        assert!(step != T::Time::ZERO, "an accumulator's step cannot be zero");
+
+        let start = *tween.range().start();
+        Self {
+            tween,
+            step,
+            simulated_time: T::Time::ZERO,
+            residual: T::Time::ZERO,
+            previous: start,
+            current: start,
+            fused: false,
+        }
+    }
+
+    /// Feeds a real, variable delta time into the accumulator, taking as many fixed steps
+    /// as fit into the accumulated time. Returns the value to *render*, which is
+    /// interpolated between the last two simulated values by [alpha](Self::alpha).
+    ///
+    /// Once the simulation reaches the end of the tween, this returns the end value once,
+    /// and `None` after that.
+    pub fn update(&mut self, delta: T::Time) -> Option<T::Value> {
+        if self.fused {
+            return None;
+        }
+
+        self.residual = self.residual.add(delta);
+
+        while self.residual.is_complete(self.step) {
+            self.residual = self.residual.sub(self.step);
+            self.simulated_time = self.simulated_time.add(self.step);
+            self.previous = self.current;
+
+            if self.simulated_time.is_complete(self.tween.duration()) {
+                self.fused = true;
+                self.current = *self.tween.range().end();
+
+                return Some(self.current);
+            }
+
+            self.current = self.tween.run(self.simulated_time);
+        }
+
+        let delta = T::Value::calculate_delta(self.current, self.previous);
+        Some(self.previous.add(delta.scale(self.alpha())))
+    }
+
+    /// How far, from `0.0` to `1.0`, the leftover time is between the last simulated step and
+    /// the next one.
+    pub fn alpha(&self) -> f64 {
+        T::Time::percent(self.step, self.residual)
+    }
+
+    /// The value of the tween at the last fixed step. Use this, rather than the interpolated
+    /// value, for anything which feeds back into your simulation.
+    pub fn simulated_value(&self) -> T::Value {
+        self.current
+    }
+
+    /// The total simulated time, which is always a multiple of the step.
+    pub fn simulated_time(&self) -> T::Time {
+        self.simulated_time
+    }
+
+    /// The fixed step this accumulator steps by.
+    pub fn step(&self) -> T::Time {
+        self.step
+    }
+
+    /// Allows inspections of a given tween.
+    pub fn tween(&self) -> &T {
+        &self.tween
+    }
+
+    /// Returns `true` if the simulation has reached the end of the tween.
+    pub fn is_finished(&self) -> bool {
+        self.fused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[test]
+    fn steps_at_fixed_rate() {
+        let mut accumulator = Accumulator::new(Linear::new(0..=10, 10), 2);
+
+        assert_eq!(accumulator.update(5), Some(3));
+        assert_eq!(accumulator.simulated_time(), 4);
+        assert_eq!(accumulator.simulated_value(), 4);
+        assert_eq!(accumulator.alpha(), 0.5);
+
+        assert_eq!(accumulator.update(1), Some(4));
+        assert_eq!(accumulator.simulated_time(), 6);
+
+        assert_eq!(accumulator.update(100), Some(10));
+        assert!(accumulator.is_finished());
+        assert_eq!(accumulator.update(1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_step() {
+        Accumulator::new(Linear::new(0..=10, 10), 0);
+    }
+}
@@ -0,0 +1,165 @@
+use super::{CancelMode, cancelled_value};
+use crate::{Tween, TweenTime, time::accumulate};
+
+/// A [Clock] is a single, shared source of time for many tweens.
+///
+/// Rather than each tweener accumulating its own deltas (and potentially drifting from each
+/// other over time), you [tick](Self::tick) one clock, and every [ClockedTweener] made from it
+/// samples its value from the clock's current time. Tweens which are started together are
+/// therefore *guaranteed* to stay in sync.
+///
+/// ```
+/// # use tween::{Clock, Linear, QuadIn};
+/// let mut clock = Clock::new();
+///
+/// let mut fade = clock.tweener(Linear::new(0.0..=1.0, 4.0));
+/// let mut slide = clock.tweener(QuadIn::new(0.0..=100.0, 4.0));
+///
+/// clock.tick(2.0);
+/// assert_eq!(fade.sample(&clock), Some(0.5));
+/// assert_eq!(slide.sample(&clock), Some(25.0));
+/// ```
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy, Default)]
+pub struct Clock<T> {
+    now: T,
+    // the rounding error of `now`, see `accumulate`
+    carry: T,
+}
+
+impl<T> Clock<T>
+where
+    T: TweenTime,
+{
+    /// Creates a new [Clock], starting at zero.
+    pub fn new() -> Self {
+        Self {
+            now: T::ZERO,
+            carry: T::ZERO,
+        }
+    }
+
+    /// Advances the clock by `delta`. Every [ClockedTweener] made from this clock will see
+    /// the new time the next time it is sampled.
+    pub fn tick(&mut self, delta: T) {
+        accumulate(&mut self.now, &mut self.carry, delta);
+    }
+
+    /// The current time of the clock.
+    pub fn now(&self) -> T {
+        self.now
+    }
+
+    /// Creates a new [ClockedTweener] which starts *now*, according to this clock.
+    pub fn tweener<Tw>(&self, tween: Tw) -> ClockedTweener<Tw>
+    where
+        Tw: Tween<Time = T>,
+    {
+        ClockedTweener {
+            tween,
+            start_time: self.now,
+            fused: false,
+        }
+    }
+}
+
+/// A [ClockedTweener] drives a tween from a shared [Clock]. See [Clock] for more
+/// information.
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+pub struct ClockedTweener<Tw: Tween> {
+    tween: Tw,
+    start_time: Tw::Time,
+    fused: bool,
+}
+
+impl<Tw> ClockedTweener<Tw>
+where
+    Tw: Tween,
+{
+    /// Samples the tween at the clock's current time.
+    ///
+    /// Once the clock has passed the end of the tween, this returns the end value once, and
+    /// `None` after that, just like a [Tweener](crate::Tweener).
+    ///
+    /// This should always be given the same clock which made this tweener.
+    pub fn sample(&mut self, clock: &Clock<Tw::Time>) -> Option<Tw::Value> {
+        if self.fused {
+            return None;
+        }
+
+        let elapsed = self.elapsed(clock);
+        if elapsed.is_complete(self.tween.duration()) {
+            self.fused = true;
+            Some(*self.tween.range().end())
+        } else {
+            Some(self.tween.run(elapsed))
+        }
+    }
+
+    /// Cancels the tweener, finishing it immediately. Returns the value the tween should be
+    /// left at, according to the given [CancelMode], where [CancelMode::Freeze] uses the
+    /// clock's current time.
+    pub fn cancel(&mut self, clock: &Clock<Tw::Time>, mode: CancelMode) -> Tw::Value {
+        let elapsed = self.elapsed(clock);
+        let value = cancelled_value(&mut self.tween, elapsed, self.fused, mode);
+        self.fused = true;
+
+        value
+    }
+
+    /// How much time has elapsed on this tweener, according to the clock.
+    pub fn elapsed(&self, clock: &Clock<Tw::Time>) -> Tw::Time {
+        clock.now().sub(self.start_time)
+    }
+
+    /// The time on the clock which this tweener started at.
+    pub fn start_time(&self) -> Tw::Time {
+        self.start_time
+    }
+
+    /// Restarts the tweener so that it starts *now*, according to the given clock.
+    pub fn restart(&mut self, clock: &Clock<Tw::Time>) {
+        self.start_time = clock.now();
+        self.fused = false;
+    }
+
+    /// Allows inspections of a given tween.
+    pub fn tween(&self) -> &Tw {
+        &self.tween
+    }
+
+    /// Returns `true` if the tween has completed.
+    pub fn is_finished(&self) -> bool {
+        self.fused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[test]
+    fn tweeners_stay_in_sync() {
+        let mut clock = Clock::new();
+        let mut a = clock.tweener(Linear::new(0..=10, 10));
+
+        clock.tick(4);
+        let mut b = clock.tweener(Linear::new(0..=10, 10));
+        assert_eq!(b.start_time(), 4);
+
+        clock.tick(2);
+        assert_eq!(a.sample(&clock), Some(6));
+        assert_eq!(b.sample(&clock), Some(2));
+
+        clock.tick(10);
+        assert_eq!(a.sample(&clock), Some(10));
+        assert_eq!(a.sample(&clock), None);
+        assert_eq!(b.sample(&clock), Some(10));
+
+        a.restart(&clock);
+        clock.tick(5);
+        assert_eq!(a.sample(&clock), Some(5));
+        assert_eq!(a.cancel(&clock, CancelMode::Freeze), 5);
+        assert_eq!(a.sample(&clock), None);
+    }
+}
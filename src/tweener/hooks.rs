@@ -0,0 +1,276 @@
+use super::{Looper, Tweener};
+use crate::Tween;
+use std::boxed::Box;
+
+/// The callbacks shared by [HookedTweener] and [HookedLooper].
+struct Hooks<V> {
+    on_start: Option<Box<dyn FnMut()>>,
+    on_update: Option<Box<dyn FnMut(V)>>,
+    started: bool,
+}
+
+impl<V: Copy> Hooks<V> {
+    fn new() -> Self {
+        Self {
+            on_start: None,
+            on_update: None,
+            started: false,
+        }
+    }
+
+    fn fire(&mut self, value: Option<V>) {
+        let Some(value) = value else {
+            return;
+        };
+
+        if !self.started {
+            self.started = true;
+            if let Some(on_start) = self.on_start.as_mut() {
+                on_start();
+            }
+        }
+
+        if let Some(on_update) = self.on_update.as_mut() {
+            on_update(value);
+        }
+    }
+}
+
+/// A [HookedTweener] is a [Tweener] which calls back into your code as it runs: once when it
+/// starts, on every update with the new value, and once when it completes.
+///
+/// ```
+/// # use tween::{Linear, Tweener};
+/// # use std::{cell::Cell, rc::Rc};
+/// let completed = Rc::new(Cell::new(false));
+/// let last_value = Rc::new(Cell::new(0));
+///
+/// let mut tweener = Tweener::new(Linear::new(0..=10, 10))
+///     .hooked()
+///     .on_update({
+///         let last_value = last_value.clone();
+///         move |value| last_value.set(value)
+///     })
+///     .on_complete({
+///         let completed = completed.clone();
+///         move || completed.set(true)
+///     });
+///
+/// tweener.update(4);
+/// assert_eq!(last_value.get(), 4);
+/// assert!(!completed.get());
+///
+/// tweener.update(6);
+/// assert_eq!(last_value.get(), 10);
+/// assert!(completed.get());
+/// ```
+pub struct HookedTweener<T: Tween> {
+    tweener: Tweener<T>,
+    hooks: Hooks<T::Value>,
+    on_complete: Option<Box<dyn FnMut()>>,
+}
+
+impl<T> HookedTweener<T>
+where
+    T: Tween,
+{
+    /// Creates a new [HookedTweener], with no hooks yet.
+    pub fn new(tweener: Tweener<T>) -> Self {
+        Self {
+            tweener,
+            hooks: Hooks::new(),
+            on_complete: None,
+        }
+    }
+
+    /// Sets a hook which is called on the first update.
+    pub fn on_start(mut self, on_start: impl FnMut() + 'static) -> Self {
+        self.hooks.on_start = Some(Box::new(on_start));
+        self
+    }
+
+    /// Sets a hook which is called on every update, with the new value.
+    pub fn on_update(mut self, on_update: impl FnMut(T::Value) + 'static) -> Self {
+        self.hooks.on_update = Some(Box::new(on_update));
+        self
+    }
+
+    /// Sets a hook which is called once, on the update which completes the tween.
+    pub fn on_complete(mut self, on_complete: impl FnMut() + 'static) -> Self {
+        self.on_complete = Some(Box::new(on_complete));
+        self
+    }
+
+    /// Drives the inner [Tweener] forward X steps in time, calling any hooks along the way.
+    pub fn update(&mut self, delta: T::Time) -> Option<T::Value> {
+        let was_finished = self.tweener.is_finished();
+        let output = self.tweener.update(delta);
+        self.hooks.fire(output);
+
+        if !was_finished && self.tweener.is_finished() {
+            if let Some(on_complete) = self.on_complete.as_mut() {
+                on_complete();
+            }
+        }
+
+        output
+    }
+
+    /// Restarts the inner [Tweener]. The start hook will be called again on the next update.
+    pub fn restart(&mut self) {
+        self.tweener.restart();
+        self.hooks.started = false;
+    }
+
+    /// Allows inspection of the inner [Tweener].
+    pub fn tweener(&self) -> &Tweener<T> {
+        &self.tweener
+    }
+}
+
+/// A [HookedLooper] is a [Looper] which calls back into your code as it runs: once when it
+/// starts, on every update with the new value, and every time it loops.
+///
+/// ```
+/// # use tween::{Linear, Tweener};
+/// # use std::{cell::Cell, rc::Rc};
+/// let loops = Rc::new(Cell::new(0));
+///
+/// let mut looper = Tweener::new(Linear::new(0..=2, 2)).looper().hooked().on_repeat({
+///     let loops = loops.clone();
+///     move |count| loops.set(count)
+/// });
+///
+/// looper.update(2);
+/// looper.update(2);
+/// assert_eq!(loops.get(), 2);
+/// ```
+pub struct HookedLooper<T: Tween> {
+    looper: Looper<T>,
+    hooks: Hooks<T::Value>,
+    on_repeat: Option<Box<dyn FnMut(usize)>>,
+}
+
+impl<T> HookedLooper<T>
+where
+    T: Tween,
+{
+    /// Creates a new [HookedLooper], with no hooks yet.
+    pub fn new(looper: Looper<T>) -> Self {
+        Self {
+            looper,
+            hooks: Hooks::new(),
+            on_repeat: None,
+        }
+    }
+
+    /// Sets a hook which is called on the first update.
+    pub fn on_start(mut self, on_start: impl FnMut() + 'static) -> Self {
+        self.hooks.on_start = Some(Box::new(on_start));
+        self
+    }
+
+    /// Sets a hook which is called on every update, with the new value.
+    pub fn on_update(mut self, on_update: impl FnMut(T::Value) + 'static) -> Self {
+        self.hooks.on_update = Some(Box::new(on_update));
+        self
+    }
+
+    /// Sets a hook which is called every time the looper wraps around, with the number of
+    /// loops completed so far.
+    pub fn on_repeat(mut self, on_repeat: impl FnMut(usize) + 'static) -> Self {
+        self.on_repeat = Some(Box::new(on_repeat));
+        self
+    }
+
+    /// Drives the inner [Looper] forward X steps in time, calling any hooks along the way.
+    pub fn update(&mut self, delta: T::Time) -> Option<T::Value> {
+        let loops = self.looper.loops();
+        let output = self.looper.update(delta);
+        self.hooks.fire(output);
+
+        if self.looper.loops() != loops {
+            if let Some(on_repeat) = self.on_repeat.as_mut() {
+                on_repeat(self.looper.loops());
+            }
+        }
+
+        output
+    }
+
+    /// Restarts the inner [Looper]. The start hook will be called again on the next update.
+    pub fn restart(&mut self) {
+        self.looper.restart();
+        self.hooks.started = false;
+    }
+
+    /// Allows inspection of the inner [Looper].
+    pub fn looper(&self) -> &Looper<T> {
+        &self.looper
+    }
+}
+
+impl<T: Tween> Tweener<T> {
+    /// Converts this tweener to a [HookedTweener], which can call back into your code.
+    pub fn hooked(self) -> HookedTweener<T> {
+        HookedTweener::new(self)
+    }
+}
+
+impl<T: Tween> Looper<T> {
+    /// Converts this looper to a [HookedLooper], which can call back into your code.
+    pub fn hooked(self) -> HookedLooper<T> {
+        HookedLooper::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Linear, Tweener};
+    use std::{cell::RefCell, rc::Rc, vec::Vec};
+
+    #[test]
+    fn hook_order() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+
+        let mut tweener = Tweener::new(Linear::new(0..=4, 4))
+            .hooked()
+            .on_start({
+                let events = events.clone();
+                move || events.borrow_mut().push("start")
+            })
+            .on_update({
+                let events = events.clone();
+                move |_| events.borrow_mut().push("update")
+            })
+            .on_complete({
+                let events = events.clone();
+                move || events.borrow_mut().push("complete")
+            });
+
+        tweener.update(2);
+        tweener.update(2);
+        tweener.update(2);
+        assert_eq!(*events.borrow(), ["start", "update", "update", "complete"]);
+
+        events.borrow_mut().clear();
+        tweener.restart();
+        tweener.update(1);
+        assert_eq!(*events.borrow(), ["start", "update"]);
+    }
+
+    #[test]
+    fn repeat_hook() {
+        let repeats = Rc::new(RefCell::new(Vec::new()));
+
+        let mut looper = Tweener::new(Linear::new(0..=2, 2)).looper().hooked().on_repeat({
+            let repeats = repeats.clone();
+            move |count| repeats.borrow_mut().push(count)
+        });
+
+        for _ in 0..6 {
+            looper.update(1);
+        }
+        assert_eq!(*repeats.borrow(), [1, 2, 3]);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{Tween, Tweener};
+
+/// A [StaticPool] drives up to `N` tweens at once, stored inline, so it never allocates. It's
+/// meant for embedded UIs which can't touch the heap after they start.
+///
+/// Every tween in the pool must be the same type, so to mix easings, use an
+/// [Eased](crate::Eased), which can be any easing without a box. Tweens are put into a free
+/// slot, and the index of that slot is the handle to the tween.
+///
+/// ```
+/// # use tween::{Ease, Eased, StaticPool};
+/// let mut pool: StaticPool<Eased<f32, f32>, 2> = StaticPool::new();
+/// let fade = pool.insert(Ease::Linear.tween(0.0..=1.0, 2.0)).unwrap();
+/// let slide = pool.insert(Ease::QuadIn.tween(0.0..=100.0, 4.0)).unwrap();
+///
+/// // the pool is full, so the tween is handed back
+/// assert!(pool.insert(Ease::Linear.tween(0.0..=1.0, 1.0)).is_err());
+///
+/// pool.update(2.0);
+/// assert_eq!(pool.get(fade), Some(1.0));
+/// assert_eq!(pool.get(slide), Some(25.0));
+///
+/// // finished tweens keep their slots until they're cleared
+/// pool.clear_finished();
+/// assert_eq!(pool.len(), 1);
+/// ```
+#[derive(Clone)]
+pub struct StaticPool<Tw: Tween, const N: usize> {
+    slots: [Option<Slot<Tw>>; N],
+}
+
+#[derive(Clone)]
+struct Slot<Tw: Tween> {
+    tweener: Tweener<Tw>,
+    value: Tw::Value,
+}
+
+impl<Tw, const N: usize> StaticPool<Tw, N>
+where
+    Tw: Tween,
+{
+    /// Creates a new, empty [StaticPool].
+    pub fn new() -> Self {
+        Self {
+            slots: core::array::from_fn(|_| None),
+        }
+    }
+
+    /// Puts a tween into a free slot, returning the index of that slot. If every slot is
+    /// taken, the tween is handed back as the error.
+    pub fn insert(&mut self, tween: Tw) -> Result<usize, Tw> {
+        let Some(index) = self.slots.iter().position(Option::is_none) else {
+            return Err(tween);
+        };
+
+        self.slots[index] = Some(Slot {
+            value: *tween.range().start(),
+            tweener: Tweener::new(tween),
+        });
+
+        Ok(index)
+    }
+
+    /// Drives every tween in the pool forward X steps in time.
+    pub fn update(&mut self, delta: Tw::Time) {
+        for slot in self.slots.iter_mut().flatten() {
+            if let Some(value) = slot.tweener.update(delta) {
+                slot.value = value;
+            }
+        }
+    }
+
+    /// The current value of the tween in the slot at `index`. Finished tweens keep their
+    /// final value until they are removed.
+    pub fn get(&self, index: usize) -> Option<Tw::Value> {
+        self.slot(index).map(|slot| slot.value)
+    }
+
+    /// Returns `true` if the slot at `index` has a tween which is still running.
+    pub fn is_active(&self, index: usize) -> bool {
+        self.slot(index).is_some_and(|slot| !slot.tweener.is_finished())
+    }
+
+    /// Removes the tween in the slot at `index`, freeing the slot, and returns its tweener.
+    pub fn remove(&mut self, index: usize) -> Option<Tweener<Tw>> {
+        self.slots.get_mut(index)?.take().map(|slot| slot.tweener)
+    }
+
+    /// Frees every slot whose tween has finished.
+    pub fn clear_finished(&mut self) {
+        for slot in &mut self.slots {
+            if slot.as_ref().is_some_and(|slot| slot.tweener.is_finished()) {
+                *slot = None;
+            }
+        }
+    }
+
+    /// Iterates over the index of every taken slot, and the current value of its tween.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, Tw::Value)> + '_ {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| slot.as_ref().map(|slot| (index, slot.value)))
+    }
+
+    /// The number of taken slots.
+    pub fn len(&self) -> usize {
+        self.slots.iter().flatten().count()
+    }
+
+    /// Returns `true` if no slots are taken.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if every slot is taken, so [insert](Self::insert) would fail.
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    fn slot(&self, index: usize) -> Option<&Slot<Tw>> {
+        self.slots.get(index)?.as_ref()
+    }
+}
+
+impl<Tw, const N: usize> Default for StaticPool<Tw, N>
+where
+    Tw: Tween,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Tw, const N: usize> core::fmt::Debug for StaticPool<Tw, N>
+where
+    Tw: Tween,
+    Tw::Value: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[test]
+    fn reuses_slots() {
+        let mut pool: StaticPool<Linear<i32, i32>, 2> = StaticPool::new();
+        let a = pool.insert(Linear::new(0..=10, 10)).unwrap();
+        let b = pool.insert(Linear::new(0..=10, 20)).unwrap();
+        assert!(pool.is_full());
+        assert_eq!(pool.insert(Linear::new(5..=6, 1)), Err(Linear::new(5..=6, 1)));
+
+        pool.update(10);
+        assert!(!pool.is_active(a));
+        assert!(pool.is_active(b));
+
+        pool.clear_finished();
+        assert_eq!(pool.get(a), None);
+        assert_eq!(pool.insert(Linear::new(5..=6, 1)), Ok(a));
+        assert!(pool.iter().eq([(0, 5), (1, 5)]));
+
+        assert!(pool.remove(b).is_some());
+        assert_eq!(pool.len(), 1);
+    }
+}
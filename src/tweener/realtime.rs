@@ -0,0 +1,127 @@
+use super::{CancelMode, cancelled_value};
+use crate::Tween;
+use std::time::{Duration, Instant};
+
+/// A [RealtimeTweener] keeps its own time, by noting when it was started and measuring how
+/// long ago that was whenever it is sampled. This suits code which has no frame loop to pass
+/// deltas in from, like command line tools or async tasks.
+///
+/// It runs tweens which use [Duration] as their time.
+///
+/// ```
+/// # use std::time::{Duration, Instant};
+/// # use tween::{Linear, RealtimeTweener};
+/// let mut tweener = RealtimeTweener::new(Linear::new(0.0..=100.0, Duration::from_secs(2)));
+///
+/// // usually you'd call `sample`, which uses `Instant::now()`
+/// let later = tweener.start_time() + Duration::from_millis(500);
+/// assert_eq!(tweener.sample_at(later), Some(25.0));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RealtimeTweener<Tw: Tween<Time = Duration>> {
+    tween: Tw,
+    start_time: Instant,
+    fused: bool,
+}
+
+impl<Tw> RealtimeTweener<Tw>
+where
+    Tw: Tween<Time = Duration>,
+{
+    /// Creates a new [RealtimeTweener], which starts right now.
+    pub fn new(tween: Tw) -> Self {
+        Self::starting_at(tween, Instant::now())
+    }
+
+    /// Creates a new [RealtimeTweener], which starts at the given instant.
+    pub fn starting_at(tween: Tw, start_time: Instant) -> Self {
+        Self {
+            tween,
+            start_time,
+            fused: false,
+        }
+    }
+
+    /// Samples the tween at the current time.
+    ///
+    /// Once the end of the tween has passed, this returns the end value once, and `None`
+    /// after that, just like a [Tweener](crate::Tweener).
+    pub fn sample(&mut self) -> Option<Tw::Value> {
+        self.sample_at(Instant::now())
+    }
+
+    /// Samples the tween at the given instant, which is handy for sampling many tweeners at
+    /// exactly the same time. Instants before the tween started give its start value.
+    pub fn sample_at(&mut self, now: Instant) -> Option<Tw::Value> {
+        if self.fused {
+            return None;
+        }
+
+        let elapsed = now.saturating_duration_since(self.start_time);
+        if elapsed >= self.tween.duration() {
+            self.fused = true;
+            Some(*self.tween.range().end())
+        } else {
+            Some(self.tween.run(elapsed))
+        }
+    }
+
+    /// Cancels the tweener, finishing it immediately. Returns the value the tween should be
+    /// left at, according to the given [CancelMode], where [CancelMode::Freeze] uses the
+    /// current time.
+    pub fn cancel(&mut self, mode: CancelMode) -> Tw::Value {
+        let elapsed = self.elapsed();
+        let value = cancelled_value(&mut self.tween, elapsed, self.fused, mode);
+        self.fused = true;
+
+        value
+    }
+
+    /// How much time has elapsed since this tweener started.
+    pub fn elapsed(&self) -> Duration {
+        self.start_time.elapsed()
+    }
+
+    /// The instant this tweener started at.
+    pub fn start_time(&self) -> Instant {
+        self.start_time
+    }
+
+    /// Restarts the tweener so that it starts right now.
+    pub fn restart(&mut self) {
+        self.start_time = Instant::now();
+        self.fused = false;
+    }
+
+    /// Allows inspections of a given tween.
+    pub fn tween(&self) -> &Tw {
+        &self.tween
+    }
+
+    /// Returns `true` if the tween has completed.
+    pub fn is_finished(&self) -> bool {
+        self.fused
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[test]
+    fn samples_elapsed_time() {
+        let start = Instant::now();
+        let mut tweener = RealtimeTweener::starting_at(Linear::new(0..=10, Duration::from_secs(10)), start);
+
+        assert_eq!(tweener.sample_at(start + Duration::from_secs(3)), Some(3));
+        assert_eq!(tweener.sample_at(start + Duration::from_secs(12)), Some(10));
+        assert_eq!(tweener.sample_at(start + Duration::from_secs(13)), None);
+        assert!(tweener.is_finished());
+
+        tweener.restart();
+        assert!(tweener.start_time() >= start);
+        assert_eq!(tweener.sample_at(start), Some(0));
+        assert!(tweener.sample().is_some());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{Hermite, Tween, TweenTime, TweenValue};
+
+/// A [Retargetable] tweener drives a tween just like a [Tweener](crate::Tweener), but can be
+/// given a new end value while it is running *without* popping.
+///
+/// When [retargeted](Self::retarget), it measures the value and the instantaneous velocity of
+/// whatever it is currently running, and replaces it with a [Hermite] curve which starts from
+/// exactly that value, moving at exactly that velocity, and comes to rest at the new end.
+///
+/// ```
+/// # use tween::{Linear, Retargetable};
+/// let mut tweener = Retargetable::new(Linear::new(0.0..=10.0, 10.0));
+/// assert_eq!(tweener.update(5.0), Some(5.0));
+///
+/// // the target moved! we pick up from 5.0, still moving to the right.
+/// tweener.retarget(20.0);
+/// assert_eq!(tweener.value(), 5.0);
+///
+/// let next = tweener.update(0.1).unwrap();
+/// assert!(next > 5.0 && next < 5.2);
+///
+/// assert_eq!(tweener.update(100.0), Some(20.0));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Retargetable<Tw: Tween> {
+    tween: Tw,
+    curve: Option<Hermite<Tw::Value, Tw::Time>>,
+    last_time: Tw::Time,
+    fused: bool,
+}
+
+impl<Tw> Retargetable<Tw>
+where
+    Tw: Tween,
+{
+    /// Creates a new [Retargetable] tweener out of an existing tween.
+    pub fn new(tween: Tw) -> Self {
+        Self {
+            tween,
+            curve: None,
+            last_time: Tw::Time::ZERO,
+            fused: false,
+        }
+    }
+
+    /// Drives the tweener forward X steps in time.
+    ///
+    /// If an input higher than the current curve's `duration` is given, you will receive its
+    /// end value, and `None` after that, until it is retargeted.
+    pub fn update(&mut self, delta: Tw::Time) -> Option<Tw::Value> {
+        if self.fused {
+            return None;
+        }
+
+        self.last_time = self.last_time.add(delta);
+
+        if self.last_time.is_complete(self.duration()) {
+            self.fused = true;
+            Some(self.end())
+        } else {
+            Some(self.run(self.last_time))
+        }
+    }
+
+    /// Gives the tweener a new end value. The replacement curve starts from the current value
+    /// and velocity, and takes as long as the original tween did.
+    pub fn retarget(&mut self, new_end: Tw::Value) {
+        let start = self.value();
+        let velocity = self.velocity();
+        let duration = self.tween.duration();
+
+        self.curve = Some(Hermite::with_velocities(
+            start..=new_end,
+            velocity,
+            Tw::Value::ZERO,
+            duration,
+        ));
+        self.last_time = Tw::Time::ZERO;
+        self.fused = false;
+    }
+
+    /// The current value of the tweener.
+    pub fn value(&mut self) -> Tw::Value {
+        if self.fused {
+            self.end()
+        } else {
+            self.run(self.last_time)
+        }
+    }
+
+    /// The current, instantaneous velocity of the tweener, in value per unit of time. This is
+    /// estimated numerically, so it works for any tween.
+    pub fn velocity(&mut self) -> Tw::Value {
+        if self.fused {
+            return Tw::Value::ZERO;
+        }
+
+        let duration = self.duration();
+        let mut step = duration.scale(1e-3);
+        if step == Tw::Time::ZERO {
+            // integer time can't step less than 1, so we use the smallest step we can. scaling
+            // by exactly `1 / duration` can round down to 0, so this aims halfway past a tick
+            step = duration.scale(1.5 / duration.as_f64());
+        }
+        if step == Tw::Time::ZERO {
+            return Tw::Value::ZERO;
+        }
+
+        let here = self.run(self.last_time);
+        let there = self.run(self.last_time.add(step));
+
+        Tw::Value::calculate_delta(there, here).scale(1.0 / step.as_f64())
+    }
+
+    /// The current time of the tweener, since it was created or last retargeted.
+    pub fn current_time(&self) -> Tw::Time {
+        self.last_time
+    }
+
+    /// Returns `true` if the tweener has completed.
+    pub fn is_finished(&self) -> bool {
+        self.fused
+    }
+
+    fn run(&mut self, time: Tw::Time) -> Tw::Value {
+        match self.curve.as_mut() {
+            Some(curve) => curve.run(time),
+            None => self.tween.run(time),
+        }
+    }
+
+    fn end(&self) -> Tw::Value {
+        match self.curve.as_ref() {
+            Some(curve) => *curve.range().end(),
+            None => *self.tween.range().end(),
+        }
+    }
+
+    fn duration(&self) -> Tw::Time {
+        match self.curve.as_ref() {
+            Some(curve) => curve.duration(),
+            None => self.tween.duration(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, QuadIn};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn preserves_value_and_velocity() {
+        let mut tweener = Retargetable::new(QuadIn::new(0.0f64..=100.0, 10.0));
+        tweener.update(5.0);
+
+        let value = tweener.value();
+        let velocity = tweener.velocity();
+        assert_abs_diff_eq!(velocity, 10.0, epsilon = 0.1);
+
+        tweener.retarget(0.0);
+        assert_abs_diff_eq!(tweener.value(), value, epsilon = 1e-9);
+        assert_abs_diff_eq!(tweener.velocity(), velocity, epsilon = 0.1);
+    }
+
+    #[test]
+    fn retarget_after_finishing() {
+        let mut tweener = Retargetable::new(Linear::new(0..=10, 10));
+        assert_eq!(tweener.update(20), Some(10));
+        assert_eq!(tweener.update(1), None);
+
+        tweener.retarget(20);
+        assert_eq!(tweener.velocity(), 0);
+        assert_eq!(tweener.update(5), Some(15));
+        assert_eq!(tweener.update(5), Some(20));
+    }
+
+    #[test]
+    fn integer_time_velocity() {
+        // 49 ticks can't be split into thousandths, or exactly into 1 / 49ths
+        let mut tweener = Retargetable::new(Linear::new(0.0f32..=49.0, 49u32));
+        tweener.update(10);
+        assert_eq!(tweener.velocity(), 1.0);
+
+        tweener.retarget(0.0);
+        assert_eq!(tweener.value(), 10.0);
+        let next = tweener.update(1).unwrap();
+        assert!(next.is_finite() && next > 10.0);
+    }
+}
@@ -0,0 +1,176 @@
+use super::Tweener;
+use crate::Tween;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+#[cfg(feature = "async")]
+mod future;
+#[cfg(feature = "async")]
+pub use future::Values;
+
+/// A [SharedTweener] is a [Tweener] behind a lock, which one thread drives while others read
+/// the latest value it produced, such as a UI thread moving a volume slider which an audio
+/// thread reads every buffer.
+///
+/// Cloning a [SharedTweener] clones the handle, not the tween, so every clone drives and reads
+/// the same tween. The latest value is kept apart from the tweener, so reading it only waits
+/// on the moment the value is stored, never on the update itself, and [try_value] never
+/// waits at all.
+///
+/// ```
+/// # use tween::{Linear, SharedTweener};
+/// let tweener = SharedTweener::new(Linear::new(0.0..=1.0, 4.0));
+/// let reader = tweener.clone();
+///
+/// std::thread::spawn(move || {
+///     tweener.update(1.0);
+/// })
+/// .join()
+/// .unwrap();
+///
+/// assert_eq!(reader.value(), 0.25);
+/// ```
+///
+/// With the `async` feature, tasks can await `finished` instead of polling, or read every new
+/// value from the stream of `values`.
+///
+/// [try_value]: Self::try_value
+pub struct SharedTweener<Tw: Tween> {
+    shared: Arc<Shared<Tw>>,
+}
+
+struct Shared<Tw: Tween> {
+    tweener: Mutex<Tweener<Tw>>,
+    value: Mutex<Tw::Value>,
+    #[cfg(feature = "async")]
+    signal: Mutex<future::Signal>,
+}
+
+impl<Tw> SharedTweener<Tw>
+where
+    Tw: Tween,
+{
+    /// Creates a new [SharedTweener], whose value starts at the start of `tween`.
+    pub fn new(tween: Tw) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                value: Mutex::new(*tween.range().start()),
+                tweener: Mutex::new(Tweener::new(tween)),
+                #[cfg(feature = "async")]
+                signal: Mutex::default(),
+            }),
+        }
+    }
+
+    /// Drives the tween forward, storing its new value for readers, and returning it. Once the
+    /// tween has finished, this returns `None`, and readers keep its end value.
+    pub fn update(&self, delta: Tw::Time) -> Option<Tw::Value> {
+        let value = lock(&self.shared.tweener).update(delta)?;
+        self.store(value);
+
+        Some(value)
+    }
+
+    /// Replaces the tween with a new one, which starts from the beginning.
+    pub fn set(&self, tween: Tw) {
+        let start = *tween.range().start();
+        *lock(&self.shared.tweener) = Tweener::new(tween);
+        self.store(start);
+    }
+
+    /// Stores the latest value for readers, waking any tasks waiting on it.
+    fn store(&self, value: Tw::Value) {
+        #[cfg(feature = "async")]
+        let mut signal = lock(&self.shared.signal);
+        *lock(&self.shared.value) = value;
+
+        // the wakers are woken once the lock is let go, in case they poll right away
+        #[cfg(feature = "async")]
+        {
+            let wakers = signal.notify(lock(&self.shared.tweener).is_finished());
+            drop(signal);
+            wakers.into_iter().for_each(std::task::Waker::wake);
+        }
+    }
+
+    /// The latest value of the tween.
+    pub fn value(&self) -> Tw::Value {
+        *lock(&self.shared.value)
+    }
+
+    /// The latest value of the tween, or `None` if it's being stored right now. This never
+    /// blocks, so a realtime thread can call it, keeping the last value it read otherwise.
+    pub fn try_value(&self) -> Option<Tw::Value> {
+        match self.shared.value.try_lock() {
+            Ok(value) => Some(*value),
+            Err(std::sync::TryLockError::Poisoned(poisoned)) => Some(*poisoned.into_inner()),
+            Err(std::sync::TryLockError::WouldBlock) => None,
+        }
+    }
+
+    /// Returns `true` if the tween has finished.
+    pub fn is_finished(&self) -> bool {
+        lock(&self.shared.tweener).is_finished()
+    }
+}
+
+impl<Tw: Tween> Clone for SharedTweener<Tw> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+/// Locks a mutex, even if another thread panicked while holding it, since tweens and their
+/// values are always left whole.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn send_and_sync() {
+        send_sync::<Linear<f32, f32>>();
+        send_sync::<ElasticInOut<[f64; 3], u64>>();
+        send_sync::<Eased<f32, f32>>();
+        send_sync::<MultiTween<'static, f32, f32>>();
+        send_sync::<Sequence<Eased<f32, f32>>>();
+        send_sync::<Group<Jitter<Linear<f32, f32>>>>();
+        send_sync::<Extrapolate<QuadIn<f32, f32>>>();
+        send_sync::<FuncTween<f32, f32>>();
+        send_sync::<Q32Tween<u32>>();
+        send_sync::<PathTween<BezierPath<'static, [f32; 2]>, Linear<f64, f32>>>();
+
+        send_sync::<Tweener<Linear<f32, f32>>>();
+        send_sync::<Looper<Linear<f32, f32>>>();
+        send_sync::<FixedTweener<Linear<f32, u32>>>();
+        send_sync::<StaticPool<Eased<f32, f32>, 4>>();
+        send_sync::<TweenManager<u32, Eased<f32, f32>>>();
+        send_sync::<TweenBank<f32, f32>>();
+        send_sync::<BakedCurve>();
+        send_sync::<SharedTweener<Linear<f32, f32>>>();
+    }
+
+    #[test]
+    fn reads_across_threads() {
+        let tweener = SharedTweener::new(Linear::new(0..=100, 10u32));
+        let writer = tweener.clone();
+
+        std::thread::scope(|scope| {
+            scope.spawn(move || while writer.update(1).is_some() {});
+        });
+
+        assert_eq!(tweener.try_value(), Some(100));
+        assert!(tweener.is_finished());
+
+        tweener.set(Linear::new(50..=60, 10));
+        assert_eq!(tweener.value(), 50);
+        assert_eq!(tweener.update(5), Some(55));
+    }
+}
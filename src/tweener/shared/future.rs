@@ -0,0 +1,183 @@
+use super::{SharedTweener, lock};
+use crate::Tween;
+use core::{
+    future::poll_fn,
+    mem,
+    task::{Context, Poll, Waker},
+};
+use std::vec::Vec;
+
+/// Counts the values stored in a [SharedTweener], and keeps the tasks waiting on the next one.
+#[derive(Debug, Default)]
+pub(super) struct Signal {
+    generation: u64,
+    finished: bool,
+    wakers: Vec<Waker>,
+}
+
+impl Signal {
+    /// Notes that a new value was stored, returning the tasks which should be woken.
+    pub(super) fn notify(&mut self, finished: bool) -> Vec<Waker> {
+        self.generation = self.generation.wrapping_add(1);
+        self.finished = finished;
+
+        mem::take(&mut self.wakers)
+    }
+
+    fn wait(&mut self, waker: &Waker) {
+        if !self.wakers.iter().any(|waiting| waiting.will_wake(waker)) {
+            self.wakers.push(waker.clone());
+        }
+    }
+}
+
+impl<Tw> SharedTweener<Tw>
+where
+    Tw: Tween,
+{
+    /// Waits for the tween to finish, giving its end value, so an async UI can await a
+    /// transition rather than polling it. This works with any executor.
+    ///
+    /// ```
+    /// # use tween::{Linear, SharedTweener};
+    /// async fn fade_out(opacity: SharedTweener<Linear<f32, f32>>) {
+    ///     assert_eq!(opacity.finished().await, 0.0);
+    ///     // now the view can be removed
+    /// }
+    /// ```
+    pub async fn finished(&self) -> Tw::Value {
+        poll_fn(|cx| self.poll_finished(cx)).await
+    }
+
+    /// Polls for the tween to finish, like [finished](Self::finished), for hand written
+    /// futures.
+    pub fn poll_finished(&self, cx: &mut Context<'_>) -> Poll<Tw::Value> {
+        let mut signal = lock(&self.shared.signal);
+        if signal.finished {
+            Poll::Ready(self.value())
+        } else {
+            signal.wait(cx.waker());
+            Poll::Pending
+        }
+    }
+
+    /// A stream of the values of the tween, starting with its latest one.
+    pub fn values(&self) -> Values<Tw> {
+        Values {
+            tweener: self.clone(),
+            seen: None,
+        }
+    }
+}
+
+/// The values of a [SharedTweener], which are awaited one at a time with [next](Self::next),
+/// and are made by [SharedTweener::values].
+///
+/// This is a stream, without depending on a crate for the `Stream` trait. [poll_next] has the
+/// same signature as `Stream::poll_next`, so it can be wrapped in `futures::stream::poll_fn`.
+///
+/// Readers which are slower than the tweener skip the values they missed, and only see the
+/// latest. Once the tween has finished, and its end value has been read, the stream ends,
+/// unless the tween is [set](SharedTweener::set) again.
+///
+/// ```
+/// # use tween::{Linear, SharedTweener};
+/// async fn follow(slide: SharedTweener<Linear<f32, f32>>, mut draw: impl FnMut(f32)) {
+///     let mut values = slide.values();
+///     while let Some(x) = values.next().await {
+///         draw(x);
+///     }
+/// }
+/// ```
+///
+/// [poll_next]: Self::poll_next
+pub struct Values<Tw: Tween> {
+    tweener: SharedTweener<Tw>,
+    seen: Option<u64>,
+}
+
+impl<Tw> Values<Tw>
+where
+    Tw: Tween,
+{
+    /// Waits for a value which hasn't been read yet, or gives `None` once the tween has
+    /// finished.
+    pub async fn next(&mut self) -> Option<Tw::Value> {
+        poll_fn(|cx| self.poll_next(cx)).await
+    }
+
+    /// Polls for a value which hasn't been read yet, like [next](Self::next).
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Tw::Value>> {
+        let mut signal = lock(&self.tweener.shared.signal);
+        if self.seen != Some(signal.generation) {
+            self.seen = Some(signal.generation);
+            Poll::Ready(Some(self.tweener.value()))
+        } else if signal.finished {
+            Poll::Ready(None)
+        } else {
+            signal.wait(cx.waker());
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+    use core::{future::Future, pin::pin};
+    use std::{
+        sync::Arc,
+        task::Wake,
+        thread::{self, Thread},
+        time::Duration,
+    };
+
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+
+    #[test]
+    fn awaits_across_threads() {
+        let tweener = SharedTweener::new(Linear::new(0..=40, 40u32));
+        let mut values = tweener.values();
+        assert_eq!(block_on(values.next()), Some(0));
+
+        let writer = tweener.clone();
+        let seen = thread::scope(|scope| {
+            scope.spawn(move || {
+                while writer.update(1).is_some() {
+                    thread::sleep(Duration::from_millis(1));
+                }
+            });
+
+            block_on(async {
+                let mut seen = Vec::new();
+                while let Some(value) = values.next().await {
+                    seen.push(value);
+                }
+                seen
+            })
+        });
+
+        assert_eq!(seen.last(), Some(&40));
+        assert!(seen.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(block_on(tweener.finished()), 40);
+    }
+}
@@ -0,0 +1,334 @@
+use super::{CancelMode, Tweener};
+use crate::{Tween, TweenValue};
+use core::ops::RangeInclusive;
+
+/// A [Lens] focuses on a single value within a larger `Target`, such as one field of a
+/// user struct. It is how a [Targeted] tweener knows where to read and write its value.
+///
+/// Most users will want to use [FnLens], which builds a lens out of a getter closure
+/// and a setter closure.
+pub trait Lens<Target> {
+    /// The value which this lens focuses on.
+    type Value;
+
+    /// Reads the focused value out of the target.
+    fn get(&self, target: &Target) -> Self::Value;
+
+    /// Writes the focused value into the target.
+    fn set(&self, target: &mut Target, value: Self::Value);
+}
+
+/// A [Lens] made out of a getter and a setter closure.
+///
+/// ```
+/// # use tween::{FnLens, Lens};
+/// struct Sprite {
+///     x: f32,
+/// }
+///
+/// let lens = FnLens::new(|s: &Sprite| s.x, |s: &mut Sprite, x| s.x = x);
+/// let mut sprite = Sprite { x: 0.0 };
+///
+/// lens.set(&mut sprite, 10.0);
+/// assert_eq!(lens.get(&sprite), 10.0);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct FnLens<G, S> {
+    getter: G,
+    setter: S,
+}
+
+impl<G, S> FnLens<G, S> {
+    /// Creates a new [FnLens] out of a getter and a setter.
+    pub fn new(getter: G, setter: S) -> Self {
+        Self { getter, setter }
+    }
+}
+
+impl<Target, V, G, S> Lens<Target> for FnLens<G, S>
+where
+    G: Fn(&Target) -> V,
+    S: Fn(&mut Target, V),
+{
+    type Value = V;
+
+    fn get(&self, target: &Target) -> V {
+        (self.getter)(target)
+    }
+
+    fn set(&self, target: &mut Target, value: V) {
+        (self.setter)(target, value)
+    }
+}
+
+/// A [Targeted] tweener drives a tween just like a [Tweener], but rather than handing
+/// the value back to you, it writes it directly into a target through a [Lens].
+///
+/// ```
+/// # use tween::{FnLens, Linear, Targeted};
+/// struct Sprite {
+///     x: i32,
+/// }
+///
+/// let mut sprite = Sprite { x: 0 };
+/// let mut targeted = Targeted::new(
+///     Linear::new(0..=10, 10),
+///     FnLens::new(|s: &Sprite| s.x, |s: &mut Sprite, x| s.x = x),
+/// );
+///
+/// targeted.update(&mut sprite, 3);
+/// assert_eq!(sprite.x, 3);
+///
+/// targeted.update(&mut sprite, 100);
+/// assert_eq!(sprite.x, 10);
+///
+/// // the tween is done, so nothing is written anymore
+/// sprite.x = 50;
+/// assert_eq!(targeted.update(&mut sprite, 1), None);
+/// assert_eq!(sprite.x, 50);
+/// ```
+#[derive(Clone)]
+pub struct Targeted<Tw: Tween, L> {
+    tweener: Tweener<Tw>,
+    lens: L,
+}
+
+impl<Tw, L> Targeted<Tw, L>
+where
+    Tw: Tween,
+{
+    /// Creates a new [Targeted] tweener out of a tween and a lens into the target.
+    pub fn new(tween: Tw, lens: L) -> Self {
+        Self {
+            tweener: Tweener::new(tween),
+            lens,
+        }
+    }
+
+    /// Drives the tween forward X steps in time, writing the new value into the
+    /// `target`. The written value is also returned.
+    ///
+    /// Once the tween has completed, this returns `None` and leaves the target alone.
+    pub fn update<Target>(&mut self, target: &mut Target, delta: Tw::Time) -> Option<Tw::Value>
+    where
+        L: Lens<Target, Value = Tw::Value>,
+    {
+        let value = self.tweener.update(delta)?;
+        self.lens.set(target, value);
+
+        Some(value)
+    }
+
+    /// Cancels the tween, finishing it immediately, and writes the value it should be left
+    /// at, according to the given [CancelMode], into the `target`.
+    pub fn cancel<Target>(&mut self, target: &mut Target, mode: CancelMode) -> Tw::Value
+    where
+        L: Lens<Target, Value = Tw::Value>,
+    {
+        let value = self.tweener.cancel(mode);
+        self.lens.set(target, value);
+
+        value
+    }
+
+    /// Allows inspection of the inner [Tweener].
+    pub fn tweener(&self) -> &Tweener<Tw> {
+        &self.tweener
+    }
+
+    /// Allows mutable access to the inner [Tweener], to [restart](Tweener::restart) it, for
+    /// example.
+    pub fn tweener_mut(&mut self) -> &mut Tweener<Tw> {
+        &mut self.tweener
+    }
+
+    /// Allows inspection of the lens.
+    pub fn lens(&self) -> &L {
+        &self.lens
+    }
+
+    /// Returns `true` if the tween has completed.
+    pub fn is_finished(&self) -> bool {
+        self.tweener.is_finished()
+    }
+}
+
+/// A [TweenBy] is a *relative* tween. Rather than tweening to an absolute end value, it tweens
+/// *by* a delta, starting from wherever the target happens to be when the tween starts.
+///
+/// The start value is read through the [Lens] on the first update, and the tween is built then,
+/// using the given constructor (any tween's `new` function, such as `Linear::new`). After that,
+/// it behaves just like a [Targeted] tweener.
+///
+/// ```
+/// # use tween::{FnLens, Linear, TweenBy};
+/// struct Sprite {
+///     x: i32,
+/// }
+///
+/// let mut sprite = Sprite { x: 100 };
+/// // move 50 to the right of wherever we are
+/// let mut move_right = TweenBy::new(
+///     Linear::new,
+///     50,
+///     10,
+///     FnLens::new(|s: &Sprite| s.x, |s: &mut Sprite, x| s.x = x),
+/// );
+///
+/// move_right.update(&mut sprite, 5);
+/// assert_eq!(sprite.x, 125);
+///
+/// move_right.update(&mut sprite, 5);
+/// assert_eq!(sprite.x, 150);
+/// ```
+pub struct TweenBy<Tw: Tween, L> {
+    constructor: fn(RangeInclusive<Tw::Value>, Tw::Time) -> Tw,
+    by: Tw::Value,
+    duration: Tw::Time,
+    lens: L,
+    tweener: Option<Tweener<Tw>>,
+}
+
+impl<Tw, L> TweenBy<Tw, L>
+where
+    Tw: Tween,
+{
+    /// Creates a new [TweenBy], which will tween the target by `by` over `duration`, using the
+    /// tween built by `constructor`.
+    pub fn new(
+        constructor: fn(RangeInclusive<Tw::Value>, Tw::Time) -> Tw,
+        by: Tw::Value,
+        duration: Tw::Time,
+        lens: L,
+    ) -> Self {
+        Self {
+            constructor,
+            by,
+            duration,
+            lens,
+            tweener: None,
+        }
+    }
+
+    /// Drives the tween forward X steps in time, writing the new value into the `target`. The
+    /// first update reads the start value out of the target.
+    ///
+    /// Once the tween has completed, this returns `None` and leaves the target alone.
+    pub fn update<Target>(&mut self, target: &mut Target, delta: Tw::Time) -> Option<Tw::Value>
+    where
+        L: Lens<Target, Value = Tw::Value>,
+    {
+        let value = self.resolve(target).update(delta)?;
+        self.lens.set(target, value);
+
+        Some(value)
+    }
+
+    /// Cancels the tween, finishing it immediately, and writes the value it should be left at,
+    /// according to the given [CancelMode], into the `target`. If the tween hadn't started
+    /// yet, it is resolved against the target first.
+    pub fn cancel<Target>(&mut self, target: &mut Target, mode: CancelMode) -> Tw::Value
+    where
+        L: Lens<Target, Value = Tw::Value>,
+    {
+        let value = self.resolve(target).cancel(mode);
+        self.lens.set(target, value);
+
+        value
+    }
+
+    /// The absolute range this tween resolved to, once it has started.
+    pub fn resolved_range(&self) -> Option<RangeInclusive<Tw::Value>> {
+        self.tweener.as_ref().map(|tweener| tweener.tween().range())
+    }
+
+    /// Returns `true` if the tween has started, and so has read its start value from the
+    /// target.
+    pub fn is_started(&self) -> bool {
+        self.tweener.is_some()
+    }
+
+    /// Returns `true` if the tween has completed.
+    pub fn is_finished(&self) -> bool {
+        self.tweener.as_ref().is_some_and(|tweener| tweener.is_finished())
+    }
+
+    fn resolve<Target>(&mut self, target: &Target) -> &mut Tweener<Tw>
+    where
+        L: Lens<Target, Value = Tw::Value>,
+    {
+        let Self {
+            constructor,
+            by,
+            duration,
+            lens,
+            tweener,
+        } = self;
+
+        tweener.get_or_insert_with(|| {
+            let start = lens.get(target);
+            Tweener::new(constructor(start..=start.add(*by), *duration))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[derive(Debug, Default)]
+    struct Style {
+        opacity: f32,
+        width: i32,
+    }
+
+    #[test]
+    fn writes_into_target() {
+        let mut style = Style::default();
+        let mut opacity = Targeted::new(
+            Linear::new(0.0..=1.0, 4),
+            FnLens::new(|s: &Style| s.opacity, |s: &mut Style, v| s.opacity = v),
+        );
+        let mut width = Targeted::new(
+            Linear::new(0..=100, 4),
+            FnLens::new(|s: &Style| s.width, |s: &mut Style, v| s.width = v),
+        );
+
+        opacity.update(&mut style, 2);
+        width.update(&mut style, 1);
+        assert_eq!(style.opacity, 0.5);
+        assert_eq!(style.width, 25);
+
+        opacity.update(&mut style, 2);
+        assert_eq!(style.opacity, 1.0);
+        assert!(opacity.is_finished());
+        assert!(!width.is_finished());
+
+        width.cancel(&mut style, CancelMode::Revert);
+        assert_eq!(style.width, 0);
+        assert!(width.is_finished());
+    }
+
+    #[test]
+    fn tween_by_resolves_at_start() {
+        let lens = FnLens::new(|s: &Style| s.width, |s: &mut Style, v| s.width = v);
+        let mut style = Style::default();
+        let mut grow = TweenBy::new(Linear::new, 10, 10, lens);
+        assert!(!grow.is_started());
+
+        // the target moves before the tween starts, so we start from there
+        style.width = 30;
+        grow.update(&mut style, 5);
+        assert_eq!(style.width, 35);
+        assert_eq!(grow.resolved_range(), Some(30..=40));
+
+        grow.update(&mut style, 5);
+        assert_eq!(style.width, 40);
+        assert!(grow.is_finished());
+
+        let mut unstarted = TweenBy::new(Linear::new, 10, 10, lens);
+        assert_eq!(unstarted.cancel(&mut style, CancelMode::Complete), 50);
+        assert_eq!(style.width, 50);
+    }
+}
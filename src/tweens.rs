@@ -30,3 +30,165 @@ pub use elastic::{ElasticIn, ElasticInOut, ElasticOut};
 
 mod bounce;
 pub use bounce::{BounceIn, BounceInOut, BounceOut};
+
+mod cubic_bezier;
+pub use cubic_bezier::{CssError, CubicBezier, CubicBezierTween};
+
+mod timing;
+pub use timing::{StepPosition, Steps, TimingFunction, TimingTween};
+
+mod hermite;
+pub use hermite::Hermite;
+
+mod multi;
+pub use multi::{MultiTween, Segment};
+
+mod sequence;
+pub use sequence::{Group, Sequence};
+
+mod progress;
+pub use progress::Progress;
+
+mod ease;
+#[cfg(feature = "std")]
+pub(crate) use ease::EaseVisitor;
+pub use ease::{Ease, Eased};
+
+mod godot;
+pub use godot::{GodotEase, GodotTransition};
+
+mod func;
+pub use func::{FuncTween, FuncTweener};
+
+mod table;
+pub use table::{EaseTable, TableTween};
+
+use crate::{TweenTime, TweenValue};
+use core::ops::RangeInclusive;
+
+/// Makes an easing tween of a range of primitive numbers in a `const` or `static`, finding the
+/// delta of the range at compile time.
+///
+/// The delta of a primitive is `end - start`, as its [TweenValue](crate::TweenValue) finds it,
+/// but generic code can't call that in a `const fn`, so this writes the subtraction out for the
+/// concrete type. Other values need their delta given to `with_delta` instead.
+///
+/// ```
+/// use tween::{const_tween, ElasticOut, Linear, QuadOut};
+///
+/// static FADE: Linear<f32, f32> = const_tween!(Linear, 0.0..=1.0, 2.0);
+/// const SLIDES: [QuadOut<i32, u32>; 2] = [const_tween!(QuadOut, 0..=100, 10), const_tween!(QuadOut, 50..=100, 10)];
+/// const WOBBLE: ElasticOut<f64, f64> = const_tween!(ElasticOut, 0.0..=8.0, 2.0);
+///
+/// assert_eq!(FADE, Linear::new(0.0..=1.0, 2.0));
+/// assert_eq!(SLIDES[1].clone().run(10), 100);
+/// ```
+#[macro_export]
+macro_rules! const_tween {
+    ($($tween:ident)::+, $range:expr, $duration:expr $(,)?) => {{
+        let range = $range;
+        let value_delta = *range.end() - *range.start();
+        $($tween)::+::with_delta(range, value_delta, $duration)
+    }};
+}
+
+/// How the easing tweens are saved, which leaves out their delta so it's found again from the
+/// range on load.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+pub(crate) struct SavedTween<V, T> {
+    pub(crate) start: V,
+    pub(crate) end: V,
+    pub(crate) duration: T,
+}
+
+/// The value a tween holds at `new_time`, if it doesn't need to be eased at all.
+///
+/// A tween with no duration has already finished, so it holds its end value, without
+/// dividing by zero. Before a tween starts, it holds its start value, and
+/// [Extrapolate](crate::Extrapolate) can be used to carry on the curve instead.
+pub(crate) fn held_value<V: TweenValue, T: TweenTime>(range: RangeInclusive<V>, duration: T, new_time: T) -> Option<V> {
+    if duration == T::ZERO {
+        Some(*range.end())
+    } else if !new_time.is_complete(T::ZERO) {
+        Some(*range.start())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Extrapolate, Tween};
+
+    macro_rules! negative_time {
+        ($($tween:ident),+) => {
+            $(
+                let mut tween = $tween::new(10.0..=20.0, 4.0);
+                assert_eq!(tween.run(-1.0), 10.0, stringify!($tween));
+                assert_eq!(tween.run(-1000.0), 10.0, stringify!($tween));
+
+                let mut tween = $tween::new(10..=20, 4i32);
+                assert_eq!(tween.run(-1), 10, stringify!($tween));
+
+                let mut extrapolated = Extrapolate::new($tween::new(10.0f64..=20.0, 4.0));
+                let expected = $tween::new(10.0f64..=20.0, 4.0).run_percent(-0.25);
+                // circular easings have no curve before they start, so these can be `NaN`
+                assert_eq!(extrapolated.run(-1.0).to_bits(), expected.to_bits(), stringify!($tween));
+            )+
+        };
+    }
+
+    macro_rules! zero_duration {
+        ($($tween:ident),+) => {
+            $(
+                let mut tween = $tween::new(10.0..=20.0, 0.0);
+                assert_eq!(tween.run(0.0), 20.0, stringify!($tween));
+                assert_eq!(tween.run(-1.0), 20.0, stringify!($tween));
+
+                let mut tween = $tween::new(10..=20, 0u32);
+                assert_eq!(tween.run(0), 20, stringify!($tween));
+                assert_eq!(Extrapolate::new(tween).run(0), 20, stringify!($tween));
+            )+
+        };
+    }
+
+    #[test]
+    fn zero_duration_ends_immediately() {
+        zero_duration!(Linear, Hermite);
+        zero_duration!(QuadIn, QuadOut, QuadInOut, CubicIn, CubicOut, CubicInOut);
+        zero_duration!(QuartIn, QuartOut, QuartInOut, QuintIn, QuintOut, QuintInOut);
+        zero_duration!(SineIn, SineOut, SineInOut, ExpoIn, ExpoOut, ExpoInOut);
+        zero_duration!(CircIn, CircOut, CircInOut, BackIn, BackOut, BackInOut);
+        zero_duration!(ElasticIn, ElasticOut, ElasticInOut, BounceIn, BounceOut, BounceInOut);
+
+        let segments = [Segment::linear(2.0, 0.0)];
+        let mut tween = MultiTween::new(1.0, &segments);
+        assert_eq!(tween.run(0.0), 2.0);
+    }
+
+    #[test]
+    fn negative_time_holds_the_start() {
+        negative_time!(Linear, Hermite);
+        negative_time!(QuadIn, QuadOut, QuadInOut, CubicIn, CubicOut, CubicInOut);
+        negative_time!(QuartIn, QuartOut, QuartInOut, QuintIn, QuintOut, QuintInOut);
+        negative_time!(SineIn, SineOut, SineInOut, ExpoIn, ExpoOut, ExpoInOut);
+        negative_time!(CircIn, CircOut, CircInOut, BackIn, BackOut, BackInOut);
+        negative_time!(ElasticIn, ElasticOut, ElasticInOut, BounceIn, BounceOut, BounceInOut);
+    }
+
+    #[test]
+    fn bake_into() {
+        let mut strip = [0; 6];
+        QuadIn::new(0..=100, 10u32).bake_into(&mut strip, 5);
+        assert_eq!(strip, [0, 6, 25, 56, 100, 0]);
+
+        BounceOut::new(50..=100, 10u32).bake_into(&mut strip, 0);
+        assert_eq!(strip, [0, 6, 25, 56, 100, 0]);
+
+        let mut single = [0.0f32];
+        CubicOut::new(3.0..=9.0, 1.0f32).bake_into(&mut single, 1);
+        assert_eq!(single, [3.0]);
+    }
+}
@@ -13,13 +13,12 @@ declare_tween!(
     /// An tween that goes out and then back in a bit. Go [here](https://easings.net/#easeInBack) for a visual demonstration.
     pub struct BackIn;
 
-    fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time);
+    fn run_percent(&mut self, t: f64) -> V {
         let scalar = t * t * ((BACK_CONST + 1.0) * t - BACK_CONST);
 
         let new_value = self.value_delta.scale(scalar);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -27,13 +26,13 @@ declare_tween!(
     /// An tween that goes in and then back out a bit. Go [here](https://easings.net/#easeOutBack) for a visual demonstration.
     pub struct BackOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time) - 1.0;
+    fn run_percent(&mut self, t: f64) -> V {
+        let t = t - 1.0;
         let scalar = t * t * ((BACK_CONST + 1.0) * t + BACK_CONST) + 1.0;
 
         let new_value = self.value_delta.scale(scalar);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -41,8 +40,8 @@ declare_in_out_tween!(
     /// An tween that goes out, in, and then back in and out a bit. Go [here](https://easings.net/#easeInOutBack) for a visual demonstration.
     pub struct BackInOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time) * 2.0;
+    fn run_percent(&mut self, t: f64) -> V {
+        let t = t * 2.0;
 
         let scalar = if t < 1.0 {
             t * t * ((BACK_IN_OUT_CONST + 1.0) * t - BACK_IN_OUT_CONST)
@@ -51,9 +50,9 @@ declare_in_out_tween!(
 
             t * t * ((BACK_IN_OUT_CONST + 1.0) * t + BACK_IN_OUT_CONST) + 2.0
         };
-        let new_value = self.half_delta.scale(scalar);
+        let new_value = self.value_delta.scale(scalar * 0.5);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -10,10 +10,29 @@ declare_tween!(
     /// An bouncy tween, similar to gravity. Go [here](https://easings.net/#easeInBounce) for a visual demonstration.
     pub struct BounceIn;
 
+    // the time is reversed before it becomes a percentage, which keeps it exact
     fn run(&mut self, new_time: T) -> V {
-        let v = {
-            let t = T::percent(self.duration, self.duration.sub(new_time));
+        if let Some(held) = crate::tweens::held_value(self.start..=self.end, self.duration, new_time) {
+            return held;
+        }
+
+        self.reversed(T::percent(self.duration, self.duration.sub(new_time)))
+    }
 
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        self.reversed(1.0 - percent_time)
+    }
+);
+
+impl<V, T> BounceIn<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Bounces in from the reversed percentage, `1.0 - percent`.
+    fn reversed(&self, t: f64) -> V {
+        let value_delta = self.value_delta;
+        let v = {
             let multip = if t < STAGE_ZERO {
                 MAGIC * t * t
             } else if t < STAGE_ONE {
@@ -28,20 +47,18 @@ declare_tween!(
                 MAGIC * t * t + 0.984375
             };
 
-            self.value_delta.scale(multip)
+            value_delta.scale(multip)
         };
 
-        TweenValue::calculate_delta(self.value_delta, v).add(*self.range.start())
+        TweenValue::calculate_delta(value_delta, v).add(self.start)
     }
-);
+}
 
 declare_tween!(
     /// An bouncy tween, similar to gravity. Go [here](https://easings.net/#easeOutBounce) for a visual demonstration.
     pub struct BounceOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time);
-
+    fn run_percent(&mut self, t: f64) -> V {
         let multip = if t < STAGE_ZERO {
             MAGIC * t * t
         } else if t < STAGE_ONE {
@@ -56,7 +73,7 @@ declare_tween!(
             MAGIC * t * t + 0.984375
         };
 
-        self.value_delta.scale(multip).add(*self.range.start())
+        self.value_delta.scale(multip).add(self.start)
     }
 );
 
@@ -64,11 +81,10 @@ declare_tween!(
     /// An bouncy tween, similar to gravity. Go [here](https://easings.net/#easeInOutBounce) for a visual demonstration.
     pub struct BounceInOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time);
-
+    fn run_percent(&mut self, t: f64) -> V {
+        let value_delta = self.value_delta;
         if t < 0.5 {
-            let t = T::percent(self.duration, self.duration.sub(new_time.scale(2.0)));
+            let t = 1.0 - t * 2.0;
 
             let v = {
                 let multip = if t < STAGE_ZERO {
@@ -85,14 +101,12 @@ declare_tween!(
                     MAGIC * t * t + 0.984375
                 };
 
-                self.value_delta.scale(multip)
+                value_delta.scale(multip)
             };
 
-            TweenValue::calculate_delta(self.value_delta, v)
-                .scale(0.5)
-                .add(*self.range.start())
+            TweenValue::calculate_delta(value_delta, v).scale(0.5).add(self.start)
         } else {
-            let t = T::percent(self.duration, new_time.scale(2.0).sub(self.duration));
+            let t = t * 2.0 - 1.0;
 
             let multip = if t < STAGE_ZERO {
                 MAGIC * t * t
@@ -108,11 +122,11 @@ declare_tween!(
                 MAGIC * t * t + 0.984375
             };
 
-            self.value_delta
+            value_delta
                 .scale(multip)
                 .scale(0.5)
-                .add(self.value_delta.scale(0.5))
-                .add(*self.range.start())
+                .add(value_delta.scale(0.5))
+                .add(self.start)
         }
     }
 );
@@ -5,16 +5,15 @@ declare_tween!(
     /// A circular tween in. Go [here](https://easings.net/#easeInCirc) for a visual demonstration.
     pub struct CircIn;
 
-    fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time);
-        #[cfg(feature = "libm")]
+    fn run_percent(&mut self, t: f64) -> V {
+        #[cfg(all(feature = "libm", not(feature = "std")))]
         let scalar = 1.0 - libm::sqrt(1.0 - t * t);
 
         #[cfg(feature = "std")]
         let scalar = 1.0 - (1.0 - t * t).sqrt();
         let new_value = self.value_delta.scale(scalar);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -22,10 +21,10 @@ declare_tween!(
     /// A circular tween out. Go [here](https://easings.net/#easeOutCirc) for a visual demonstration.
     pub struct CircOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time) - 1.0;
+    fn run_percent(&mut self, t: f64) -> V {
+        let t = t - 1.0;
 
-        #[cfg(feature = "libm")]
+        #[cfg(all(feature = "libm", not(feature = "std")))]
         let scalar = libm::sqrt(1.0 - t * t);
 
         #[cfg(feature = "std")]
@@ -33,7 +32,7 @@ declare_tween!(
 
         let new_value = self.value_delta.scale(scalar);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -41,11 +40,11 @@ declare_in_out_tween!(
     /// A circular tween in and out. Go [here](https://easings.net/#easeInOutCirc) for a visual demonstration.
     pub struct CircInOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time) * 2.0;
+    fn run_percent(&mut self, t: f64) -> V {
+        let t = t * 2.0;
 
         let scalar = if t < 1.0 {
-            #[cfg(feature = "libm")]
+            #[cfg(all(feature = "libm", not(feature = "std")))]
             let o = 1.0 - libm::sqrt(1.0 - t * t);
 
             #[cfg(feature = "std")]
@@ -55,7 +54,7 @@ declare_in_out_tween!(
         } else {
             let t = t - 2.0;
 
-            #[cfg(feature = "libm")]
+            #[cfg(all(feature = "libm", not(feature = "std")))]
             let o = libm::sqrt(1.0 - t * t) + 1.0;
 
             #[cfg(feature = "std")]
@@ -63,9 +62,9 @@ declare_in_out_tween!(
 
             o
         };
-        let new_value = self.half_delta.scale(scalar);
+        let new_value = self.value_delta.scale(scalar * 0.5);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
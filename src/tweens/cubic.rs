@@ -5,11 +5,10 @@ declare_tween!(
     /// A cubic tween in. Go [here](https://easings.net/#easeInCubic) for a visual demonstration.
     pub struct CubicIn;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time);
+    fn run_percent(&mut self, percent_time: f64) -> V {
         let new_value = self.value_delta.scale(percent_time * percent_time * percent_time);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -17,11 +16,11 @@ declare_tween!(
     /// A cubic tween out. Go [here](https://easings.net/#easeOutCubic) for a visual demonstration.
     pub struct CubicOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time) - 1.0;
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        let percent_time = percent_time - 1.0;
         let new_value = self.value_delta.scale(percent_time * percent_time * percent_time + 1.0);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -29,8 +28,8 @@ declare_in_out_tween!(
     /// A cubic tween in and out. Go [here](https://easings.net/#easeInOutCubic) for a visual demonstration.
     pub struct CubicInOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time) * 2.0;
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        let percent_time = percent_time * 2.0;
 
         let scalar = if percent_time < 1.0 {
             percent_time * percent_time * percent_time
@@ -38,9 +37,9 @@ declare_in_out_tween!(
             let p = percent_time - 2.0;
             p * p * p + 2.0
         };
-        let new_value = self.half_delta.scale(scalar);
+        let new_value = self.value_delta.scale(scalar * 0.5);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
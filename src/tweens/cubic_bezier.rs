@@ -0,0 +1,362 @@
+use crate::{Tween, TweenTime, TweenValue};
+use core::{fmt, ops::RangeInclusive};
+
+/// A [CubicBezier] is a timing function like the `cubic-bezier()` of CSS, which eases along a
+/// curve from `(0, 0)` to `(1, 1)`, bent towards the control points `(x1, y1)` and `(x2, y2)`.
+///
+/// This is how browsers, and design tools like Figma, describe easings, so their timing
+/// functions can be pasted in verbatim with [from_css](Self::from_css).
+///
+/// ```
+/// # use tween::{CubicBezier, Tweener};
+/// let ease = CubicBezier::from_css("cubic-bezier(0.25, 0.1, 0.25, 1.0)").unwrap();
+/// assert_eq!(ease, CubicBezier::new(0.25, 0.1, 0.25, 1.0));
+///
+/// let mut tweener = Tweener::new(ease.tween(0.0f32..=100.0, 2.0f32));
+/// assert!((tweener.update(1.0).unwrap() - 80.24).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "[f64; 4]", into = "[f64; 4]"))]
+pub struct CubicBezier {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+}
+
+impl CubicBezier {
+    /// The CSS `ease` keyword, which is also the default timing function of CSS transitions.
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+    /// The CSS `ease-in` keyword.
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+    /// The CSS `ease-out` keyword.
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+    /// The CSS `ease-in-out` keyword.
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    /// Creates a new timing function out of its two control points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x1` or `x2` is outside of `0.0..=1.0`, since then the curve could go back
+    /// in time.
+    pub const fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        assert!(
+            0.0 <= x1 && x1 <= 1.0 && 0.0 <= x2 && x2 <= 1.0,
+            "the x of each control point must be within `0.0..=1.0`"
+        );
+
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Parses a CSS `cubic-bezier(x1, y1, x2, y2)` timing function.
+    pub fn from_css(css: &str) -> Result<Self, CssError> {
+        let arguments = css_function(css, "cubic-bezier")?;
+
+        let mut numbers = [0.0; 4];
+        let mut count = 0;
+        for argument in arguments.split(',') {
+            if let Some(number) = numbers.get_mut(count) {
+                *number = css_number(argument)?;
+            }
+            count += 1;
+        }
+
+        if count != numbers.len() {
+            return Err(CssError::ArgumentCount {
+                expected: numbers.len(),
+                found: count,
+            });
+        }
+
+        Self::try_from(numbers)
+    }
+
+    /// The control points of the curve, as `[x1, y1, x2, y2]`.
+    pub fn control_points(&self) -> [f64; 4] {
+        [self.x1, self.y1, self.x2, self.y2]
+    }
+
+    /// Eases a percentage, by finding the height of the curve where it's that far across.
+    /// Percentages outside of `0.0..=1.0` hold the nearest end.
+    pub fn ease(&self, percent: f64) -> f64 {
+        if percent <= 0.0 {
+            return 0.0;
+        }
+        if percent >= 1.0 {
+            return 1.0;
+        }
+
+        bezier(self.y1, self.y2, self.solve_x(percent))
+    }
+
+    /// Finds where along the curve it's `x` across, first with Newton's method, which almost
+    /// always converges in a few steps, and then by bisection, for flat spots where it can't.
+    pub(crate) fn solve_x(&self, x: f64) -> f64 {
+        let mut t = x;
+        for _ in 0..8 {
+            let error = bezier(self.x1, self.x2, t) - x;
+            if error.abs() < 1e-7 {
+                return t;
+            }
+
+            let slope = bezier_slope(self.x1, self.x2, t);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            t -= error / slope;
+        }
+
+        let (mut low, mut high) = (0.0, 1.0);
+        t = x;
+        while high - low > 1e-7 {
+            if bezier(self.x1, self.x2, t) < x {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) / 2.0;
+        }
+
+        t
+    }
+
+    /// Creates a new tween out of a range with a duration, which is eased by this curve.
+    pub fn tween<V, T>(self, range: RangeInclusive<V>, duration: T) -> CubicBezierTween<V, T>
+    where
+        V: TweenValue,
+        T: TweenTime,
+    {
+        CubicBezierTween::new(self, range, duration)
+    }
+}
+
+/// One axis of a cubic bezier from `0` to `1`, with the control points `p1` and `p2`.
+fn bezier(p1: f64, p2: f64, t: f64) -> f64 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+
+    ((a * t + b) * t + c) * t
+}
+
+/// The slope of [bezier].
+fn bezier_slope(p1: f64, p2: f64, t: f64) -> f64 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+
+    (3.0 * a * t + 2.0 * b) * t + c
+}
+
+/// The arguments of the CSS function `name`, such as `0.25, 0.1, 0.25, 1.0` out of
+/// `cubic-bezier(0.25, 0.1, 0.25, 1.0)`. Names are matched ignoring case, as CSS does.
+pub(crate) fn css_function<'a>(css: &'a str, name: &str) -> Result<&'a str, CssError> {
+    let css = css.trim();
+    let arguments = css
+        .get(..name.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(name))
+        .map(|_| css[name.len()..].trim_start())
+        .and_then(|rest| rest.strip_prefix('('))
+        .ok_or(CssError::Unrecognized)?;
+
+    arguments.strip_suffix(')').ok_or(CssError::Unclosed)
+}
+
+/// A CSS `<number>`, which must be finite.
+pub(crate) fn css_number(css: &str) -> Result<f64, CssError> {
+    css.trim()
+        .parse()
+        .ok()
+        .filter(|number: &f64| number.is_finite())
+        .ok_or(CssError::InvalidNumber)
+}
+
+/// Makes a timing function out of its control points, as `[x1, y1, x2, y2]`, which is how it's
+/// saved with serde too. Unlike [CubicBezier::new], this gives [CssError::OutOfRange] rather
+/// than panicking if `x1` or `x2` is outside of `0.0..=1.0`.
+impl TryFrom<[f64; 4]> for CubicBezier {
+    type Error = CssError;
+
+    fn try_from([x1, y1, x2, y2]: [f64; 4]) -> Result<Self, CssError> {
+        if !(0.0..=1.0).contains(&x1) || !(0.0..=1.0).contains(&x2) {
+            return Err(CssError::OutOfRange);
+        }
+
+        Ok(Self::new(x1, y1, x2, y2))
+    }
+}
+
+impl From<CubicBezier> for [f64; 4] {
+    fn from(bezier: CubicBezier) -> Self {
+        bezier.control_points()
+    }
+}
+
+/// Why a CSS timing function couldn't be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CssError {
+    /// The string isn't a timing function this library knows.
+    Unrecognized,
+    /// The function's arguments aren't closed with a `)`.
+    Unclosed,
+    /// The function was given the wrong number of arguments.
+    ArgumentCount {
+        /// The number of arguments the function takes.
+        expected: usize,
+        /// The number of arguments it was given.
+        found: usize,
+    },
+    /// An argument isn't a number.
+    InvalidNumber,
+    /// An argument is outside of the values it can take, such as the x of a control point of
+    /// a `cubic-bezier()` outside of `0.0..=1.0`.
+    OutOfRange,
+}
+
+impl fmt::Display for CssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unrecognized => f.write_str("not a css timing function"),
+            Self::Unclosed => f.write_str("the arguments aren't closed with `)`"),
+            Self::ArgumentCount { expected, found } => write!(f, "expected {expected} arguments, found {found}"),
+            Self::InvalidNumber => f.write_str("an argument isn't a number"),
+            Self::OutOfRange => f.write_str("an argument is out of range"),
+        }
+    }
+}
+
+impl core::error::Error for CssError {}
+
+/// A tween which is eased by a [CubicBezier]. It's made with [CubicBezier::tween].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezierTween<V, T> {
+    bezier: CubicBezier,
+    start: V,
+    end: V,
+    duration: T,
+}
+
+impl<V, T> CubicBezierTween<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Creates a new tween out of a range with a duration, which is eased by `bezier`.
+    pub fn new(bezier: CubicBezier, range: RangeInclusive<V>, duration: T) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            bezier,
+            start,
+            end,
+            duration,
+        }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: T) -> V {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// The curve which eases this tween.
+    pub fn bezier(&self) -> CubicBezier {
+        self.bezier
+    }
+}
+
+impl<V, T> Tween for CubicBezierTween<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    type Value = V;
+    type Time = T;
+
+    fn run(&mut self, new_time: T) -> V {
+        if let Some(held) = crate::tweens::held_value(self.range(), self.duration, new_time) {
+            return held;
+        }
+
+        self.run_percent(T::percent(self.duration, new_time))
+    }
+
+    fn run_percent(&mut self, percent: f64) -> V {
+        V::calculate_delta(self.end, self.start)
+            .scale(self.bezier.ease(percent))
+            .add(self.start)
+    }
+
+    fn range(&self) -> RangeInclusive<V> {
+        self.start..=self.end
+    }
+
+    fn duration(&self) -> T {
+        self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ease() {
+        let linear = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+        let ease_in_out = CubicBezier::new(0.42, 0.0, 0.58, 1.0);
+        let back = CubicBezier::new(0.3, -0.5, 0.7, 1.5);
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            assert!((linear.ease(t) - t).abs() < 1e-6);
+            assert!((ease_in_out.ease(t) + ease_in_out.ease(1.0 - t) - 1.0).abs() < 1e-6);
+        }
+
+        assert!((CubicBezier::new(0.25, 0.1, 0.25, 1.0).ease(0.5) - 0.8024033877399112).abs() < 1e-6);
+        assert!(back.ease(0.1) < 0.0);
+        assert_eq!(back.ease(1.5), 1.0);
+    }
+
+    #[test]
+    fn from_css() {
+        assert_eq!(
+            CubicBezier::from_css("  Cubic-Bezier( .4,0, 1 , 1e0 ) "),
+            Ok(CubicBezier::new(0.4, 0.0, 1.0, 1.0))
+        );
+        assert_eq!(CubicBezier::from_css("ease-in"), Err(CssError::Unrecognized));
+        assert_eq!(
+            CubicBezier::from_css("cubic-bezier(0, 0, 1, 1"),
+            Err(CssError::Unclosed)
+        );
+        assert_eq!(
+            CubicBezier::from_css("cubic-bezier(0, 0, 1, nan)"),
+            Err(CssError::InvalidNumber)
+        );
+        assert_eq!(
+            CubicBezier::from_css("cubic-bezier(1.5, 0, 1, 1)"),
+            Err(CssError::OutOfRange)
+        );
+        assert_eq!(
+            CubicBezier::from_css("cubic-bezier(0, 0, 1)"),
+            Err(CssError::ArgumentCount { expected: 4, found: 3 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize() {
+        use serde::{
+            Deserialize,
+            de::value::{Error, SeqDeserializer},
+        };
+        use std::string::ToString;
+
+        let load = |points: [f64; 4]| CubicBezier::deserialize(SeqDeserializer::<_, Error>::new(points.into_iter()));
+
+        assert_eq!(load([0.25, 0.1, 0.25, 1.0]), Ok(CubicBezier::EASE));
+        // this curve would go back in time, which `new` panics on
+        let error = load([0.5, 0.0, 1.5, 1.0]).unwrap_err();
+        assert_eq!(error.to_string(), CssError::OutOfRange.to_string());
+        assert!(load([f64::NAN, 0.0, 1.0, 1.0]).is_err());
+    }
+}
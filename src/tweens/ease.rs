@@ -0,0 +1,243 @@
+use crate::{
+    BackIn, BackInOut, BackOut, BounceIn, BounceInOut, BounceOut, CircIn, CircInOut, CircOut, CubicIn, CubicInOut,
+    CubicOut, ElasticIn, ElasticInOut, ElasticOut, ExpoIn, ExpoInOut, ExpoOut, Linear, Q32, QuadIn, QuadInOut, QuadOut,
+    QuartIn, QuartInOut, QuartOut, QuintIn, QuintInOut, QuintOut, SineIn, SineInOut, SineOut, Tween, TweenTime,
+    TweenValue,
+};
+use core::ops::RangeInclusive;
+
+/// Work which is done with the function of one [Ease], in `f32`, through [Ease::visit].
+#[cfg(feature = "std")]
+pub(crate) trait EaseVisitor {
+    /// What the work gives back.
+    type Output;
+
+    /// Does the work with `function`.
+    fn visit<F: Fn(f32) -> f32>(self, function: F) -> Self::Output;
+}
+
+macro_rules! eases {
+    ($($name:ident => $text:literal, $function:ident),+ $(,)?) => {
+        /// Every easing of this library, which can be picked at runtime, such as from a config
+        /// file, and then made into an [Eased] tween.
+        ///
+        /// ```
+        /// # use tween::{Ease, Tweener};
+        /// let ease = Ease::from_name("quad_in").unwrap();
+        /// let mut tweener = Tweener::eased(0.0..=100.0, 10.0, ease);
+        ///
+        /// assert_eq!(tweener.update(5.0), Some(25.0));
+        /// assert_eq!(ease.name(), "quad_in");
+        /// ```
+        #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum Ease {
+            $(
+                #[doc = concat!("See [", stringify!($name), "].")]
+                $name,
+            )+
+        }
+
+        impl Ease {
+            /// Every easing, in the order they are declared.
+            pub const ALL: &'static [Ease] = &[$(Ease::$name),+];
+
+            /// Creates a new tween of this easing out of a range with a duration.
+            pub fn tween<V, T>(self, range: RangeInclusive<V>, duration: T) -> Eased<V, T>
+            where
+                V: TweenValue,
+                T: TweenTime,
+            {
+                match self {
+                    $(Ease::$name => Eased::$name($name::new(range, duration)),)+
+                }
+            }
+
+            /// The name of this easing in snake case, such as `"bounce_out"`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Ease::$name => $text,)+
+                }
+            }
+
+            /// The stateless [function](crate::functions) of this easing.
+            pub fn function(self) -> fn(f32) -> f32 {
+                match self {
+                    $(Ease::$name => crate::functions::$function,)+
+                }
+            }
+
+            /// The stateless [function](crate::functions) of this easing, in `f64`, which
+            /// can ease a [FuncTween](crate::FuncTween) or a [Segment](crate::Segment).
+            pub fn function_f64(self) -> fn(f64) -> f64 {
+                match self {
+                    $(Ease::$name => crate::functions::$function,)+
+                }
+            }
+
+            /// The stateless [function](crate::functions) of this easing, in [Q32] fixed
+            /// point, which gives the same bits on every platform.
+            pub fn function_q32(self) -> fn(Q32) -> Q32 {
+                match self {
+                    $(Ease::$name => crate::functions::$function,)+
+                }
+            }
+
+            /// Runs `visitor` with the function of this easing as its own type, rather than
+            /// as a pointer, so the easing is inlined into the visitor's loop.
+            #[cfg(feature = "std")]
+            pub(crate) fn visit<Vi: EaseVisitor>(self, visitor: Vi) -> Vi::Output {
+                match self {
+                    $(Ease::$name => visitor.visit(crate::functions::$function::<f32>),)+
+                }
+            }
+
+            /// Finds the easing with the given [name](Self::name).
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($text => Some(Ease::$name),)+
+                    _ => None,
+                }
+            }
+        }
+
+        /// A tween of any [Ease], which dispatches to it with a `match` rather than through a
+        /// pointer, so it needs no allocation.
+        #[derive(Debug, PartialEq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(
+            feature = "serde",
+            serde(bound(deserialize = "V: TweenValue + serde::Deserialize<'de>, T: TweenTime + serde::Deserialize<'de>"))
+        )]
+        pub enum Eased<V, T> {
+            $(
+                #[doc = concat!("A [", stringify!($name), "] tween.")]
+                $name($name<V, T>),
+            )+
+        }
+
+        impl<V, T> Eased<V, T>
+        where
+            V: TweenValue,
+            T: TweenTime,
+        {
+            /// Which easing this tween uses.
+            pub fn ease(&self) -> Ease {
+                match self {
+                    $(Eased::$name(_) => Ease::$name,)+
+                }
+            }
+
+            /// Switches this tween to another easing in place, keeping its range and duration.
+            /// Nothing is allocated, so this is cheap enough to do in the middle of a frame,
+            /// such as when a drag is let go. The value jumps to wherever the new easing is at
+            /// the same time.
+            pub fn set_ease(&mut self, ease: Ease) {
+                if self.ease() != ease {
+                    *self = ease.tween(self.range(), self.duration());
+                }
+            }
+
+            /// Run the given Tween with a new time.
+            pub fn run(&mut self, new_time: T) -> V {
+                <Self as Tween>::run(self, new_time)
+            }
+        }
+
+        impl<V, T> Tween for Eased<V, T>
+        where
+            V: TweenValue,
+            T: TweenTime,
+        {
+            type Value = V;
+            type Time = T;
+
+            fn run(&mut self, new_time: T) -> V {
+                match self {
+                    $(Eased::$name(tween) => tween.run(new_time),)+
+                }
+            }
+
+            fn run_percent(&mut self, percent: f64) -> V {
+                match self {
+                    $(Eased::$name(tween) => tween.run_percent(percent),)+
+                }
+            }
+
+            fn range(&self) -> RangeInclusive<V> {
+                match self {
+                    $(Eased::$name(tween) => tween.range(),)+
+                }
+            }
+
+            fn duration(&self) -> T {
+                match self {
+                    $(Eased::$name(tween) => tween.duration(),)+
+                }
+            }
+        }
+    };
+}
+
+eases! {
+    Linear => "linear", linear,
+    SineIn => "sine_in", sine_in,
+    SineOut => "sine_out", sine_out,
+    SineInOut => "sine_in_out", sine_in_out,
+    QuadIn => "quad_in", quad_in,
+    QuadOut => "quad_out", quad_out,
+    QuadInOut => "quad_in_out", quad_in_out,
+    CubicIn => "cubic_in", cubic_in,
+    CubicOut => "cubic_out", cubic_out,
+    CubicInOut => "cubic_in_out", cubic_in_out,
+    QuartIn => "quart_in", quart_in,
+    QuartOut => "quart_out", quart_out,
+    QuartInOut => "quart_in_out", quart_in_out,
+    QuintIn => "quint_in", quint_in,
+    QuintOut => "quint_out", quint_out,
+    QuintInOut => "quint_in_out", quint_in_out,
+    ExpoIn => "expo_in", expo_in,
+    ExpoOut => "expo_out", expo_out,
+    ExpoInOut => "expo_in_out", expo_in_out,
+    CircIn => "circ_in", circ_in,
+    CircOut => "circ_out", circ_out,
+    CircInOut => "circ_in_out", circ_in_out,
+    BackIn => "back_in", back_in,
+    BackOut => "back_out", back_out,
+    BackInOut => "back_in_out", back_in_out,
+    ElasticIn => "elastic_in", elastic_in,
+    ElasticOut => "elastic_out", elastic_out,
+    ElasticInOut => "elastic_in_out", elastic_in_out,
+    BounceIn => "bounce_in", bounce_in,
+    BounceOut => "bounce_out", bounce_out,
+    BounceInOut => "bounce_in_out", bounce_in_out,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch() {
+        for &ease in Ease::ALL {
+            assert_eq!(Ease::from_name(ease.name()), Some(ease));
+
+            let eased = ease.tween(0.0..=10.0, 4.0);
+            assert_eq!(eased.ease(), ease);
+            assert_eq!(eased.duration(), 4.0);
+        }
+
+        let mut eased = Ease::BounceOut.tween(0.0..=10.0, 4.0);
+        assert_eq!(eased.run(1.0), BounceOut::new(0.0..=10.0, 4.0).run(1.0));
+        assert_eq!(Ease::from_name("wobbly"), None);
+    }
+
+    #[test]
+    fn set_ease() {
+        let mut eased = Ease::ElasticOut.tween(5.0..=-5.0, 8u32);
+        for &ease in Ease::ALL {
+            eased.set_ease(ease);
+            assert_eq!(eased, ease.tween(5.0..=-5.0, 8));
+        }
+    }
+}
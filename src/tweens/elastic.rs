@@ -2,13 +2,23 @@ use crate::{Tween, TweenTime, TweenValue};
 use core::{f64::consts::PI, ops::RangeInclusive};
 
 /// An elastic tween in. Go [here](https://easings.net/#easeInElastic) for a visual demonstration.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "crate::tweens::SavedTween<TValue, TTime>",
+        bound(
+            deserialize = "TValue: TweenValue + serde::Deserialize<'de>, TTime: TweenTime + serde::Deserialize<'de>"
+        )
+    )
+)]
 pub struct ElasticIn<TValue, TTime> {
-    range: RangeInclusive<TValue>,
+    start: TValue,
+    end: TValue,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     value_delta: TValue,
     duration: TTime,
-    three_tenths: f64,
-    s: f64,
 }
 
 impl<TValue, TTime> ElasticIn<TValue, TTime>
@@ -18,18 +28,37 @@ where
 {
     /// Creates a new tween out of a range with a duration.
     pub fn new(range: RangeInclusive<TValue>, duration: TTime) -> Self {
-        let delta = TValue::calculate_delta(*range.end(), *range.start());
-        let three_tenths = duration.as_f64() * 0.3;
+        let value_delta = TValue::calculate_delta(*range.end(), *range.start());
+        Self::with_delta(range, value_delta, duration)
+    }
+
+    /// Creates a new tween out of a range with a duration, and the delta of that range,
+    /// which must be `TweenValue::calculate_delta(end, start)`.
+    ///
+    /// [new](Self::new) finds the delta itself, which a `const fn` can't, so this is how
+    /// tweens are kept in `const`s and `static`s. For ranges of primitive numbers,
+    /// [const_tween](crate::const_tween) finds the delta itself, so it can't be wrong.
+    pub const fn with_delta(range: RangeInclusive<TValue>, value_delta: TValue, duration: TTime) -> Self {
         Self {
-            range,
-            value_delta: delta,
+            start: *range.start(),
+            end: *range.end(),
+            value_delta,
             duration,
-            three_tenths,
-            s: three_tenths * 0.25,
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl<TValue, TTime> From<crate::tweens::SavedTween<TValue, TTime>> for ElasticIn<TValue, TTime>
+where
+    TValue: TweenValue,
+    TTime: TweenTime,
+{
+    fn from(saved: crate::tweens::SavedTween<TValue, TTime>) -> Self {
+        Self::new(saved.start..=saved.end, saved.duration)
+    }
+}
+
 impl<V, T> Tween for ElasticIn<V, T>
 where
     V: TweenValue,
@@ -39,38 +68,46 @@ where
     type Time = T;
 
     fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time);
+        if let Some(held) = crate::tweens::held_value(self.start..=self.end, self.duration, new_time) {
+            return held;
+        }
 
+        self.run_percent(T::percent(self.duration, new_time))
+    }
+
+    fn run_percent(&mut self, t: f64) -> V {
         if t == 0.0 {
-            return *self.range.start();
+            return self.start;
         }
 
         if t == 1.0 {
-            return *self.range.end();
+            return self.end;
         }
 
         let t: f64 = t - 1.0;
 
-        #[cfg(feature = "libm")]
+        #[cfg(all(feature = "libm", not(feature = "std")))]
         let scalar = libm::pow(2.0, t * 10.0);
 
         #[cfg(feature = "std")]
         let scalar = 2f64.powf(t * 10.0);
 
         let post_fix = self.value_delta.scale(scalar);
-        let temp = (self.duration.as_f64() * t - self.s) * (2.0 * PI) / self.three_tenths;
+        let three_tenths = self.duration.as_f64() * 0.3;
+        let s = three_tenths * 0.25;
+        let temp = (self.duration.as_f64() * t - s) * (2.0 * PI) / three_tenths;
 
-        #[cfg(feature = "libm")]
+        #[cfg(all(feature = "libm", not(feature = "std")))]
         let scalar = -libm::sin(temp);
 
         #[cfg(feature = "std")]
         let scalar = -temp.sin();
 
-        post_fix.scale(scalar).add(*self.range.start())
+        post_fix.scale(scalar).add(self.start)
     }
 
-    fn range(&self) -> &RangeInclusive<V> {
-        &self.range
+    fn range(&self) -> RangeInclusive<V> {
+        self.start..=self.end
     }
 
     fn duration(&self) -> T {
@@ -79,13 +116,23 @@ where
 }
 
 /// An elastic tween out. Go [here](https://easings.net/#easeOutElastic) for a visual demonstration.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "crate::tweens::SavedTween<TValue, TTime>",
+        bound(
+            deserialize = "TValue: TweenValue + serde::Deserialize<'de>, TTime: TweenTime + serde::Deserialize<'de>"
+        )
+    )
+)]
 pub struct ElasticOut<TValue, TTime> {
-    range: RangeInclusive<TValue>,
+    start: TValue,
+    end: TValue,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     value_delta: TValue,
     duration: TTime,
-    three_tenths: f64,
-    s: f64,
 }
 
 impl<TValue, TTime> ElasticOut<TValue, TTime>
@@ -95,18 +142,37 @@ where
 {
     /// Creates a new tween out of a range with a duration.
     pub fn new(range: RangeInclusive<TValue>, duration: TTime) -> Self {
-        let delta = TValue::calculate_delta(*range.end(), *range.start());
-        let three_tenths = duration.as_f64() * 0.3;
+        let value_delta = TValue::calculate_delta(*range.end(), *range.start());
+        Self::with_delta(range, value_delta, duration)
+    }
+
+    /// Creates a new tween out of a range with a duration, and the delta of that range,
+    /// which must be `TweenValue::calculate_delta(end, start)`.
+    ///
+    /// [new](Self::new) finds the delta itself, which a `const fn` can't, so this is how
+    /// tweens are kept in `const`s and `static`s. For ranges of primitive numbers,
+    /// [const_tween](crate::const_tween) finds the delta itself, so it can't be wrong.
+    pub const fn with_delta(range: RangeInclusive<TValue>, value_delta: TValue, duration: TTime) -> Self {
         Self {
-            range,
-            value_delta: delta,
+            start: *range.start(),
+            end: *range.end(),
+            value_delta,
             duration,
-            three_tenths,
-            s: three_tenths * 0.25,
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl<TValue, TTime> From<crate::tweens::SavedTween<TValue, TTime>> for ElasticOut<TValue, TTime>
+where
+    TValue: TweenValue,
+    TTime: TweenTime,
+{
+    fn from(saved: crate::tweens::SavedTween<TValue, TTime>) -> Self {
+        Self::new(saved.start..=saved.end, saved.duration)
+    }
+}
+
 impl<V, T> Tween for ElasticOut<V, T>
 where
     V: TweenValue,
@@ -116,32 +182,37 @@ where
     type Time = T;
 
     fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time);
+        if let Some(held) = crate::tweens::held_value(self.start..=self.end, self.duration, new_time) {
+            return held;
+        }
+
+        self.run_percent(T::percent(self.duration, new_time))
+    }
 
+    fn run_percent(&mut self, t: f64) -> V {
         if t == 0.0 {
-            return *self.range.start();
+            return self.start;
         }
 
         if t == 1.0 {
-            return *self.range.end();
+            return self.end;
         }
 
-        let temp = (t * self.duration.as_f64() - self.s) * (2.0 * PI) / self.three_tenths;
+        let three_tenths = self.duration.as_f64() * 0.3;
+        let s = three_tenths * 0.25;
+        let temp = (t * self.duration.as_f64() - s) * (2.0 * PI) / three_tenths;
 
-        #[cfg(feature = "libm")]
+        #[cfg(all(feature = "libm", not(feature = "std")))]
         let scalar = libm::pow(2.0, -10.0 * t) * libm::sin(temp);
 
         #[cfg(feature = "std")]
         let scalar = 2f64.powf(-10.0 * t) * temp.sin();
 
-        self.value_delta
-            .scale(scalar)
-            .add(self.value_delta)
-            .add(*self.range.start())
+        self.value_delta.scale(scalar).add(self.value_delta).add(self.start)
     }
 
-    fn range(&self) -> &RangeInclusive<V> {
-        &self.range
+    fn range(&self) -> RangeInclusive<V> {
+        self.start..=self.end
     }
 
     fn duration(&self) -> T {
@@ -150,13 +221,23 @@ where
 }
 
 /// An elastic tween in and out. Go [here](https://easings.net/#easeInOutElastic) for a visual demonstration.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "crate::tweens::SavedTween<TValue, TTime>",
+        bound(
+            deserialize = "TValue: TweenValue + serde::Deserialize<'de>, TTime: TweenTime + serde::Deserialize<'de>"
+        )
+    )
+)]
 pub struct ElasticInOut<TValue, TTime> {
-    range: RangeInclusive<TValue>,
+    start: TValue,
+    end: TValue,
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     value_delta: TValue,
     duration: TTime,
-    p: f64,
-    s: f64,
 }
 
 impl<TValue, TTime> ElasticInOut<TValue, TTime>
@@ -166,18 +247,37 @@ where
 {
     /// Creates a new tween out of a range with a duration.
     pub fn new(range: RangeInclusive<TValue>, duration: TTime) -> Self {
-        let delta = TValue::calculate_delta(*range.end(), *range.start());
-        let p = duration.as_f64() * 0.45;
+        let value_delta = TValue::calculate_delta(*range.end(), *range.start());
+        Self::with_delta(range, value_delta, duration)
+    }
+
+    /// Creates a new tween out of a range with a duration, and the delta of that range,
+    /// which must be `TweenValue::calculate_delta(end, start)`.
+    ///
+    /// [new](Self::new) finds the delta itself, which a `const fn` can't, so this is how
+    /// tweens are kept in `const`s and `static`s. For ranges of primitive numbers,
+    /// [const_tween](crate::const_tween) finds the delta itself, so it can't be wrong.
+    pub const fn with_delta(range: RangeInclusive<TValue>, value_delta: TValue, duration: TTime) -> Self {
         Self {
-            range,
-            value_delta: delta,
+            start: *range.start(),
+            end: *range.end(),
+            value_delta,
             duration,
-            p,
-            s: p * 0.25,
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl<TValue, TTime> From<crate::tweens::SavedTween<TValue, TTime>> for ElasticInOut<TValue, TTime>
+where
+    TValue: TweenValue,
+    TTime: TweenTime,
+{
+    fn from(saved: crate::tweens::SavedTween<TValue, TTime>) -> Self {
+        Self::new(saved.start..=saved.end, saved.duration)
+    }
+}
+
 impl<V, T> Tween for ElasticInOut<V, T>
 where
     V: TweenValue,
@@ -187,56 +287,67 @@ where
     type Time = T;
 
     fn run(&mut self, new_time: T) -> V {
-        let t = T::percent(self.duration, new_time) * 2.0;
+        if let Some(held) = crate::tweens::held_value(self.start..=self.end, self.duration, new_time) {
+            return held;
+        }
+
+        self.run_percent(T::percent(self.duration, new_time))
+    }
+
+    fn run_percent(&mut self, t: f64) -> V {
+        let t = t * 2.0;
 
         if t == 0.0 {
-            return *self.range.start();
+            return self.start;
         }
 
         if t == 2.0 {
-            return *self.range.end();
+            return self.end;
         }
 
+        let p = self.duration.as_f64() * 0.45;
+        let s = p * 0.25;
+
         let t = t - 1.0;
         if t < 0.0 {
-            #[cfg(feature = "libm")]
+            #[cfg(all(feature = "libm", not(feature = "std")))]
             let scalar = libm::pow(2.0, t * 10.0);
 
             #[cfg(feature = "std")]
             let scalar = 2f64.powf(t * 10.0);
 
             let post_fix = self.value_delta.scale(scalar);
-            let temp = (self.duration.as_f64() * t - self.s) * (2.0 * PI) / self.p;
+            let temp = (self.duration.as_f64() * t - s) * (2.0 * PI) / p;
 
-            #[cfg(feature = "libm")]
+            #[cfg(all(feature = "libm", not(feature = "std")))]
             let temp_sin = libm::sin(temp);
 
             #[cfg(feature = "std")]
             let temp_sin = temp.sin();
 
-            post_fix.scale(-0.5 * temp_sin).add(*self.range.start())
+            post_fix.scale(-0.5 * temp_sin).add(self.start)
         } else {
-            #[cfg(feature = "libm")]
+            #[cfg(all(feature = "libm", not(feature = "std")))]
             let scalar = libm::pow(2.0, t * -10.0);
 
             #[cfg(feature = "std")]
             let scalar = 2f64.powf(-10.0 * t);
 
             let post_fix = self.value_delta.scale(scalar);
-            let temp = (self.duration.as_f64() * t - self.s) * (2.0 * PI) / self.p;
+            let temp = (self.duration.as_f64() * t - s) * (2.0 * PI) / p;
 
-            #[cfg(feature = "libm")]
+            #[cfg(all(feature = "libm", not(feature = "std")))]
             let temp_sin = libm::sin(temp);
 
             #[cfg(feature = "std")]
             let temp_sin = temp.sin();
 
-            post_fix.scale(temp_sin * 0.5).add(*self.range.end())
+            post_fix.scale(temp_sin * 0.5).add(self.end)
         }
     }
 
-    fn range(&self) -> &RangeInclusive<V> {
-        &self.range
+    fn range(&self) -> RangeInclusive<V> {
+        self.start..=self.end
     }
 
     fn duration(&self) -> T {
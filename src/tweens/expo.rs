@@ -5,12 +5,12 @@ declare_tween!(
     /// An exponenential tween in. See [here](https://easings.net/#easeInExpo)
     pub struct ExpoIn;
 
-    fn run(&mut self, new_time: T) -> V {
-        if new_time == T::ZERO {
-            *self.range.start()
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        if percent_time == 0.0 {
+            self.start
         } else {
-            let percent_time = 10.0 * (T::percent(self.duration, new_time) - 1.0);
-            #[cfg(feature = "libm")]
+            let percent_time = 10.0 * (percent_time - 1.0);
+            #[cfg(all(feature = "libm", not(feature = "std")))]
             let scalar = libm::pow(2.0, percent_time);
 
             #[cfg(feature = "std")]
@@ -18,7 +18,7 @@ declare_tween!(
 
             let new_value = self.value_delta.scale(scalar);
 
-            new_value.add(*self.range.start())
+            new_value.add(self.start)
         }
     }
 );
@@ -27,19 +27,19 @@ declare_tween!(
     /// An exponenential tween out. See [here](https://easings.net/#easeOutExpo)
     pub struct ExpoOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        if new_time == self.duration {
-            *self.range.end()
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        if percent_time == 1.0 {
+            self.end
         } else {
-            #[cfg(feature = "libm")]
-            let powf = libm::pow(2.0, -10.0 * T::percent(self.duration, new_time));
+            #[cfg(all(feature = "libm", not(feature = "std")))]
+            let powf = libm::pow(2.0, -10.0 * percent_time);
 
             #[cfg(feature = "std")]
-            let powf = 2.0f64.powf(-10.0 * T::percent(self.duration, new_time));
+            let powf = 2.0f64.powf(-10.0 * percent_time);
 
             let new_value = self.value_delta.scale(1.0 - powf);
 
-            new_value.add(*self.range.start())
+            new_value.add(self.start)
         }
     }
 );
@@ -48,19 +48,19 @@ declare_tween!(
     /// An exponenential tween in and out. See [here](https://easings.net/#easeInOutExpo)
     pub struct ExpoInOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        if new_time == T::ZERO {
-            return *self.range.start();
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        if percent_time == 0.0 {
+            return self.start;
         }
 
-        if new_time == self.duration {
-            return *self.range.end();
+        if percent_time == 1.0 {
+            return self.end;
         }
 
-        let t = T::percent(self.duration, new_time) * 2.0;
+        let t = percent_time * 2.0;
 
         let powf = if t < 1.0 {
-            #[cfg(feature = "libm")]
+            #[cfg(all(feature = "libm", not(feature = "std")))]
             let scalar = libm::pow(2.0, 10.0 * (t - 1.0));
 
             #[cfg(feature = "std")]
@@ -70,7 +70,7 @@ declare_tween!(
         } else {
             let t = t - 1.0;
 
-            #[cfg(feature = "libm")]
+            #[cfg(all(feature = "libm", not(feature = "std")))]
             let scalar = libm::pow(2.0, -10.0 * t);
 
             #[cfg(feature = "std")]
@@ -81,7 +81,7 @@ declare_tween!(
 
         let new_value = self.value_delta.scale(powf);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -0,0 +1,116 @@
+use crate::{Tween, TweenTime, TweenValue, Tweener};
+use core::ops::RangeInclusive;
+
+/// A [FuncTween] eases its range with a plain function, which takes the percentage of the way
+/// through the tween and gives back how far along the range it should be.
+///
+/// Every easing becomes this same type, so a program which uses many kinds of easings only
+/// compiles one tween, and one [Tweener] over it, where each easing struct would be compiled
+/// for itself. The cost is a call through a pointer, rather than an inlined easing.
+///
+/// ```
+/// # use tween::{functions, FuncTweener};
+/// let mut tweener = FuncTweener::from_fn(0.0..=100.0, 10.0, |t| t * t);
+/// assert_eq!(tweener.update(5.0), Some(25.0));
+///
+/// // any of the easing functions can be used too
+/// let mut tweener = FuncTweener::from_fn(0.0..=1.0, 4, functions::bounce_out);
+/// assert_eq!(tweener.update(4), Some(1.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FuncTween<V, T> {
+    range: RangeInclusive<V>,
+    value_delta: V,
+    duration: T,
+    ease: fn(f64) -> f64,
+}
+
+impl<V, T> FuncTween<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Creates a new tween out of a range with a duration, which is eased by `ease`.
+    pub fn new(range: RangeInclusive<V>, duration: T, ease: fn(f64) -> f64) -> Self {
+        let value_delta = V::calculate_delta(*range.end(), *range.start());
+        Self {
+            range,
+            value_delta,
+            duration,
+            ease,
+        }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: T) -> V {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// The function which eases this tween.
+    pub fn ease(&self) -> fn(f64) -> f64 {
+        self.ease
+    }
+}
+
+impl<V, T> Tween for FuncTween<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    type Value = V;
+    type Time = T;
+
+    fn run(&mut self, new_time: T) -> V {
+        if let Some(held) = crate::tweens::held_value(self.range.clone(), self.duration, new_time) {
+            return held;
+        }
+
+        self.run_percent(T::percent(self.duration, new_time))
+    }
+
+    fn run_percent(&mut self, percent: f64) -> V {
+        self.value_delta.scale((self.ease)(percent)).add(*self.range.start())
+    }
+
+    fn range(&self) -> RangeInclusive<V> {
+        self.range.clone()
+    }
+
+    fn duration(&self) -> T {
+        self.duration
+    }
+}
+
+/// A [Tweener] over a [FuncTween], which is one type for every easing.
+pub type FuncTweener<V, T> = Tweener<FuncTween<V, T>>;
+
+impl<V, T> Tweener<FuncTween<V, T>>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Creates a new [Tweener] out of a range with a duration, which is eased by `ease`. See
+    /// [FuncTween].
+    pub fn from_fn(range: RangeInclusive<V>, duration: T, ease: fn(f64) -> f64) -> Self {
+        Self::new(FuncTween::new(range, duration, ease))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QuadInOut, functions};
+
+    #[test]
+    fn func_tween() {
+        let mut tween = FuncTween::new(0.0f64..=10.0, 8.0, functions::quad_in_out);
+        let mut expected = QuadInOut::new(0.0..=10.0, 8.0);
+        for time in 0..=8 {
+            let time = time as f64;
+            assert!((tween.run(time) - expected.run(time)).abs() < 1e-12);
+        }
+
+        assert_eq!(tween.run(-1.0), 0.0);
+        assert_eq!(FuncTween::new(0..=10, 0, |t| t).run(0), 10);
+    }
+}
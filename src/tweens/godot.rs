@@ -0,0 +1,110 @@
+use crate::Ease;
+
+/// Godot's `Tween.TransitionType`, which is the shape of an easing, with the same numbers
+/// as in Godot.
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum GodotTransition {
+    /// `TRANS_LINEAR`.
+    Linear = 0,
+    /// `TRANS_SINE`.
+    Sine = 1,
+    /// `TRANS_QUINT`.
+    Quint = 2,
+    /// `TRANS_QUART`.
+    Quart = 3,
+    /// `TRANS_QUAD`.
+    Quad = 4,
+    /// `TRANS_EXPO`.
+    Expo = 5,
+    /// `TRANS_ELASTIC`.
+    Elastic = 6,
+    /// `TRANS_CUBIC`.
+    Cubic = 7,
+    /// `TRANS_CIRC`.
+    Circ = 8,
+    /// `TRANS_BOUNCE`.
+    Bounce = 9,
+    /// `TRANS_BACK`.
+    Back = 10,
+    /// `TRANS_SPRING`.
+    Spring = 11,
+}
+
+/// Godot's `Tween.EaseType`, which is which end of a [GodotTransition] is eased, with the same
+/// numbers as in Godot.
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum GodotEase {
+    /// `EASE_IN`.
+    In = 0,
+    /// `EASE_OUT`.
+    Out = 1,
+    /// `EASE_IN_OUT`.
+    InOut = 2,
+    /// `EASE_OUT_IN`.
+    OutIn = 3,
+}
+
+impl Ease {
+    /// Finds the easing of a Godot transition and ease, so the animations of a Godot project
+    /// keep their tuning once ported. `TRANS_LINEAR` is [Ease::Linear] with every ease.
+    ///
+    /// Godot's `TRANS_SPRING` and `EASE_OUT_IN` have no easing in this library, so they give
+    /// `None`.
+    ///
+    /// ```
+    /// # use tween::{Ease, GodotEase, GodotTransition};
+    /// let ease = Ease::from_godot(GodotTransition::Back, GodotEase::Out);
+    /// assert_eq!(ease, Some(Ease::BackOut));
+    /// ```
+    pub fn from_godot(transition: GodotTransition, ease: GodotEase) -> Option<Self> {
+        let [ease_in, ease_out, ease_in_out] = match transition {
+            GodotTransition::Linear => return Some(Ease::Linear),
+            GodotTransition::Sine => [Ease::SineIn, Ease::SineOut, Ease::SineInOut],
+            GodotTransition::Quint => [Ease::QuintIn, Ease::QuintOut, Ease::QuintInOut],
+            GodotTransition::Quart => [Ease::QuartIn, Ease::QuartOut, Ease::QuartInOut],
+            GodotTransition::Quad => [Ease::QuadIn, Ease::QuadOut, Ease::QuadInOut],
+            GodotTransition::Expo => [Ease::ExpoIn, Ease::ExpoOut, Ease::ExpoInOut],
+            GodotTransition::Elastic => [Ease::ElasticIn, Ease::ElasticOut, Ease::ElasticInOut],
+            GodotTransition::Cubic => [Ease::CubicIn, Ease::CubicOut, Ease::CubicInOut],
+            GodotTransition::Circ => [Ease::CircIn, Ease::CircOut, Ease::CircInOut],
+            GodotTransition::Bounce => [Ease::BounceIn, Ease::BounceOut, Ease::BounceInOut],
+            GodotTransition::Back => [Ease::BackIn, Ease::BackOut, Ease::BackInOut],
+            GodotTransition::Spring => return None,
+        };
+
+        match ease {
+            GodotEase::In => Some(ease_in),
+            GodotEase::Out => Some(ease_out),
+            GodotEase::InOut => Some(ease_in_out),
+            GodotEase::OutIn => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_godot() {
+        assert_eq!(
+            Ease::from_godot(GodotTransition::Quad, GodotEase::In),
+            Some(Ease::QuadIn)
+        );
+        assert_eq!(
+            Ease::from_godot(GodotTransition::Elastic, GodotEase::InOut),
+            Some(Ease::ElasticInOut)
+        );
+        assert_eq!(
+            Ease::from_godot(GodotTransition::Linear, GodotEase::OutIn),
+            Some(Ease::Linear)
+        );
+        assert_eq!(Ease::from_godot(GodotTransition::Spring, GodotEase::Out), None);
+        assert_eq!(Ease::from_godot(GodotTransition::Sine, GodotEase::OutIn), None);
+        assert_eq!(GodotTransition::Back as u8, 10);
+    }
+}
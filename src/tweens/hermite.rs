@@ -0,0 +1,149 @@
+use crate::{Tween, TweenTime, TweenValue};
+use core::ops::RangeInclusive;
+
+/// A cubic Hermite curve, which moves from a start value to an end value, leaving the start
+/// and arriving at the end with the given *velocities* (in value per unit of time).
+///
+/// With both velocities at zero, this is the classic "smoothstep" curve. With non-zero
+/// velocities, it can pick up exactly where another motion left off, which is what
+/// [Retargetable](crate::Retargetable) uses it for.
+///
+/// ```
+/// # use tween::Hermite;
+/// let mut smoothstep = Hermite::new(0.0..=100.0, 10.0);
+/// assert_eq!(smoothstep.run(5.0), 50.0);
+///
+/// // leaves 0.0 moving at 20.0 per second, arriving at rest
+/// let mut hermite = Hermite::with_velocities(0.0..=100.0, 20.0, 0.0, 10.0);
+/// assert!(hermite.run(1.0) > 15.0);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hermite<TValue, TTime> {
+    start: TValue,
+    end: TValue,
+    value_delta: TValue,
+    start_velocity: TValue,
+    end_velocity: TValue,
+    duration: TTime,
+}
+
+impl<TValue, TTime> Hermite<TValue, TTime>
+where
+    TValue: TweenValue,
+    TTime: TweenTime,
+{
+    /// Creates a new tween out of a range with a duration, which starts and ends at rest.
+    pub fn new(range: RangeInclusive<TValue>, duration: TTime) -> Self {
+        Self::with_velocities(range, TValue::ZERO, TValue::ZERO, duration)
+    }
+
+    /// Creates a new tween out of a range with a duration, which leaves the start of the range
+    /// at `start_velocity` and arrives at the end at `end_velocity`. Velocities are in value
+    /// per unit of time.
+    pub fn with_velocities(
+        range: RangeInclusive<TValue>,
+        start_velocity: TValue,
+        end_velocity: TValue,
+        duration: TTime,
+    ) -> Self {
+        let value_delta = TValue::calculate_delta(*range.end(), *range.start());
+        let (start, end) = range.into_inner();
+        Self {
+            start,
+            end,
+            value_delta,
+            start_velocity,
+            end_velocity,
+            duration,
+        }
+    }
+
+    /// The velocity this curve leaves its start value at.
+    pub fn start_velocity(&self) -> TValue {
+        self.start_velocity
+    }
+
+    /// The velocity this curve arrives at its end value with.
+    pub fn end_velocity(&self) -> TValue {
+        self.end_velocity
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: TTime) -> TValue {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// Run the given Tween at a percentage of the way through its duration.
+    pub fn run_percent(&mut self, percent: f64) -> TValue {
+        <Self as Tween>::run_percent(self, percent)
+    }
+}
+
+impl<V, T> Tween for Hermite<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    type Value = V;
+    type Time = T;
+
+    fn run(&mut self, new_time: T) -> V {
+        if let Some(held) = crate::tweens::held_value(self.range(), self.duration, new_time) {
+            return held;
+        }
+
+        self.run_percent(T::percent(self.duration, new_time))
+    }
+
+    fn run_percent(&mut self, s: f64) -> V {
+        let s2 = s * s;
+        let s3 = s2 * s;
+
+        // the velocities are per unit of time, so we bring them into the curve's `0..=1`
+        // parameter space by multiplying by the duration.
+        let duration = self.duration.as_f64();
+        let start_tangent = (s3 - 2.0 * s2 + s) * duration;
+        let end_tangent = (s3 - s2) * duration;
+
+        self.value_delta
+            .scale(3.0 * s2 - 2.0 * s3)
+            .add(self.start_velocity.scale(start_tangent))
+            .add(self.end_velocity.scale(end_tangent))
+            .add(self.start)
+    }
+
+    fn range(&self) -> RangeInclusive<V> {
+        self.start..=self.end
+    }
+
+    fn duration(&self) -> T {
+        self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_ulps_eq};
+
+    #[test]
+    fn hits_endpoints() {
+        let mut hermite = Hermite::with_velocities(10.0..=30.0, 5.0, -3.0, 4.0);
+
+        assert_ulps_eq!(hermite.run(0.0), 10.0);
+        assert_ulps_eq!(hermite.run(4.0), 30.0);
+    }
+
+    #[test]
+    fn matches_velocities() {
+        let mut hermite = Hermite::with_velocities(0.0f64..=10.0, 3.0, -2.0, 4.0);
+        let h = 1e-6;
+
+        let start = (hermite.run(h) - hermite.run(0.0)) / h;
+        let end = (hermite.run(4.0) - hermite.run(4.0 - h)) / h;
+
+        assert_abs_diff_eq!(start, 3.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(end, -2.0, epsilon = 1e-4);
+    }
+}
@@ -5,17 +5,17 @@ declare_tween!(
     /// A Linear tween is a simple lerp from one value to another.
     pub struct Linear;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time);
+    fn run_percent(&mut self, percent_time: f64) -> V {
         let new_value = self.value_delta.scale(percent_time);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{BounceIn, ElasticOut, Jitter};
     use approx::assert_ulps_eq;
 
     #[test]
@@ -28,4 +28,58 @@ mod tests {
             assert_ulps_eq!(value, val as f32 * 10.0);
         }
     }
+
+    #[test]
+    fn const_tween() {
+        use crate::{CubicInOut, ElasticInOut, QuadOut};
+
+        static FADE: Linear<f32, f32> = crate::const_tween!(Linear, 0.0..=1.0, 2.0);
+        const SLIDES: [QuadOut<i32, u32>; 2] = [
+            crate::const_tween!(QuadOut, 0..=100, 10),
+            crate::const_tween!(crate::QuadOut, 50..=100, 10),
+        ];
+        const EASE: CubicInOut<f64, f64> = crate::const_tween!(CubicInOut, 0.0..=8.0, 2.0);
+        const WOBBLE: ElasticInOut<u64, u64> = crate::const_tween!(ElasticInOut, 10..=1000, 8);
+        const SIGNED: Linear<i8, u8> = crate::const_tween!(Linear, -10..=10, 4);
+
+        assert_eq!(FADE.clone().run(1.0), 0.5);
+        assert_eq!(SLIDES[1].clone().run(10), 100);
+        assert_eq!(SLIDES[0], QuadOut::new(0..=100, 10));
+        assert_eq!(EASE, CubicInOut::new(0.0..=8.0, 2.0));
+        assert_eq!(WOBBLE, ElasticInOut::new(10..=1000, 8));
+        assert_eq!(SIGNED, Linear::new(-10..=10, 4));
+
+        // a delta given by hand is taken as it is
+        const MANUAL: Linear<f32, f32> = Linear::with_delta(0.0..=1.0, 1.0, 2.0);
+        assert_eq!(MANUAL, FADE);
+    }
+
+    #[test]
+    fn copy() {
+        let tween = Linear::new(0.0f32..=10.0, 4.0f32);
+        let mut copied = tween;
+
+        assert_eq!(copied.run(2.0), 5.0);
+        assert_eq!(tween.range(), 0.0..=10.0);
+        // the start, end, delta, and duration, with no `RangeInclusive` flag to pad out
+        assert_eq!(core::mem::size_of::<Linear<f32, f32>>(), 16);
+        assert_eq!(core::mem::size_of::<crate::Hermite<f32, f32>>(), 24);
+    }
+
+    #[test]
+    fn run_percent() {
+        let mut linear = Linear::new(0.0..=100.0, 10.0);
+        assert_eq!(linear.run_percent(0.25), 25.0);
+
+        // the easings, and the default which goes through the duration, agree with `run`
+        let mut bounce = BounceIn::new(0.0..=1.0, 8);
+        let mut elastic = ElasticOut::new(0.0..=1.0, 8);
+        let mut jitter = Jitter::new(Linear::new(0.0..=1.0, 8), 0);
+        for time in 0..=8 {
+            let percent = time as f64 / 8.0;
+            assert_ulps_eq!(bounce.run_percent(percent), bounce.run(time));
+            assert_ulps_eq!(elastic.run_percent(percent), elastic.run(time));
+            assert_ulps_eq!(jitter.run_percent(percent), jitter.run(time));
+        }
+    }
 }
@@ -0,0 +1,164 @@
+use crate::{Tween, TweenTime, TweenValue, path::lerp};
+use core::ops::RangeInclusive;
+
+/// One segment of a [MultiTween], which moves to a value over a duration.
+#[derive(Debug, Clone, Copy)]
+pub struct Segment<V, T> {
+    /// The value this segment ends at.
+    pub to: V,
+    /// How long this segment takes.
+    pub duration: T,
+    /// Eases the percentage of the way through this segment, from `0.0` to `1.0`.
+    pub ease: fn(f64) -> f64,
+}
+
+impl<V, T> Segment<V, T> {
+    /// Creates a new segment, which moves linearly.
+    pub const fn linear(to: V, duration: T) -> Self {
+        Self::eased(to, duration, |percent| percent)
+    }
+
+    /// Creates a new segment, which eases its percentage with the given function.
+    pub const fn eased(to: V, duration: T, ease: fn(f64) -> f64) -> Self {
+        Self { to, duration, ease }
+    }
+}
+
+/// A [MultiTween] moves through a list of values one after another, each with its own
+/// duration, and optionally its own easing. For simple `A -> B -> C` motion, this is much
+/// lighter than chaining a tween for every step.
+///
+/// ```
+/// # use tween::{MultiTween, Segment, Tween};
+/// let segments = [
+///     Segment::linear(10.0, 2.0),
+///     Segment::eased(20.0, 4.0, |t| t * t),
+///     Segment::linear(0.0, 1.0),
+/// ];
+/// let mut tween = MultiTween::new(0.0, &segments);
+///
+/// assert_eq!(tween.run(1.0), 5.0);
+/// assert_eq!(tween.run(4.0), 12.5);
+/// assert_eq!(tween.run(6.5), 10.0);
+/// assert_eq!(tween.duration(), 7.0);
+/// ```
+///
+/// Once every segment has finished, it holds the value of the last one.
+#[derive(Debug, Clone)]
+pub struct MultiTween<'a, V, T> {
+    segments: &'a [Segment<V, T>],
+    range: RangeInclusive<V>,
+    duration: T,
+}
+
+impl<'a, V, T> MultiTween<'a, V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Creates a new [MultiTween], which starts at `start` and then runs through each
+    /// segment in turn.
+    pub fn new(start: V, segments: &'a [Segment<V, T>]) -> Self {
+        let end = segments.last().map_or(start, |segment| segment.to);
+        let duration = segments
+            .iter()
+            .fold(T::ZERO, |duration, segment| duration.add(segment.duration));
+
+        Self {
+            segments,
+            range: start..=end,
+            duration,
+        }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: T) -> V {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// The segments this runs through.
+    pub fn segments(&self) -> &'a [Segment<V, T>] {
+        self.segments
+    }
+}
+
+impl<V, T> Tween for MultiTween<'_, V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    type Value = V;
+    type Time = T;
+
+    fn run(&mut self, new_time: T) -> V {
+        if let Some(held) = super::held_value(self.range.clone(), self.duration, new_time) {
+            return held;
+        }
+
+        let mut from = *self.range.start();
+
+        let mut elapsed = T::ZERO;
+
+        for segment in self.segments {
+            let end = elapsed.add(segment.duration);
+            if !new_time.is_complete(end) {
+                let percent = T::percent(segment.duration, new_time.sub(elapsed));
+                return lerp(from, segment.to, (segment.ease)(percent));
+            }
+
+            from = segment.to;
+            elapsed = end;
+        }
+
+        from
+    }
+
+    fn range(&self) -> RangeInclusive<V> {
+        self.range.clone()
+    }
+
+    fn duration(&self) -> T {
+        self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tweener;
+
+    #[test]
+    fn runs_through_segments() {
+        let segments = [
+            Segment::linear([10, 0], 10),
+            Segment::linear([10, 10], 5),
+            Segment::eased([0, 0], 10, |t| t * t),
+        ];
+        let mut tweener = Tweener::new(MultiTween::new([0, 0], &segments));
+
+        assert_eq!(tweener.update(5), Some([5, 0]));
+        assert_eq!(tweener.update(5), Some([10, 0]));
+        assert_eq!(tweener.update(4), Some([10, 8]));
+        assert_eq!(tweener.update(6), Some([8, 8]));
+        assert_eq!(tweener.update(5), Some([0, 0]));
+        assert_eq!(tweener.update(1), None);
+    }
+
+    #[test]
+    fn degenerate_segments() {
+        let mut empty = MultiTween::<f32, f32>::new(3.0, &[]);
+        assert_eq!(empty.duration(), 0.0);
+        assert_eq!(empty.run(1.0), 3.0);
+
+        // zero length segments jump straight to their value
+        let segments = [
+            Segment::linear(1.0, 1.0),
+            Segment::linear(5.0, 0.0),
+            Segment::linear(6.0, 1.0),
+        ];
+        let mut tween = MultiTween::new(0.0, &segments);
+        assert_eq!(tween.run(1.0), 5.0);
+        assert_eq!(tween.run(1.5), 5.5);
+        assert_eq!(tween.run(3.0), 6.0);
+    }
+}
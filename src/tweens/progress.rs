@@ -0,0 +1,108 @@
+use crate::{Map, Tween};
+use core::ops::RangeInclusive;
+
+/// A [Progress] tween outputs the eased *percentage* of its easing, always running from
+/// `0.0` to `1.0`, rather than a value of your own.
+///
+/// This is handy when you want to drive something which isn't a [TweenValue](crate::TweenValue)
+/// yourself, or to feed one eased clock into several things at once. Use [Progress::map]
+/// to turn the percentage into any value you like:
+///
+/// ```
+/// # use tween::{Linear, Progress, QuadIn};
+/// let mut progress = Progress::new(QuadIn::new, 10.0);
+/// assert_eq!(progress.run(5.0), 0.25);
+///
+/// let mut label = Progress::new(Linear::new, 10.0).map(|t| if t < 0.5 { "loading" } else { "almost done" });
+/// assert_eq!(label.run(2.0), "loading");
+/// assert_eq!(label.run(8.0), "almost done");
+/// ```
+///
+/// Easings which overshoot, such as [BackOut](crate::BackOut), will briefly leave `0.0..=1.0`
+/// in the middle of the tween, but always start at `0.0` and end at `1.0`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Progress<Ease> {
+    ease: Ease,
+}
+
+impl<Ease> Progress<Ease>
+where
+    Ease: Tween<Value = f64>,
+{
+    /// Creates a new [Progress] out of a constructor for an easing, and a duration. The
+    /// constructor is given the range `0.0..=1.0` and the duration, so any tween's `new`
+    /// function, like `SineIn::new`, can be passed in directly.
+    pub fn new(ease: impl FnOnce(RangeInclusive<f64>, Ease::Time) -> Ease, duration: Ease::Time) -> Self {
+        Self {
+            ease: ease(0.0..=1.0, duration),
+        }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: Ease::Time) -> f64 {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// Converts the eased percentage into another value every time this is run.
+    pub fn map<U, F>(self, map: F) -> Map<Self, F, U>
+    where
+        F: FnMut(f64) -> U,
+    {
+        Map::new(self, map)
+    }
+
+    /// Allows inspection of the inner easing.
+    pub fn ease(&self) -> &Ease {
+        &self.ease
+    }
+}
+
+impl<Ease> Tween for Progress<Ease>
+where
+    Ease: Tween<Value = f64>,
+{
+    type Value = f64;
+    type Time = Ease::Time;
+
+    fn run(&mut self, new_time: Self::Time) -> Self::Value {
+        self.ease.run(new_time)
+    }
+
+    fn run_percent(&mut self, percent: f64) -> Self::Value {
+        self.ease.run_percent(percent)
+    }
+
+    fn range(&self) -> RangeInclusive<Self::Value> {
+        self.ease.range()
+    }
+
+    fn duration(&self) -> Self::Time {
+        self.ease.duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BackOut, SineIn, Tweener};
+    use approx::assert_ulps_eq;
+
+    #[test]
+    fn matches_easing() {
+        let mut progress = Progress::new(SineIn::new, 10.0);
+        let mut sine = SineIn::new(0.0..=1.0, 10.0);
+
+        for time in 0..=10 {
+            let time = time as f64;
+            assert_ulps_eq!(progress.run(time), sine.run(time));
+        }
+    }
+
+    #[test]
+    fn ends_at_one() {
+        let mut tweener = Tweener::new(Progress::new(BackOut::new, 4));
+
+        assert!(tweener.update(2).unwrap() > 1.0);
+        assert_eq!(tweener.update(2), Some(1.0));
+    }
+}
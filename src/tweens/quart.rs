@@ -5,13 +5,12 @@ declare_tween!(
     /// An quartic tween in. Go [here](https://easings.net/#easeInQuart) for a visual demonstration.
     pub struct QuartIn;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time);
+    fn run_percent(&mut self, percent_time: f64) -> V {
         let new_value = self
             .value_delta
             .scale(percent_time * percent_time * percent_time * percent_time);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -19,13 +18,13 @@ declare_tween!(
     /// An quartic tween out. Go [here](https://easings.net/#easeOutQuart) for a visual demonstration.
     pub struct QuartOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time) - 1.0;
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        let percent_time = percent_time - 1.0;
         let new_value = self
             .value_delta
             .scale(-(percent_time * percent_time * percent_time * percent_time - 1.0));
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -33,8 +32,8 @@ declare_in_out_tween!(
     /// An quartic tween in and out. Go [here](https://easings.net/#easeInOutQuart) for a visual demonstration.
     pub struct QuartInOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time) * 2.0;
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        let percent_time = percent_time * 2.0;
 
         let scalar = if percent_time < 1.0 {
             percent_time * percent_time * percent_time * percent_time
@@ -42,9 +41,9 @@ declare_in_out_tween!(
             let p = percent_time - 2.0;
             -(p * p * p * p - 2.0)
         };
-        let new_value = self.half_delta.scale(scalar);
+        let new_value = self.value_delta.scale(scalar * 0.5);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
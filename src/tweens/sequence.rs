@@ -0,0 +1,519 @@
+use crate::{Tween, TweenTime, TweenValue};
+use core::ops::RangeInclusive;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// The tweens of a [Sequence] or a [Group]. The first `N` are stored inline, so the common short
+/// chains never allocate, and any past that spill into a `Vec`.
+#[derive(Debug, Clone)]
+struct Stages<Tw, const N: usize> {
+    inline: [Option<Tw>; N],
+    #[cfg(feature = "std")]
+    spilled: Vec<Tw>,
+}
+
+impl<Tw, const N: usize> Stages<Tw, N> {
+    fn new(first: Tw) -> Self {
+        let mut stages = Self {
+            inline: core::array::from_fn(|_| None),
+            #[cfg(feature = "std")]
+            spilled: Vec::new(),
+        };
+        stages.push(first);
+
+        stages
+    }
+
+    fn push(&mut self, stage: Tw) {
+        if self.try_push(stage).is_err() {
+            panic!("only {N} tweens can be stored without `std`");
+        }
+    }
+
+    /// Stores a stage, or, without `std`, hands it back if every inline slot is taken.
+    fn try_push(&mut self, stage: Tw) -> Result<(), Tw> {
+        if let Some(slot) = self.inline.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some(stage);
+            return Ok(());
+        }
+
+        #[cfg(feature = "std")]
+        {
+            self.spilled.push(stage);
+            Ok(())
+        }
+        #[cfg(not(feature = "std"))]
+        Err(stage)
+    }
+
+    fn is_inline(&self) -> bool {
+        self.spilled().is_empty()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &Tw> {
+        self.inline.iter().flatten().chain(self.spilled())
+    }
+
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut Tw> {
+        #[cfg(feature = "std")]
+        let spilled = &mut self.spilled[..];
+        #[cfg(not(feature = "std"))]
+        let spilled: &mut [Tw] = &mut [];
+
+        self.inline.iter_mut().flatten().chain(spilled)
+    }
+
+    fn first(&self) -> &Tw {
+        self.iter().next().expect("there is always a first tween")
+    }
+
+    fn last(&self) -> &Tw {
+        self.iter().last().expect("there is always a first tween")
+    }
+
+    #[cfg(feature = "std")]
+    fn spilled(&self) -> &[Tw] {
+        &self.spilled
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn spilled(&self) -> &[Tw] {
+        &[]
+    }
+}
+
+/// A [Sequence] runs tweens one after another, each starting when the one before it finishes.
+///
+/// The first `N` tweens, four by default, are stored inline, so building the usual two or three
+/// step chain every frame doesn't allocate. Longer sequences spill onto the heap, or, without
+/// `std`, panic, unless their tweens are added with [try_push](Self::try_push). Every tween must be the same type, so to mix easings, use an
+/// [Eased](crate::Eased).
+///
+/// ```
+/// # use tween::{Ease, Sequence, Tween};
+/// let mut sequence = Sequence::new(Ease::Linear.tween(0.0..=10.0, 2.0))
+///     .then(Ease::QuadIn.tween(10.0..=20.0, 2.0))
+///     .then(Ease::Linear.tween(20.0..=0.0, 1.0));
+///
+/// assert_eq!(sequence.run(1.0), 5.0);
+/// assert_eq!(sequence.run(3.0), 12.5);
+/// assert_eq!(sequence.run(4.5), 10.0);
+/// assert_eq!(sequence.duration(), 5.0);
+/// assert!(sequence.is_inline());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sequence<Tw: Tween, const N: usize = 4> {
+    stages: Stages<Tw, N>,
+    duration: Tw::Time,
+}
+
+impl<Tw> Sequence<Tw>
+where
+    Tw: Tween,
+{
+    /// Creates a new [Sequence], which starts with `first`.
+    pub fn new(first: Tw) -> Self {
+        Self::with_inline(first)
+    }
+}
+
+impl<Tw, const N: usize> Sequence<Tw, N>
+where
+    Tw: Tween,
+{
+    /// Creates a new [Sequence], which starts with `first`, and stores `N` tweens inline.
+    pub fn with_inline(first: Tw) -> Self {
+        Self {
+            duration: first.duration(),
+            stages: Stages::new(first),
+        }
+    }
+
+    /// Adds a tween to the end of the sequence, which starts once every tween before it has
+    /// finished.
+    ///
+    /// # Panics
+    ///
+    /// Without `std`, this panics if the sequence already has `N` tweens.
+    pub fn then(mut self, next: Tw) -> Self {
+        self.push(next);
+        self
+    }
+
+    /// Adds a tween to the end of the sequence, like [then](Self::then).
+    ///
+    /// # Panics
+    ///
+    /// Without `std`, this panics if the sequence already has `N` tweens. Use
+    /// [try_push](Self::try_push) to get the tween back instead.
+    pub fn push(&mut self, next: Tw) {
+        self.duration = self.duration.add(next.duration());
+        self.stages.push(next);
+    }
+
+    /// Adds a tween to the end of the sequence, like [push](Self::push). Without `std`, if the
+    /// sequence already has `N` tweens, the tween is handed back as the error. With `std`, this
+    /// never fails.
+    pub fn try_push(&mut self, next: Tw) -> Result<(), Tw> {
+        let duration = next.duration();
+        self.stages.try_push(next)?;
+        self.duration = self.duration.add(duration);
+
+        Ok(())
+    }
+
+    /// Returns `true` if every tween is stored inline, so the sequence hasn't allocated.
+    pub fn is_inline(&self) -> bool {
+        self.stages.is_inline()
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: Tw::Time) -> Tw::Value {
+        <Self as Tween>::run(self, new_time)
+    }
+}
+
+impl<Tw, const N: usize> Tween for Sequence<Tw, N>
+where
+    Tw: Tween,
+{
+    type Value = Tw::Value;
+    type Time = Tw::Time;
+
+    fn run(&mut self, new_time: Self::Time) -> Self::Value {
+        if let Some(held) = super::held_value(self.range(), self.duration, new_time) {
+            return held;
+        }
+
+        let mut elapsed = Self::Time::ZERO;
+        for stage in self.stages.iter_mut() {
+            let end = elapsed.add(stage.duration());
+            if !new_time.is_complete(end) {
+                return stage.run(new_time.sub(elapsed));
+            }
+
+            elapsed = end;
+        }
+
+        *self.stages.last().range().end()
+    }
+
+    fn range(&self) -> RangeInclusive<Self::Value> {
+        *self.stages.first().range().start()..=*self.stages.last().range().end()
+    }
+
+    fn duration(&self) -> Self::Time {
+        self.duration
+    }
+}
+
+/// A [Group] runs tweens all at once, adding their values together, so a shake can be layered
+/// on top of a slide, or a bob on top of a path. It lasts as long as its longest tween, and
+/// tweens which finish sooner hold their end values.
+///
+/// Like a [Sequence], the first `N` tweens are stored inline.
+///
+/// ```
+/// # use tween::{Group, Linear, Tween};
+/// let mut group = Group::new(Linear::new(0.0..=100.0, 4.0)).with(Linear::new(0.0..=10.0, 2.0));
+///
+/// assert_eq!(group.run(1.0), 30.0);
+/// assert_eq!(group.run(3.0), 85.0);
+/// assert_eq!(group.range(), 0.0..=110.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Group<Tw: Tween, const N: usize = 4> {
+    stages: Stages<Tw, N>,
+    duration: Tw::Time,
+}
+
+impl<Tw> Group<Tw>
+where
+    Tw: Tween,
+{
+    /// Creates a new [Group], of only `first`.
+    pub fn new(first: Tw) -> Self {
+        Self::with_inline(first)
+    }
+}
+
+impl<Tw, const N: usize> Group<Tw, N>
+where
+    Tw: Tween,
+{
+    /// Creates a new [Group], of only `first`, which stores `N` tweens inline.
+    pub fn with_inline(first: Tw) -> Self {
+        Self {
+            duration: first.duration(),
+            stages: Stages::new(first),
+        }
+    }
+
+    /// Adds a tween to the group, which runs alongside the others.
+    ///
+    /// # Panics
+    ///
+    /// Without `std`, this panics if the group already has `N` tweens.
+    pub fn with(mut self, tween: Tw) -> Self {
+        self.push(tween);
+        self
+    }
+
+    /// Adds a tween to the group, like [with](Self::with).
+    ///
+    /// # Panics
+    ///
+    /// Without `std`, this panics if the group already has `N` tweens. Use
+    /// [try_push](Self::try_push) to get the tween back instead.
+    pub fn push(&mut self, tween: Tw) {
+        if !self.duration.is_complete(tween.duration()) {
+            self.duration = tween.duration();
+        }
+        self.stages.push(tween);
+    }
+
+    /// Adds a tween to the group, like [push](Self::push). Without `std`, if the group already
+    /// has `N` tweens, the tween is handed back as the error. With `std`, this never fails.
+    pub fn try_push(&mut self, tween: Tw) -> Result<(), Tw> {
+        let duration = tween.duration();
+        self.stages.try_push(tween)?;
+        if !self.duration.is_complete(duration) {
+            self.duration = duration;
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if every tween is stored inline, so the group hasn't allocated.
+    pub fn is_inline(&self) -> bool {
+        self.stages.is_inline()
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: Tw::Time) -> Tw::Value {
+        <Self as Tween>::run(self, new_time)
+    }
+}
+
+impl<Tw, const N: usize> Tween for Group<Tw, N>
+where
+    Tw: Tween,
+{
+    type Value = Tw::Value;
+    type Time = Tw::Time;
+
+    fn run(&mut self, new_time: Self::Time) -> Self::Value {
+        if let Some(held) = super::held_value(self.range(), self.duration, new_time) {
+            return held;
+        }
+
+        self.stages.iter_mut().fold(Self::Value::ZERO, |value, stage| {
+            let stage_value = if new_time.is_complete(stage.duration()) {
+                *stage.range().end()
+            } else {
+                stage.run(new_time)
+            };
+
+            value.add(stage_value)
+        })
+    }
+
+    fn range(&self) -> RangeInclusive<Self::Value> {
+        let (start, end) = self
+            .stages
+            .iter()
+            .fold((Self::Value::ZERO, Self::Value::ZERO), |(start, end), stage| {
+                let (stage_start, stage_end) = stage.range().into_inner();
+                (start.add(stage_start), end.add(stage_end))
+            });
+
+        start..=end
+    }
+
+    fn duration(&self) -> Self::Time {
+        self.duration
+    }
+}
+
+/// Sequences and groups are saved as the list of their tweens, since everything else about them
+/// is worked out from those.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::{Group, Sequence};
+    use crate::Tween;
+    use core::fmt;
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{Error, SeqAccess, Visitor},
+    };
+
+    /// Rebuilds a [Sequence] or a [Group] from a list of its tweens.
+    struct StagesVisitor<Tw, C> {
+        new: fn(Tw) -> C,
+        push: fn(&mut C, Tw),
+        /// The most tweens which can be stored, without `std`.
+        capacity: Option<usize>,
+    }
+
+    impl<'de, Tw, C> Visitor<'de> for StagesVisitor<Tw, C>
+    where
+        Tw: Deserialize<'de>,
+    {
+        type Value = C;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a list of at least one tween")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+            let first = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(0, &self))?;
+            let mut stages = (self.new)(first);
+
+            let mut len = 1;
+            while let Some(stage) = seq.next_element()? {
+                if let Some(capacity) = self.capacity.filter(|&capacity| len == capacity) {
+                    return Err(A::Error::custom(format_args!(
+                        "only {capacity} tweens can be stored without `std`"
+                    )));
+                }
+
+                (self.push)(&mut stages, stage);
+                len += 1;
+            }
+
+            Ok(stages)
+        }
+    }
+
+    const fn capacity(inline: usize) -> Option<usize> {
+        if cfg!(feature = "std") { None } else { Some(inline) }
+    }
+
+    impl<Tw, const N: usize> Serialize for Sequence<Tw, N>
+    where
+        Tw: Tween + Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.stages.iter())
+        }
+    }
+
+    impl<'de, Tw, const N: usize> Deserialize<'de> for Sequence<Tw, N>
+    where
+        Tw: Tween + Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(StagesVisitor {
+                new: Self::with_inline,
+                push: Self::push,
+                capacity: capacity(N),
+            })
+        }
+    }
+
+    impl<Tw, const N: usize> Serialize for Group<Tw, N>
+    where
+        Tw: Tween + Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.stages.iter())
+        }
+    }
+
+    impl<'de, Tw, const N: usize> Deserialize<'de> for Group<Tw, N>
+    where
+        Tw: Tween + Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(StagesVisitor {
+                new: Self::with_inline,
+                push: Self::push,
+                capacity: capacity(N),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, Tweener};
+
+    #[test]
+    fn sequence_runs_in_order() {
+        let mut tweener = Tweener::new(
+            Sequence::new(Linear::new(0..=10, 10))
+                .then(Linear::new(10..=10, 0))
+                .then(Linear::new(10..=20, 5)),
+        );
+
+        assert_eq!(tweener.update(5), Some(5));
+        assert_eq!(tweener.update(5), Some(10));
+        assert_eq!(tweener.update(1), Some(12));
+        assert_eq!(tweener.update(4), Some(20));
+        assert_eq!(tweener.update(1), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn spills_past_inline() {
+        let mut sequence = Sequence::<_, 2>::with_inline(Linear::new(0.0..=1.0, 1.0)).then(Linear::new(1.0..=2.0, 1.0));
+        assert!(sequence.is_inline());
+
+        sequence.push(Linear::new(2.0..=4.0, 2.0));
+        assert!(!sequence.is_inline());
+        assert_eq!(sequence.range(), 0.0..=4.0);
+        assert_eq!(sequence.run(3.0), 3.0);
+
+        let mut group = Group::<_, 1>::with_inline(Linear::new(0.0..=1.0, 1.0)).with(Linear::new(0.0..=4.0, 4.0));
+        assert!(!group.is_inline());
+        assert_eq!(group.duration(), 4.0);
+        assert_eq!(group.run(-1.0), 0.0);
+        assert_eq!(group.run(2.0), 3.0);
+    }
+
+    #[test]
+    fn try_push() {
+        let mut sequence = Sequence::<_, 1>::with_inline(Linear::new(0..=1, 1));
+        let mut group = Group::<_, 1>::with_inline(Linear::new(0..=1, 1));
+        let next = Linear::new(1..=2, 4);
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(sequence.try_push(next), Ok(()));
+            assert_eq!(sequence.duration(), 5);
+            assert_eq!(group.try_push(next), Ok(()));
+            assert_eq!(group.duration(), 4);
+        }
+
+        // without `std`, a full sequence hands the tween back, and is left as it was
+        #[cfg(not(feature = "std"))]
+        {
+            assert_eq!(sequence.try_push(next), Err(next));
+            assert_eq!(sequence.duration(), 1);
+            assert_eq!(group.try_push(next), Err(next));
+            assert_eq!(group.duration(), 1);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize() {
+        use serde::{
+            Deserialize,
+            de::value::{Error, MapDeserializer, SeqDeserializer},
+        };
+
+        let linear = |start: f32, end: f32| {
+            MapDeserializer::new([("start", start), ("end", end), ("duration", 1.0)].into_iter())
+        };
+        let stages = SeqDeserializer::<_, Error>::new([linear(0.0, 1.0), linear(1.0, 3.0)].into_iter());
+
+        let mut sequence = Sequence::<Linear<f32, f32>>::deserialize(stages).unwrap();
+        assert_eq!(sequence.duration(), 2.0);
+        assert_eq!(sequence.run(1.5), 2.0);
+
+        let empty = SeqDeserializer::<_, Error>::new(core::iter::empty::<f32>());
+        assert!(Group::<Linear<f32, f32>>::deserialize(empty).is_err());
+    }
+}
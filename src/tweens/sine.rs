@@ -5,18 +5,17 @@ declare_tween!(
     /// An sine based tween in. Go [here](https://easings.net/#easeInSine) for a visual demonstration.
     pub struct SineIn;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time);
-
-        #[cfg(feature = "libm")]
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        #[cfg(all(feature = "libm", not(feature = "std")))]
         let time = libm::cos(percent_time * PI / 2.0);
 
         #[cfg(feature = "std")]
         let time = (percent_time * PI / 2.0).cos();
 
-        let new_value = self.value_delta.scale(-time);
+        let value_delta = self.value_delta;
+        let new_value = value_delta.scale(-time);
 
-        new_value.add(*self.range.start()).add(self.value_delta)
+        new_value.add(value_delta).add(self.start)
     }
 );
 
@@ -24,10 +23,8 @@ declare_tween!(
     /// An sine based tween out. Go [here](https://easings.net/#easeOutSine) for a visual demonstration.
     pub struct SineOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time);
-
-        #[cfg(feature = "libm")]
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        #[cfg(all(feature = "libm", not(feature = "std")))]
         let time = libm::sin(percent_time * PI / 2.0);
 
         #[cfg(feature = "std")]
@@ -35,7 +32,7 @@ declare_tween!(
 
         let new_value = self.value_delta.scale(time);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
@@ -43,16 +40,15 @@ declare_tween!(
     /// An sine based tween in out. Go [here](https://easings.net/#easeInOutSine) for a visual demonstration.
     pub struct SineInOut;
 
-    fn run(&mut self, new_time: T) -> V {
-        let percent_time = T::percent(self.duration, new_time);
-        #[cfg(feature = "libm")]
+    fn run_percent(&mut self, percent_time: f64) -> V {
+        #[cfg(all(feature = "libm", not(feature = "std")))]
         let time = libm::cos(percent_time * PI) - 1.0;
 
         #[cfg(feature = "std")]
         let time = (percent_time * PI).cos() - 1.0;
         let new_value = self.value_delta.scale(-time / 2.0);
 
-        new_value.add(*self.range.start())
+        new_value.add(self.start)
     }
 );
 
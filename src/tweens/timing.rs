@@ -0,0 +1,370 @@
+use super::cubic_bezier::{css_function, css_number};
+use crate::{CssError, CubicBezier, Tween, TweenTime, TweenValue};
+use core::{ops::RangeInclusive, str::FromStr};
+
+/// A [TimingFunction] is any CSS `<easing-function>`, so the easings of a theme or a config
+/// file can be written just as they would be in a stylesheet.
+///
+/// These are parsed from strings, such as `ease-in-out`, `cubic-bezier(0.1, 0.7, 1.0, 0.1)`,
+/// or `steps(4, jump-end)`. Note that the CSS keywords are curves of their own, so `ease-in`
+/// isn't any of the [Ease](crate::Ease) easings.
+///
+/// ```
+/// # use tween::{StepPosition, Steps, TimingFunction, Tweener};
+/// let timing: TimingFunction = "steps(4, jump-end)".parse().unwrap();
+/// assert_eq!(timing, TimingFunction::Steps(Steps::new(4, StepPosition::JumpEnd)));
+///
+/// let mut tweener = Tweener::new(timing.tween(0.0f32..=100.0, 1.0f32));
+/// assert_eq!(tweener.update(0.6), Some(50.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimingFunction {
+    /// The `linear` keyword, which doesn't ease at all.
+    Linear,
+    /// A `cubic-bezier()` function, or one of the keywords which stand for one, like `ease`.
+    CubicBezier(CubicBezier),
+    /// A `steps()` function, or the `step-start` and `step-end` keywords.
+    Steps(Steps),
+}
+
+impl TimingFunction {
+    /// Eases a percentage with this timing function.
+    pub fn ease(&self, percent: f64) -> f64 {
+        match self {
+            Self::Linear => percent.clamp(0.0, 1.0),
+            Self::CubicBezier(bezier) => bezier.ease(percent),
+            Self::Steps(steps) => steps.ease(percent),
+        }
+    }
+
+    /// Creates a new tween out of a range with a duration, which is eased by this timing
+    /// function.
+    pub fn tween<V, T>(self, range: RangeInclusive<V>, duration: T) -> TimingTween<V, T>
+    where
+        V: TweenValue,
+        T: TweenTime,
+    {
+        TimingTween::new(self, range, duration)
+    }
+}
+
+impl FromStr for TimingFunction {
+    type Err = CssError;
+
+    fn from_str(css: &str) -> Result<Self, CssError> {
+        let keyword = css.trim();
+        let keywords = [
+            ("linear", Self::Linear),
+            ("ease", Self::CubicBezier(CubicBezier::EASE)),
+            ("ease-in", Self::CubicBezier(CubicBezier::EASE_IN)),
+            ("ease-out", Self::CubicBezier(CubicBezier::EASE_OUT)),
+            ("ease-in-out", Self::CubicBezier(CubicBezier::EASE_IN_OUT)),
+            ("step-start", Self::Steps(Steps::new(1, StepPosition::JumpStart))),
+            ("step-end", Self::Steps(Steps::new(1, StepPosition::JumpEnd))),
+        ];
+        if let Some((_, timing)) = keywords.iter().find(|(name, _)| name.eq_ignore_ascii_case(keyword)) {
+            return Ok(*timing);
+        }
+
+        match CubicBezier::from_css(css) {
+            Err(CssError::Unrecognized) => Steps::from_css(css).map(Self::Steps),
+            bezier => bezier.map(Self::CubicBezier),
+        }
+    }
+}
+
+impl From<CubicBezier> for TimingFunction {
+    fn from(bezier: CubicBezier) -> Self {
+        Self::CubicBezier(bezier)
+    }
+}
+
+impl From<Steps> for TimingFunction {
+    fn from(steps: Steps) -> Self {
+        Self::Steps(steps)
+    }
+}
+
+/// Which ends of a [Steps] jump, rather than hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StepPosition {
+    /// The first step happens right at the start, which is `jump-start` or `start` in CSS.
+    JumpStart,
+    /// The last step happens right at the end, which is `jump-end` or `end` in CSS.
+    JumpEnd,
+    /// Neither end jumps, so the steps start at `0.0` and end at `1.0`. This is `jump-none`.
+    JumpNone,
+    /// Both ends jump, so neither `0.0` nor `1.0` is held for a step. This is `jump-both`.
+    JumpBoth,
+}
+
+/// A timing function which jumps between evenly spaced steps, like the `steps()` of CSS, such
+/// as for a ticking clock hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SavedSteps"))]
+pub struct Steps {
+    count: u32,
+    position: StepPosition,
+}
+
+/// How [Steps] are saved, so they're checked as they're loaded, as [Steps::new] checks them.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SavedSteps {
+    count: u32,
+    position: StepPosition,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SavedSteps> for Steps {
+    type Error = CssError;
+
+    fn try_from(saved: SavedSteps) -> Result<Self, CssError> {
+        Self::checked(saved.count, saved.position)
+    }
+}
+
+impl Steps {
+    /// Creates a new timing function of `count` steps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is zero, or is one with [StepPosition::JumpNone], which would have
+    /// no steps in between its ends.
+    pub const fn new(count: u32, position: StepPosition) -> Self {
+        let least = match position {
+            StepPosition::JumpNone => 2,
+            _ => 1,
+        };
+        assert!(count >= least, "too few steps for the step position");
+
+        Self { count, position }
+    }
+
+    /// Parses a CSS `steps(count, position)` timing function, whose position can be left out,
+    /// as it's `jump-end` by default.
+    pub fn from_css(css: &str) -> Result<Self, CssError> {
+        let arguments = css_function(css, "steps")?;
+
+        let mut arguments = arguments.split(',');
+        let count = arguments.next().unwrap_or_default().trim();
+        let position = arguments.next();
+        let found = 2 + arguments.count();
+        if found > 2 {
+            return Err(CssError::ArgumentCount { expected: 2, found });
+        }
+
+        let count = match count.parse::<u32>() {
+            Ok(count) => count,
+            Err(_) if css_number(count).is_ok() => return Err(CssError::OutOfRange),
+            Err(_) => return Err(CssError::InvalidNumber),
+        };
+
+        let position = match position.map(str::trim) {
+            None => StepPosition::JumpEnd,
+            Some(position) => {
+                [
+                    ("jump-start", StepPosition::JumpStart),
+                    ("start", StepPosition::JumpStart),
+                    ("jump-end", StepPosition::JumpEnd),
+                    ("end", StepPosition::JumpEnd),
+                    ("jump-none", StepPosition::JumpNone),
+                    ("jump-both", StepPosition::JumpBoth),
+                ]
+                .into_iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(position))
+                .ok_or(CssError::Unrecognized)?
+                .1
+            }
+        };
+
+        Self::checked(count, position)
+    }
+
+    /// Like [new](Self::new), but gives [CssError::OutOfRange] rather than panicking.
+    fn checked(count: u32, position: StepPosition) -> Result<Self, CssError> {
+        if count == 0 || (count == 1 && position == StepPosition::JumpNone) {
+            return Err(CssError::OutOfRange);
+        }
+
+        Ok(Self::new(count, position))
+    }
+
+    /// The number of steps.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Which ends of the steps jump.
+    pub fn position(&self) -> StepPosition {
+        self.position
+    }
+
+    /// Eases a percentage, by rounding it down to the step it's in. Percentages outside of
+    /// `0.0..=1.0` hold the nearest end.
+    pub fn ease(&self, percent: f64) -> f64 {
+        let percent = percent.clamp(0.0, 1.0);
+        let count = f64::from(self.count);
+
+        let mut step = crate::math::floor(percent * count);
+        if matches!(self.position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+            step += 1.0;
+        }
+
+        let jumps = match self.position {
+            StepPosition::JumpStart | StepPosition::JumpEnd => count,
+            StepPosition::JumpNone => count - 1.0,
+            StepPosition::JumpBoth => count + 1.0,
+        };
+
+        step.min(jumps) / jumps
+    }
+}
+
+/// A tween which is eased by a [TimingFunction]. It's made with [TimingFunction::tween].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimingTween<V, T> {
+    timing: TimingFunction,
+    start: V,
+    end: V,
+    duration: T,
+}
+
+impl<V, T> TimingTween<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    /// Creates a new tween out of a range with a duration, which is eased by `timing`.
+    pub fn new(timing: TimingFunction, range: RangeInclusive<V>, duration: T) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            timing,
+            start,
+            end,
+            duration,
+        }
+    }
+
+    /// Run the given Tween with a new time.
+    pub fn run(&mut self, new_time: T) -> V {
+        <Self as Tween>::run(self, new_time)
+    }
+
+    /// The timing function which eases this tween.
+    pub fn timing(&self) -> TimingFunction {
+        self.timing
+    }
+}
+
+impl<V, T> Tween for TimingTween<V, T>
+where
+    V: TweenValue,
+    T: TweenTime,
+{
+    type Value = V;
+    type Time = T;
+
+    fn run(&mut self, new_time: T) -> V {
+        if let Some(held) = crate::tweens::held_value(self.range(), self.duration, new_time) {
+            return held;
+        }
+
+        self.run_percent(T::percent(self.duration, new_time))
+    }
+
+    fn run_percent(&mut self, percent: f64) -> V {
+        V::calculate_delta(self.end, self.start)
+            .scale(self.timing.ease(percent))
+            .add(self.start)
+    }
+
+    fn range(&self) -> RangeInclusive<V> {
+        self.start..=self.end
+    }
+
+    fn duration(&self) -> T {
+        self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let parse = |css: &str| css.parse::<TimingFunction>();
+        assert_eq!(parse("linear"), Ok(TimingFunction::Linear));
+        assert_eq!(parse(" EASE "), Ok(CubicBezier::EASE.into()));
+        assert_eq!(parse("ease-in-out"), Ok(CubicBezier::EASE_IN_OUT.into()));
+        assert_eq!(
+            parse("cubic-bezier(0.1, 0.7, 1.0, 0.1)"),
+            Ok(CubicBezier::new(0.1, 0.7, 1.0, 0.1).into())
+        );
+        assert_eq!(parse("step-start"), Ok(Steps::new(1, StepPosition::JumpStart).into()));
+        assert_eq!(parse("steps(3)"), Ok(Steps::new(3, StepPosition::JumpEnd).into()));
+        assert_eq!(
+            parse("steps(2, jump-none)"),
+            Ok(Steps::new(2, StepPosition::JumpNone).into())
+        );
+
+        assert_eq!(parse("bouncy"), Err(CssError::Unrecognized));
+        assert_eq!(parse("steps(4, sideways)"), Err(CssError::Unrecognized));
+        assert_eq!(parse("steps(1.5)"), Err(CssError::OutOfRange));
+        assert_eq!(parse("steps(1, jump-none)"), Err(CssError::OutOfRange));
+        assert_eq!(parse("steps(four)"), Err(CssError::InvalidNumber));
+        assert_eq!(
+            parse("steps(4, end, 1)"),
+            Err(CssError::ArgumentCount { expected: 2, found: 3 })
+        );
+    }
+
+    #[test]
+    fn steps() {
+        let ease = |position, percent| Steps::new(4, position).ease(percent);
+        let percents = [0.0, 0.1, 0.3, 0.6, 0.9, 1.0];
+
+        assert_eq!(
+            percents.map(|p| ease(StepPosition::JumpStart, p)),
+            [0.25, 0.25, 0.5, 0.75, 1.0, 1.0]
+        );
+        assert_eq!(
+            percents.map(|p| ease(StepPosition::JumpEnd, p)),
+            [0.0, 0.0, 0.25, 0.5, 0.75, 1.0]
+        );
+        assert_eq!(
+            percents.map(|p| ease(StepPosition::JumpNone, p)),
+            [0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0]
+        );
+        assert_eq!(
+            percents.map(|p| ease(StepPosition::JumpBoth, p)),
+            [0.2, 0.2, 0.4, 0.6, 0.8, 1.0]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize() {
+        use serde::{
+            Deserialize,
+            de::value::{Error, MapDeserializer},
+        };
+
+        // the position is given by the index of its variant
+        let load = |count: u32, position: u32| {
+            let fields = [("count", count), ("position", position)];
+            Steps::deserialize(MapDeserializer::<_, Error>::new(fields.into_iter()))
+        };
+
+        assert_eq!(load(4, 1), Ok(Steps::new(4, StepPosition::JumpEnd)));
+        // these would ease to `0.0 / 0.0`
+        assert!(load(0, 1).is_err());
+        assert!(load(1, 2).is_err());
+        assert_eq!(load(2, 2), Ok(Steps::new(2, StepPosition::JumpNone)));
+    }
+}
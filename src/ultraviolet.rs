@@ -0,0 +1,24 @@
+use crate::{Distance, TweenValue};
+use ultraviolet::Vec3;
+
+impl TweenValue for Vec3 {
+    const ZERO: Self = Vec3::new(0.0, 0.0, 0.0);
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        destination - start
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        self * scale as f32
+    }
+}
+
+impl Distance for Vec3 {
+    fn distance(self, other: Self) -> f64 {
+        (self - other).mag() as f64
+    }
+}
@@ -0,0 +1,354 @@
+//! Curves imported from Unity's `AnimationCurve`, so the gameplay curves tuned in a Unity
+//! project can be carried over.
+//!
+//! Each [Keyframe] of a curve has a value at a time, and the slopes the curve arrives and
+//! leaves it with. Between two keyframes, the curve is a cubic Hermite curve, or, if either end
+//! is weighted, a cubic bezier whose handles are as long as the weights. A curve is
+//! [instantiated](instantiate) into a [Sequence] of a [CurveTween] for each pair of keyframes.
+//!
+//! ```
+//! # use tween::{unity::{self, Keyframe}, Tween};
+//! // rises from 0.0 to 1.0, leaving and arriving flat, like `AnimationCurve.EaseInOut`
+//! let keys = [Keyframe::new(0.0, 0.0), Keyframe::new(2.0, 1.0)];
+//!
+//! let mut curve = unity::instantiate(&keys).unwrap();
+//! assert_eq!(curve.run(1.0), 0.5);
+//! ```
+//!
+//! With the `serde` feature, keyframes deserialize from the fields Unity writes in its asset
+//! files, such as `time`, `value`, `inSlope`, and `outSlope`.
+
+use crate::{CubicBezier, Hermite, Sequence, StepPosition, Steps, TimingFunction, TimingTween, Tween, TweenTime};
+use core::{fmt, ops::RangeInclusive};
+
+/// A keyframe of a Unity `AnimationCurve`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Keyframe {
+    /// The time of this keyframe.
+    pub time: f32,
+    /// The value of the curve at this keyframe.
+    pub value: f32,
+    /// The slope the curve arrives at this keyframe with, in value per unit of time. If it's
+    /// infinite, the curve holds the value of the keyframe before this one until it.
+    #[cfg_attr(feature = "serde", serde(rename = "inSlope", default))]
+    pub in_tangent: f32,
+    /// The slope the curve leaves this keyframe with. If it's infinite, the curve holds the
+    /// value of this keyframe until the next one.
+    #[cfg_attr(feature = "serde", serde(rename = "outSlope", default))]
+    pub out_tangent: f32,
+    /// How long the handle arriving at this keyframe is, as a fraction of the time since the
+    /// keyframe before it, if it's weighted.
+    #[cfg_attr(feature = "serde", serde(default = "third"))]
+    pub in_weight: f32,
+    /// How long the handle leaving this keyframe is, as a fraction of the time until the next
+    /// keyframe, if it's weighted.
+    #[cfg_attr(feature = "serde", serde(default = "third"))]
+    pub out_weight: f32,
+    /// Which of the weights are used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub weighted_mode: WeightedMode,
+}
+
+#[cfg(feature = "serde")]
+fn third() -> f32 {
+    1.0 / 3.0
+}
+
+/// Which handles of a Unity [Keyframe] are weighted, with the same numbers as Unity's
+/// `WeightedMode`. Handles which aren't weighted are a third as long as their side of the
+/// keyframe, which makes a Hermite curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u8", into = "u8"))]
+pub enum WeightedMode {
+    /// Neither handle is weighted.
+    #[default]
+    None = 0,
+    /// The handle arriving at the keyframe is weighted.
+    In = 1,
+    /// The handle leaving the keyframe is weighted.
+    Out = 2,
+    /// Both handles are weighted.
+    Both = 3,
+}
+
+impl From<u8> for WeightedMode {
+    fn from(mode: u8) -> Self {
+        match mode {
+            1 => Self::In,
+            2 => Self::Out,
+            3 => Self::Both,
+            _ => Self::None,
+        }
+    }
+}
+
+impl From<WeightedMode> for u8 {
+    fn from(mode: WeightedMode) -> Self {
+        mode as u8
+    }
+}
+
+impl Keyframe {
+    /// Creates a new keyframe, which the curve leaves and arrives at flat, without weights.
+    pub fn new(time: f32, value: f32) -> Self {
+        Self {
+            time,
+            value,
+            in_tangent: 0.0,
+            out_tangent: 0.0,
+            in_weight: 1.0 / 3.0,
+            out_weight: 1.0 / 3.0,
+            weighted_mode: WeightedMode::None,
+        }
+    }
+
+    /// Sets the slopes the curve arrives and leaves this keyframe with.
+    pub fn with_tangents(self, in_tangent: f32, out_tangent: f32) -> Self {
+        Self {
+            in_tangent,
+            out_tangent,
+            ..self
+        }
+    }
+
+    /// Sets the weights of this keyframe's handles, and which of them are used.
+    pub fn with_weights(self, in_weight: f32, out_weight: f32, weighted_mode: WeightedMode) -> Self {
+        Self {
+            in_weight,
+            out_weight,
+            weighted_mode,
+            ..self
+        }
+    }
+
+    /// The weight of the handle arriving at this keyframe.
+    fn in_weight(&self) -> f32 {
+        match self.weighted_mode {
+            WeightedMode::In | WeightedMode::Both => self.in_weight.clamp(0.0, 1.0),
+            _ => 1.0 / 3.0,
+        }
+    }
+
+    /// The weight of the handle leaving this keyframe.
+    fn out_weight(&self) -> f32 {
+        match self.weighted_mode {
+            WeightedMode::Out | WeightedMode::Both => self.out_weight.clamp(0.0, 1.0),
+            _ => 1.0 / 3.0,
+        }
+    }
+}
+
+/// Why a list of Unity [Keyframe]s couldn't be instantiated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveError {
+    /// There are no keyframes.
+    NoKeyframes,
+    /// A keyframe is at an earlier time than the keyframe before it.
+    UnorderedKeyframes {
+        /// The index of the keyframe which is out of order.
+        index: usize,
+    },
+}
+
+impl fmt::Display for CurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoKeyframes => f.write_str("there are no keyframes"),
+            Self::UnorderedKeyframes { index } => write!(f, "keyframe {index} is before the keyframe before it"),
+        }
+    }
+}
+
+impl std::error::Error for CurveError {}
+
+/// Makes a [Sequence] which plays a Unity curve from time zero. Until the first keyframe, the
+/// curve holds its value, and after the last, it holds the last value, like a curve which
+/// clamps.
+pub fn instantiate(keyframes: &[Keyframe]) -> Result<Sequence<CurveTween>, CurveError> {
+    let first = keyframes.first().ok_or(CurveError::NoKeyframes)?;
+
+    let hold = TimingFunction::Linear.tween(first.value..=first.value, first.time);
+    let mut sequence = Sequence::new(CurveTween::Constant(hold));
+    for (index, pair) in keyframes.windows(2).enumerate() {
+        let [from, to] = [pair[0], pair[1]];
+        let duration = to.time - from.time;
+        if duration < 0.0 {
+            return Err(CurveError::UnorderedKeyframes { index: index + 1 });
+        }
+
+        let range = from.value..=to.value;
+        let (out_weight, in_weight) = (from.out_weight(), to.in_weight());
+        sequence.push(if from.out_tangent.is_infinite() || to.in_tangent.is_infinite() {
+            let step = TimingFunction::Steps(Steps::new(1, StepPosition::JumpEnd));
+            CurveTween::Constant(step.tween(range, duration))
+        } else if out_weight == 1.0 / 3.0 && in_weight == 1.0 / 3.0 {
+            CurveTween::Hermite(Hermite::with_velocities(
+                range,
+                from.out_tangent,
+                to.in_tangent,
+                duration,
+            ))
+        } else {
+            CurveTween::Weighted(WeightedTween {
+                bezier: CubicBezier::new(out_weight.into(), 0.0, (1.0 - in_weight).into(), 1.0),
+                start: from.value,
+                start_handle: from.value + from.out_tangent * duration * out_weight,
+                end_handle: to.value - to.in_tangent * duration * in_weight,
+                end: to.value,
+                duration,
+            })
+        });
+    }
+
+    Ok(sequence)
+}
+
+/// The tween between two keyframes of a Unity curve.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CurveTween {
+    /// A curve which holds its value, before the first keyframe or out of an infinite
+    /// tangent.
+    Constant(TimingTween<f32, f32>),
+    /// A curve between two keyframes which aren't weighted.
+    Hermite(Hermite<f32, f32>),
+    /// A curve between two keyframes, at least one of which is weighted.
+    Weighted(WeightedTween),
+}
+
+/// A cubic bezier between two weighted Unity keyframes, whose handles are placed by the
+/// weights across time, and by the tangents up the curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedTween {
+    /// Only the x of this curve is used, to find how far along the curve a time is.
+    bezier: CubicBezier,
+    start: f32,
+    start_handle: f32,
+    end_handle: f32,
+    end: f32,
+    duration: f32,
+}
+
+impl Tween for WeightedTween {
+    type Value = f32;
+    type Time = f32;
+
+    fn run(&mut self, new_time: f32) -> f32 {
+        if let Some(held) = crate::tweens::held_value(self.range(), self.duration, new_time) {
+            return held;
+        }
+
+        self.run_percent(f32::percent(self.duration, new_time))
+    }
+
+    fn run_percent(&mut self, percent: f64) -> f32 {
+        let t = self.bezier.solve_x(percent.clamp(0.0, 1.0));
+        let u = 1.0 - t;
+
+        let [start, start_handle, end_handle, end] =
+            [self.start, self.start_handle, self.end_handle, self.end].map(f64::from);
+        let value = u * u * u * start + 3.0 * u * u * t * start_handle + 3.0 * u * t * t * end_handle + t * t * t * end;
+        value as f32
+    }
+
+    fn range(&self) -> RangeInclusive<f32> {
+        self.start..=self.end
+    }
+
+    fn duration(&self) -> f32 {
+        self.duration
+    }
+}
+
+impl Tween for CurveTween {
+    type Value = f32;
+    type Time = f32;
+
+    fn run(&mut self, new_time: f32) -> f32 {
+        match self {
+            Self::Constant(tween) => tween.run(new_time),
+            Self::Hermite(tween) => tween.run(new_time),
+            Self::Weighted(tween) => tween.run(new_time),
+        }
+    }
+
+    fn run_percent(&mut self, percent: f64) -> f32 {
+        match self {
+            Self::Constant(tween) => tween.run_percent(percent),
+            Self::Hermite(tween) => tween.run_percent(percent),
+            Self::Weighted(tween) => tween.run_percent(percent),
+        }
+    }
+
+    fn range(&self) -> RangeInclusive<f32> {
+        match self {
+            Self::Constant(tween) => tween.range(),
+            Self::Hermite(tween) => tween.range(),
+            Self::Weighted(tween) => tween.range(),
+        }
+    }
+
+    fn duration(&self) -> f32 {
+        match self {
+            Self::Constant(tween) => tween.duration(),
+            Self::Hermite(tween) => tween.duration(),
+            Self::Weighted(tween) => tween.duration(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn instantiate_curve() {
+        let mut keys = [
+            Keyframe::new(1.0, 0.0).with_tangents(0.0, 2.0),
+            Keyframe::new(2.0, 1.0).with_tangents(-1.0, f32::INFINITY),
+            Keyframe::new(3.0, 5.0),
+        ];
+
+        let mut curve = instantiate(&keys).unwrap();
+        assert_eq!(curve.duration(), 3.0);
+        assert_eq!(curve.run(0.5), 0.0);
+        assert_eq!(
+            curve.run(1.5),
+            Hermite::with_velocities(0.0..=1.0, 2.0, -1.0, 1.0).run(0.5)
+        );
+        assert_eq!(curve.run(2.5), 1.0);
+        assert_eq!(curve.run(3.0), 5.0);
+
+        // weights of about a third make about the same curve as no weights at all
+        keys[0] = keys[0].with_weights(0.5, 0.3333, WeightedMode::Both);
+        let mut weighted = instantiate(&keys).unwrap();
+        for time in [1.1, 1.5, 1.9] {
+            assert_abs_diff_eq!(weighted.run(time), curve.run(time), epsilon = 1e-3);
+        }
+
+        keys[2].time = 1.5;
+        assert_eq!(
+            instantiate(&keys).unwrap_err(),
+            CurveError::UnorderedKeyframes { index: 2 }
+        );
+    }
+
+    #[test]
+    fn weighted() {
+        let keys = [
+            Keyframe::new(0.0, 0.0).with_weights(0.0, 0.9, WeightedMode::Out),
+            Keyframe::new(1.0, 1.0).with_weights(0.9, 0.0, WeightedMode::In),
+        ];
+
+        // long flat handles hold the curve near its ends for longer than a Hermite curve
+        let mut curve = instantiate(&keys).unwrap();
+        assert_abs_diff_eq!(curve.run(0.5), 0.5, epsilon = 1e-6);
+        assert!(curve.run(0.2) < Hermite::new(0.0..=1.0, 1.0).run(0.2));
+        assert_eq!(curve.run(1.0), 1.0);
+    }
+}
@@ -0,0 +1,237 @@
+mod angle;
+mod discrete;
+mod gradient;
+mod rect;
+mod rotation;
+mod rounded;
+mod transform;
+
+pub use angle::Angle;
+pub use discrete::Discrete;
+pub use gradient::Gradient;
+pub use rect::Rect;
+pub use rotation::Rotation;
+pub use rounded::{Ceil, Floor, Nearest, Rounded, Rounding, Stochastic};
+pub use transform::{Transform2D, Transform3D};
+
+use crate::TweenValue;
+use core::time::Duration;
+
+/// Arrays are tweened element by element, so `[f32; 4]` works as an RGBA color and
+/// `[Vec3; 8]` as a chain of joints.
+impl<V: TweenValue, const N: usize> TweenValue for [V; N] {
+    const ZERO: Self = [V::ZERO; N];
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        core::array::from_fn(|i| V::calculate_delta(destination[i], start[i]))
+    }
+
+    fn add(self, other: Self) -> Self {
+        core::array::from_fn(|i| self[i].add(other[i]))
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        self.map(|v| v.scale(scale))
+    }
+}
+
+/// Durations are tweened with saturating arithmetic, since they can't be negative. In
+/// particular, this means that the delta of a tween from a longer duration *down* to a shorter
+/// one saturates to zero, so such a tween never moves. Tween upwards and subtract the result
+/// from the longer duration instead, which is how a cooldown bar counts down.
+///
+/// Scaling by a negative factor, which overshooting easings like [BackIn](crate::BackIn) do,
+/// saturates to zero as well.
+impl TweenValue for Duration {
+    const ZERO: Self = Duration::ZERO;
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        destination.saturating_sub(start)
+    }
+
+    fn add(self, other: Self) -> Self {
+        self.saturating_add(other)
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        let secs = self.as_secs_f64() * scale;
+        if secs > 0.0 {
+            Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+        } else {
+            Duration::ZERO
+        }
+    }
+}
+
+/// Implements [TweenValue] for a tuple, tweening each field on its own.
+macro_rules! tuple_value {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: TweenValue),+> TweenValue for ($($name,)+) {
+            const ZERO: Self = ($($name::ZERO,)+);
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                ($($name::calculate_delta(destination.$index, start.$index),)+)
+            }
+
+            fn add(self, other: Self) -> Self {
+                ($(self.$index.add(other.$index),)+)
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                ($(self.$index.scale(scale),)+)
+            }
+        }
+    };
+}
+
+tuple_value!(A 0);
+tuple_value!(A 0, B 1);
+tuple_value!(A 0, B 1, C 2);
+tuple_value!(A 0, B 1, C 2, D 3);
+tuple_value!(A 0, B 1, C 2, D 3, E 4);
+tuple_value!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_value!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_value!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+#[cfg(test)]
+mod tests {
+    use crate::{BackIn, ElasticOut, Linear, QuadIn, Tween, TweenTime, TweenValue};
+    use core::time::Duration;
+
+    #[test]
+    fn arrays() {
+        let mut tween = Linear::new([0.0, 10.0, 100.0]..=[1.0, 20.0, 0.0], 2.0);
+        assert_eq!(tween.run(1.0), [0.5, 15.0, 50.0]);
+
+        let mut tween = QuadIn::new([0u8; 4]..=[255, 128, 0, 255], 10);
+        assert_eq!(tween.run(10), [255, 128, 0, 255]);
+        assert_eq!(tween.range(), [0; 4]..=[255, 128, 0, 255]);
+    }
+
+    #[test]
+    fn tuples() {
+        let mut tween = Linear::new((0.0f32, 10.0f64, 0u8)..=(1.0, 20.0, 200), 4.0);
+        assert_eq!(tween.run(2.0), (0.5, 15.0, 100));
+        assert_eq!(tween.run(4.0), (1.0, 20.0, 200));
+
+        let mut tween = Linear::new((0,)..=(8,), 4);
+        assert_eq!(tween.run(1), (2,));
+    }
+
+    #[test]
+    fn durations() {
+        let mut tween = Linear::new(Duration::ZERO..=Duration::from_secs(10), 4.0);
+        assert_eq!(tween.run(1.0), Duration::from_millis(2500));
+
+        // these saturate rather than underflowing
+        let mut tween = BackIn::new(Duration::ZERO..=Duration::from_secs(10), 4.0);
+        assert_eq!(tween.run(1.0), Duration::ZERO);
+        let mut tween = Linear::new(Duration::from_secs(10)..=Duration::ZERO, 4.0);
+        assert_eq!(tween.run(2.0), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn wide_integers() {
+        let start = u64::MAX - 1_000_003;
+        let mut tween = Linear::new(start..=u64::MAX, 1_000_003u64);
+        assert_eq!(tween.run(1_000_003), u64::MAX);
+
+        let mut last = start;
+        for time in (0..=1_000_003).step_by(997) {
+            let value = tween.run(time);
+            assert!(value >= last);
+            last = value;
+        }
+
+        // going through an f64 would round this to `-(1 << 62)`
+        let end = -(1i64 << 62) - 1;
+        let mut tween = Linear::new(0..=end, 3i64);
+        assert_eq!(tween.run(3), end);
+    }
+
+    #[test]
+    fn wide_integers_overshoot() {
+        // these saturate, like the narrower integers do, rather than wrapping around
+        assert_eq!(TweenValue::scale(100u64, -0.1), 0);
+        assert_eq!(BackIn::new(10u64..=110, 10u32).run(2), 10);
+        assert_eq!(BackIn::new(10usize..=110, 10u32).run(2), 10);
+
+        let mut tween = BackIn::new(-10i64..=90, 10u32);
+        assert!(tween.run(2) < -10);
+
+        let mut tween = ElasticOut::new(0u64..=100, 10u32);
+        let mut tween_signed = ElasticOut::new(-100i64..=0, 10u32);
+        for time in 0..=10 {
+            let value = tween.run(time);
+            assert!(value <= 150);
+            assert!(tween_signed.run(time) <= 50);
+        }
+    }
+
+    #[test]
+    fn wide_integers_extreme_scale() {
+        // past what the product of an i128 can hold
+        assert_eq!(TweenValue::scale(i64::MAX, 1e20), i64::MAX);
+        assert_eq!(TweenValue::scale(i64::MAX, -1e20), i64::MIN);
+        assert_eq!(TweenValue::scale(i64::MIN, 1e300), i64::MIN);
+        assert_eq!(TweenValue::scale(u64::MAX, f64::INFINITY), u64::MAX);
+        assert_eq!(TweenValue::scale(u64::MAX, f64::NAN), 0);
+        assert_eq!(TweenTime::scale(u64::MAX, 1e20), u64::MAX);
+        assert_eq!(TweenTime::scale(i64::MIN, 1e20), i64::MIN);
+
+        // fractions round toward zero, as the narrower integers' float casts do
+        assert_eq!(TweenValue::scale(3i64, -0.5), TweenValue::scale(3i32, -0.5) as i64);
+        assert_eq!(TweenValue::scale(3i64, -0.5), -1);
+        assert_eq!(TweenValue::scale(-3i64, 0.5), -1);
+        assert_eq!(TweenTime::scale(-7i64, 1.5), -10);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive() {
+        #[derive(crate::TweenValue, Debug, PartialEq, Clone, Copy)]
+        struct Style {
+            opacity: f32,
+            offset: [f32; 2],
+            #[tween(skip)]
+            id: u32,
+            #[tween(skip, zero = false)]
+            visible: bool,
+        }
+
+        #[derive(crate::TweenValue, Debug, PartialEq, Clone, Copy)]
+        struct Pair<T>(T, T);
+
+        let start = Style {
+            opacity: 0.0,
+            offset: [0.0, 10.0],
+            id: 1,
+            visible: true,
+        };
+        let end = Style {
+            opacity: 1.0,
+            offset: [10.0, 0.0],
+            id: 2,
+            visible: false,
+        };
+
+        let mut tween = Linear::new(start..=end, 2.0);
+        let halfway = tween.run(1.0);
+        assert_eq!(
+            halfway,
+            Style {
+                opacity: 0.5,
+                offset: [5.0, 5.0],
+                id: 1,
+                visible: true,
+            }
+        );
+
+        // a skipped field keeps `self`'s value, however the values are added
+        assert_eq!(TweenValue::add(start, end).id, 1);
+        assert_eq!(TweenValue::add(end, start).id, 2);
+
+        let mut tween = Linear::new(Pair(0, 10)..=Pair(10, 0), 2);
+        assert_eq!(tween.run(1), Pair(5, 5));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::TweenValue;
+use core::f32::consts::{PI, TAU};
+
+/// An angle which is tweened the short way around the circle, wrapping across `±π`, so a tween
+/// from `350°` to `10°` turns `20°` forwards rather than `340°` backwards.
+///
+/// Use [Angle::the_long_way] on either end of a tween to go around the other way instead.
+///
+/// ```
+/// # use tween::{Angle, Linear};
+/// let mut tween = Linear::new(Angle::degrees(350.0)..=Angle::degrees(10.0), 2.0);
+/// assert!(tween.run(1.0).to_degrees().abs() < 1e-4);
+///
+/// let mut tween = Linear::new(Angle::degrees(350.0)..=Angle::degrees(10.0).the_long_way(), 2.0);
+/// assert!((tween.run(1.0).to_degrees() - 180.0).abs() < 1e-4);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Angle {
+    radians: f32,
+    /// `None` for deltas, which are never wrapped.
+    long_way: Option<bool>,
+}
+
+impl Angle {
+    /// Creates an angle from radians.
+    pub const fn radians(radians: f32) -> Self {
+        Self {
+            radians,
+            long_way: Some(false),
+        }
+    }
+
+    /// Creates an angle from degrees.
+    pub fn degrees(degrees: f32) -> Self {
+        Self::radians(degrees.to_radians())
+    }
+
+    /// The angle in radians. Tweened angles are always in `-π..=π`.
+    pub fn to_radians(self) -> f32 {
+        self.radians
+    }
+
+    /// The angle in degrees. Tweened angles are always in `-180.0..=180.0`.
+    pub fn to_degrees(self) -> f32 {
+        self.radians.to_degrees()
+    }
+
+    /// Makes tweens to or from this angle go the long way around the circle. Two equal angles
+    /// make a full turn.
+    pub const fn the_long_way(mut self) -> Self {
+        self.long_way = Some(true);
+        self
+    }
+
+    /// Whether tweens to or from this angle go the long way around the circle.
+    pub fn is_long_way(self) -> bool {
+        self.long_way.unwrap_or(false)
+    }
+}
+
+impl Default for Angle {
+    fn default() -> Self {
+        Self::radians(0.0)
+    }
+}
+
+/// Wraps an angle into `-π..=π`.
+fn wrap(radians: f32) -> f32 {
+    let wrapped = radians % TAU;
+    if wrapped > PI {
+        wrapped - TAU
+    } else if wrapped <= -PI {
+        wrapped + TAU
+    } else {
+        wrapped
+    }
+}
+
+impl TweenValue for Angle {
+    const ZERO: Self = Self {
+        radians: 0.0,
+        long_way: None,
+    };
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        let difference = destination.radians - start.radians;
+        let radians = match (destination.long_way, start.long_way) {
+            (None, None) => difference,
+            (Some(true), _) | (_, Some(true)) => {
+                let short = wrap(difference);
+                if short > 0.0 { short - TAU } else { short + TAU }
+            }
+            _ => wrap(difference),
+        };
+
+        Self {
+            radians,
+            long_way: None,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        let long_way = self.long_way.or(other.long_way);
+        let radians = self.radians + other.radians;
+
+        Self {
+            radians: if long_way.is_some() { wrap(radians) } else { radians },
+            long_way,
+        }
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        Self {
+            radians: self.radians * scale as f32,
+            long_way: self.long_way,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BounceIn, Linear};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn wraps_the_short_way() {
+        let mut tween = Linear::new(Angle::degrees(170.0)..=Angle::degrees(-170.0), 4.0);
+        assert_abs_diff_eq!(tween.run(1.0).to_degrees(), 175.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(tween.run(3.0).to_degrees(), -175.0, epsilon = 1e-4);
+        assert_abs_diff_eq!(tween.run(4.0).to_degrees(), -170.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn goes_the_long_way() {
+        let start = Angle::degrees(10.0).the_long_way();
+        let mut tween = Linear::new(start..=Angle::degrees(10.0), 4.0);
+        assert_abs_diff_eq!(tween.run(2.0).to_degrees(), -170.0, epsilon = 1e-4);
+
+        // easings which combine deltas together must not wrap them
+        let mut tween = BounceIn::new(Angle::degrees(0.0)..=Angle::degrees(-90.0).the_long_way(), 4.0);
+        assert_abs_diff_eq!(tween.run(4.0).to_degrees(), -90.0, epsilon = 1e-3);
+    }
+}
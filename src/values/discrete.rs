@@ -0,0 +1,141 @@
+use crate::{TweenValue, math};
+
+/// A value which steps through a list of values, such as sprite frames or the states of an
+/// enum, rather than blending between them. Only its *position* in the list is tweened, so
+/// the easing decides when each step happens, and [Discrete::get] picks out the value.
+///
+/// ```
+/// # use tween::{Discrete, Linear};
+/// #[derive(Debug, PartialEq, Clone)]
+/// enum Frame {
+///     Crouch,
+///     Jump,
+///     Land,
+/// }
+/// const FRAMES: [Frame; 3] = [Frame::Crouch, Frame::Jump, Frame::Land];
+///
+/// let mut tween = Linear::new(Discrete::new(&FRAMES, 0)..=Discrete::new(&FRAMES, 2), 4.0);
+/// assert_eq!(tween.run(1.0).get(), Frame::Crouch);
+/// assert_eq!(tween.run(2.0).get(), Frame::Jump);
+/// assert_eq!(tween.run(4.0).get(), Frame::Land);
+/// ```
+///
+/// A value is shown from its own position up to the next one, so the last value of the
+/// tween only appears once it ends. To give the last value an equal share of the time, like a
+/// looping sprite animation wants, tween to `values.len()` instead: positions past the end of
+/// the list show the last value, and positions before the start show the first.
+///
+/// Both ends of a tween should use the same list of values.
+#[derive(Debug, PartialEq)]
+pub struct Discrete<'a, T> {
+    values: &'a [T],
+    position: f64,
+}
+
+impl<'a, T> Discrete<'a, T> {
+    /// Creates a new value at the given index into the list of values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    pub fn new(values: &'a [T], index: usize) -> Self {
+        assert!(!values.is_empty(), "a `Discrete` needs at least one value");
+
+        Self {
+            values,
+            position: index as f64,
+        }
+    }
+
+    /// The index of the current value into the list of values.
+    pub fn index(&self) -> usize {
+        // `as` saturates the negative positions of overshooting easings to zero
+        (math::floor(self.position) as usize).min(self.values.len() - 1)
+    }
+
+    /// The current value.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.values[self.index()].clone()
+    }
+
+    /// The list of values this steps through.
+    pub fn values(&self) -> &'a [T] {
+        self.values
+    }
+}
+
+impl<T> Clone for Discrete<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Discrete<'_, T> {}
+
+/// Deltas have no values, like [ZERO](TweenValue::ZERO), and adding them to a value keeps
+/// that value's list.
+impl<T> TweenValue for Discrete<'_, T> {
+    const ZERO: Self = Self {
+        values: &[],
+        position: 0.0,
+    };
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Self {
+            values: &[],
+            position: destination.position - start.position,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            values: if self.values.is_empty() {
+                other.values
+            } else {
+                self.values
+            },
+            position: self.position + other.position,
+        }
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        Self {
+            values: self.values,
+            position: self.position * scale,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BackIn, BounceOut, Linear, QuadIn};
+
+    #[test]
+    fn eased_steps() {
+        let frames = [10, 20, 30, 40];
+
+        // every frame gets an equal share of a linear tween
+        let mut tween = Linear::new(Discrete::new(&frames, 0)..=Discrete::new(&frames, frames.len()), 8);
+        let shown: [i32; 9] = core::array::from_fn(|time| tween.run(time as i32).get());
+        assert_eq!(shown, [10, 10, 20, 20, 30, 30, 40, 40, 40]);
+
+        // while a quadratic lingers on the first frames
+        let mut tween = QuadIn::new(Discrete::new(&frames, 0)..=Discrete::new(&frames, 3), 4.0);
+        assert_eq!(tween.run(2.0).index(), 0);
+        assert_eq!(tween.run(3.0).index(), 1);
+        assert_eq!(tween.run(4.0).get(), 40);
+    }
+
+    #[test]
+    fn overshooting() {
+        let frames = ['a', 'b', 'c'];
+        let range = Discrete::new(&frames, 0)..=Discrete::new(&frames, 2);
+
+        assert_eq!(BackIn::new(range.clone(), 10.0).run(2.0).get(), 'a');
+        assert_eq!(BounceOut::new(range, 10.0).run(10.0).get(), 'c');
+    }
+}
@@ -0,0 +1,144 @@
+use crate::TweenValue;
+
+/// A value which passes through a list of stops, each a position and a value, instead of
+/// going straight from one value to another. Only its *position* is tweened, and
+/// [Gradient::get] blends between the two stops on either side of it, so a tween can run
+/// through as many intermediate values, like the colors of a sunset, as it likes.
+///
+/// ```
+/// # use tween::{Gradient, Linear, Srgba};
+/// const SUNSET: [(f64, Srgba); 3] = [
+///     (0.0, Srgba::new(1.0, 1.0, 0.0, 1.0)),
+///     (0.25, Srgba::new(1.0, 0.0, 0.0, 1.0)),
+///     (1.0, Srgba::new(0.0, 0.0, 1.0, 1.0)),
+/// ];
+///
+/// let mut tween = Linear::new(Gradient::start(&SUNSET)..=Gradient::end(&SUNSET), 4.0);
+/// assert_eq!(tween.run(1.0).get().to_hex(), 0xff0000ff);
+/// assert_eq!(tween.run(4.0).get().to_hex(), 0x0000ffff);
+/// ```
+///
+/// The stops must be sorted by their position. Positions before the first stop and after
+/// the last stop hold the value of that stop. Both ends of a tween should use the same stops.
+#[derive(Debug, PartialEq)]
+pub struct Gradient<'a, V> {
+    stops: &'a [(f64, V)],
+    position: f64,
+}
+
+impl<'a, V: TweenValue> Gradient<'a, V> {
+    /// Creates a new value at the given position along the stops.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stops` is empty.
+    pub fn new(stops: &'a [(f64, V)], position: f64) -> Self {
+        assert!(!stops.is_empty(), "a `Gradient` needs at least one stop");
+
+        Self { stops, position }
+    }
+
+    /// Creates a new value at the position of the first stop.
+    pub fn start(stops: &'a [(f64, V)]) -> Self {
+        Self::new(stops, stops.first().map_or(0.0, |stop| stop.0))
+    }
+
+    /// Creates a new value at the position of the last stop.
+    pub fn end(stops: &'a [(f64, V)]) -> Self {
+        Self::new(stops, stops.last().map_or(0.0, |stop| stop.0))
+    }
+
+    /// The current position along the stops.
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    /// The current value, blended between the stops on either side of the position.
+    pub fn get(&self) -> V {
+        let Some(next) = self.stops.iter().position(|stop| self.position < stop.0) else {
+            return self.stops[self.stops.len() - 1].1;
+        };
+        if next == 0 {
+            return self.stops[0].1;
+        }
+
+        let (start, end) = (self.stops[next - 1], self.stops[next]);
+        let percent = (self.position - start.0) / (end.0 - start.0);
+
+        V::calculate_delta(end.1, start.1).scale(percent).add(start.1)
+    }
+
+    /// The stops this passes through.
+    pub fn stops(&self) -> &'a [(f64, V)] {
+        self.stops
+    }
+}
+
+impl<V> Clone for Gradient<'_, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> Copy for Gradient<'_, V> {}
+
+/// Deltas have no stops, like [ZERO](TweenValue::ZERO), and adding them to a value keeps that
+/// value's stops.
+impl<V> TweenValue for Gradient<'_, V> {
+    const ZERO: Self = Self {
+        stops: &[],
+        position: 0.0,
+    };
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Self {
+            stops: &[],
+            position: destination.position - start.position,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            stops: if self.stops.is_empty() { other.stops } else { self.stops },
+            position: self.position + other.position,
+        }
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        Self {
+            stops: self.stops,
+            position: self.position * scale,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BackOut, Linear, QuadIn};
+
+    #[test]
+    fn passes_through_stops() {
+        let stops = [(0.0, [0.0, 0.0]), (1.0, [10.0, 0.0]), (3.0, [10.0, 20.0])];
+
+        let mut tween = Linear::new(Gradient::start(&stops)..=Gradient::end(&stops), 6.0);
+        assert_eq!(tween.run(0.0).get(), [0.0, 0.0]);
+        assert_eq!(tween.run(1.0).get(), [5.0, 0.0]);
+        assert_eq!(tween.run(2.0).get(), [10.0, 0.0]);
+        assert_eq!(tween.run(4.0).get(), [10.0, 10.0]);
+        assert_eq!(tween.run(6.0).get(), [10.0, 20.0]);
+
+        let mut tween = QuadIn::new(Gradient::start(&stops)..=Gradient::end(&stops), 6.0);
+        assert_eq!(tween.run(3.0).get(), [7.5, 0.0]);
+    }
+
+    #[test]
+    fn holds_ends() {
+        let stops = [(0.2, 1.0), (0.8, 2.0)];
+        assert_eq!(Gradient::new(&stops, 0.0).get(), 1.0);
+        assert_eq!(Gradient::new(&[(0.5, 3.0)], 0.7).get(), 3.0);
+
+        let mut overshoot = BackOut::new(Gradient::start(&stops)..=Gradient::end(&stops), 1.0);
+        assert_eq!(overshoot.run(0.7).get(), 2.0);
+    }
+}
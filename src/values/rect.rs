@@ -0,0 +1,90 @@
+use crate::TweenValue;
+
+/// An axis-aligned rectangle, stored as its minimum and maximum corners, which tweens each
+/// corner along a straight line. This keeps the edges of an expanding panel or a moving
+/// focus rectangle consistent with each other.
+///
+/// ```
+/// # use tween::{Linear, Rect};
+/// let collapsed = Rect::from_origin_size([100.0, 100.0], [0.0, 0.0]);
+/// let expanded = Rect::from_origin_size([0.0, 50.0], [400.0, 300.0]);
+///
+/// let mut tween = Linear::new(collapsed..=expanded, 2.0);
+/// let halfway = tween.run(1.0);
+/// assert_eq!(halfway.origin(), [50.0, 75.0]);
+/// assert_eq!(halfway.size(), [200.0, 150.0]);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Rect {
+    /// The corner with the smallest coordinates.
+    pub min: [f32; 2],
+    /// The corner with the largest coordinates.
+    pub max: [f32; 2],
+}
+
+impl Rect {
+    /// Creates a rectangle from its minimum and maximum corners.
+    pub const fn new(min: [f32; 2], max: [f32; 2]) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates a rectangle from its minimum corner and its size.
+    pub fn from_origin_size(origin: [f32; 2], size: [f32; 2]) -> Self {
+        Self::new(origin, [origin[0] + size[0], origin[1] + size[1]])
+    }
+
+    /// The minimum corner.
+    pub fn origin(&self) -> [f32; 2] {
+        self.min
+    }
+
+    /// The width and height.
+    pub fn size(&self) -> [f32; 2] {
+        [self.max[0] - self.min[0], self.max[1] - self.min[1]]
+    }
+
+    /// The point in the middle of the rectangle.
+    pub fn center(&self) -> [f32; 2] {
+        [(self.min[0] + self.max[0]) / 2.0, (self.min[1] + self.max[1]) / 2.0]
+    }
+}
+
+impl TweenValue for Rect {
+    const ZERO: Self = Self::new([0.0, 0.0], [0.0, 0.0]);
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Self::new(
+            TweenValue::calculate_delta(destination.min, start.min),
+            TweenValue::calculate_delta(destination.max, start.max),
+        )
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.min.add(other.min), self.max.add(other.max))
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        Self::new(self.min.scale(scale), self.max.scale(scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QuadOut;
+
+    #[test]
+    fn corners_stay_consistent() {
+        let start = Rect::new([0.0, 0.0], [10.0, 10.0]);
+        let end = Rect::new([-10.0, 20.0], [30.0, 40.0]);
+
+        let mut tween = QuadOut::new(start..=end, 4.0);
+        for time in 0..=4 {
+            let rect = tween.run(time as f32);
+            // the center moves along a straight line, whatever the easing
+            let [x, y] = rect.center();
+            assert!((y - 5.0 - (x - 5.0) * 5.0).abs() < 1e-4);
+        }
+        assert_eq!(tween.run(4.0), end);
+    }
+}
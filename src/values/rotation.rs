@@ -0,0 +1,214 @@
+use crate::{
+    TweenValue,
+    math::{atan2, sin_cos, sqrt},
+};
+
+/// A 3D rotation, stored as a unit quaternion, which is tweened by spherical linear
+/// interpolation (slerp) along the shortest arc.
+///
+/// Tweening a quaternion component by component, which is all the math libraries' own types
+/// can offer, cuts straight through the sphere of rotations: the in-between values aren't
+/// normalized, and the rotation speeds up and slows down along the way. A `Rotation`'s
+/// in-between values are always normalized and turn at a steady rate.
+///
+/// `Rotation` doesn't depend on any math library, but converts to and from the quaternions of
+/// `glam`, `nalgebra`, `cgmath`, and `mint` when their features are enabled, and to and from
+/// `[x, y, z, w]` arrays for everything else.
+///
+/// ```
+/// # use tween::{Linear, Rotation};
+/// let quarter_turn = Rotation::from_axis_angle([0.0, 0.0, 1.0], core::f32::consts::FRAC_PI_2);
+///
+/// let mut tween = Linear::new(Rotation::IDENTITY..=quarter_turn, 2.0);
+/// let halfway = tween.run(1.0);
+///
+/// assert!((halfway.angle() - core::f32::consts::FRAC_PI_4).abs() < 1e-6);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rotation {
+    xyzw: [f32; 4],
+}
+
+impl Rotation {
+    /// The rotation which doesn't rotate at all.
+    pub const IDENTITY: Self = Self {
+        xyzw: [0.0, 0.0, 0.0, 1.0],
+    };
+
+    /// Creates a rotation from the components of a quaternion, normalizing them.
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { xyzw: [x, y, z, w] }.normalized()
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`, which doesn't need to be normalized.
+    pub fn from_axis_angle(axis: [f32; 3], angle: f32) -> Self {
+        let [x, y, z] = axis;
+        let length = sqrt(x * x + y * y + z * z);
+        if length == 0.0 {
+            return Self::IDENTITY;
+        }
+
+        let (sin, cos) = sin_cos(angle / 2.0);
+        let s = sin / length;
+        Self {
+            xyzw: [x * s, y * s, z * s, cos],
+        }
+    }
+
+    /// The components of the quaternion, in the order `[x, y, z, w]`.
+    pub fn to_xyzw(self) -> [f32; 4] {
+        self.xyzw
+    }
+
+    /// The angle of this rotation, in radians, in `0.0..=PI`.
+    pub fn angle(self) -> f32 {
+        let [x, y, z, w] = self.xyzw;
+        2.0 * atan2(sqrt(x * x + y * y + z * z), w.abs())
+    }
+
+    /// The rotation which undoes this one.
+    pub fn inverse(self) -> Self {
+        let [x, y, z, w] = self.xyzw;
+        Self { xyzw: [-x, -y, -z, w] }
+    }
+
+    fn normalized(self) -> Self {
+        let [x, y, z, w] = self.xyzw;
+        let length = sqrt(x * x + y * y + z * z + w * w);
+        if length == 0.0 {
+            return Self::IDENTITY;
+        }
+
+        Self {
+            xyzw: self.xyzw.map(|v| v / length),
+        }
+    }
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[f32; 4]> for Rotation {
+    fn from([x, y, z, w]: [f32; 4]) -> Self {
+        Self::from_xyzw(x, y, z, w)
+    }
+}
+
+impl From<Rotation> for [f32; 4] {
+    fn from(rotation: Rotation) -> Self {
+        rotation.xyzw
+    }
+}
+
+impl core::ops::Mul for Rotation {
+    type Output = Self;
+
+    /// Composes two rotations, so that `rhs` is applied first, and then `self`.
+    fn mul(self, rhs: Self) -> Self {
+        let [ax, ay, az, aw] = self.xyzw;
+        let [bx, by, bz, bw] = rhs.xyzw;
+
+        Self {
+            xyzw: [
+                aw * bx + ax * bw + ay * bz - az * by,
+                aw * by - ax * bz + ay * bw + az * bx,
+                aw * bz + ax * by - ay * bx + az * bw,
+                aw * bw - ax * bx - ay * by - az * bz,
+            ],
+        }
+        .normalized()
+    }
+}
+
+// The delta between two rotations is the rotation which takes `start` to `destination`, adding
+// is composing, and scaling a rotation scales its angle, so `delta.scale(t).add(start)` is a
+// slerp. `ZERO` is the identity.
+impl TweenValue for Rotation {
+    const ZERO: Self = Self::IDENTITY;
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        let delta = destination * start.inverse();
+
+        // `q` and `-q` are the same rotation, but only the one with a positive `w` is the
+        // short way around
+        if delta.xyzw[3] < 0.0 {
+            Self {
+                xyzw: delta.xyzw.map(|v| -v),
+            }
+        } else {
+            delta
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        let [x, y, z, w] = self.xyzw;
+        let sin_half_angle = sqrt(x * x + y * y + z * z);
+        if sin_half_angle == 0.0 {
+            return Self::IDENTITY;
+        }
+
+        let half_angle = atan2(sin_half_angle, w) * scale as f32;
+        let (sin, cos) = sin_cos(half_angle);
+        let s = sin / sin_half_angle;
+
+        Self {
+            xyzw: [x * s, y * s, z * s, cos],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BackOut, Linear};
+    use approx::assert_abs_diff_eq;
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn length(rotation: Rotation) -> f32 {
+        let [x, y, z, w] = rotation.to_xyzw();
+        sqrt(x * x + y * y + z * z + w * w)
+    }
+
+    #[test]
+    fn slerps() {
+        let end = Rotation::from_axis_angle([0.0, 0.0, 2.0], FRAC_PI_2);
+        let mut tween = Linear::new(Rotation::IDENTITY..=end, 4.0);
+
+        for time in 0..=4 {
+            let rotation = tween.run(time as f32);
+            assert_abs_diff_eq!(rotation.angle(), FRAC_PI_2 * time as f32 / 4.0, epsilon = 1e-6);
+            assert_abs_diff_eq!(length(rotation), 1.0, epsilon = 1e-6);
+        }
+
+        let [_, _, z, w] = tween.run(4.0).to_xyzw();
+        let (sin, cos) = sin_cos(FRAC_PI_4);
+        assert_abs_diff_eq!(z, sin, epsilon = 1e-6);
+        assert_abs_diff_eq!(w, cos, epsilon = 1e-6);
+
+        // overshooting eases keep turning around the same axis
+        let mut tween = BackOut::new(Rotation::IDENTITY..=end, 4.0);
+        assert!(tween.run(3.0).angle() > FRAC_PI_2);
+        assert_abs_diff_eq!(length(tween.run(3.0)), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn takes_the_shortest_arc() {
+        let start = Rotation::from_axis_angle([0.0, 1.0, 0.0], PI * 0.9);
+        // the same as a `-0.9π` turn, so the short way goes through `π`
+        let end = Rotation::from_axis_angle([0.0, 1.0, 0.0], PI * 1.1);
+
+        let mut tween = Linear::new(start..=end, 2.0);
+        assert_abs_diff_eq!(tween.run(1.0).angle(), PI, epsilon = 1e-5);
+
+        let flipped = Rotation::from_xyzw(0.0, 0.0, 0.0, -1.0);
+        let mut tween = Linear::new(Rotation::IDENTITY..=flipped, 2.0);
+        assert_abs_diff_eq!(tween.run(1.0).angle(), 0.0);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    TweenValue,
+    math::{ceil, floor, round},
+    rng::SplitMix64,
+};
+use core::marker::PhantomData;
+
+/// How a [Rounded] integer turns the fractional result of an easing back into an integer.
+///
+/// This is implemented by [Floor], [Ceil], [Nearest], and [Stochastic].
+pub trait Rounding {
+    /// Rounds `value` to a whole number.
+    fn round(value: f64) -> f64;
+}
+
+/// Rounds towards negative infinity.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Floor;
+
+/// Rounds towards positive infinity.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Ceil;
+
+/// Rounds to the nearest integer, and half-way cases away from zero.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Nearest;
+
+/// Rounds up with a probability equal to the fractional part, so `2.25` becomes `3` a quarter
+/// of the time. On average, the integer output tracks the real curve, which keeps slow tweens
+/// from stalling on one value and then jumping.
+///
+/// The "randomness" is seeded by the value being rounded, so the same tween sampled at the same
+/// time always gives the same output.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Stochastic;
+
+impl Rounding for Floor {
+    fn round(value: f64) -> f64 {
+        floor(value)
+    }
+}
+
+impl Rounding for Ceil {
+    fn round(value: f64) -> f64 {
+        ceil(value)
+    }
+}
+
+impl Rounding for Nearest {
+    fn round(value: f64) -> f64 {
+        round(value)
+    }
+}
+
+impl Rounding for Stochastic {
+    fn round(value: f64) -> f64 {
+        let whole = floor(value);
+        let threshold = SplitMix64::new(value.to_bits()).next_f64();
+
+        if threshold < value - whole { whole + 1.0 } else { whole }
+    }
+}
+
+/// An integer which is rounded with [Rounding] `R` whenever an easing scales it. The plain
+/// integer [TweenValue]s truncate towards zero instead, which makes a `-10..=10` tween
+/// linger on `0` for twice as long as on any other value.
+///
+/// ```
+/// # use tween::{Ceil, Linear, Nearest, Rounded};
+/// let mut tween = Linear::new(Rounded::<i32, Nearest>::new(0)..=Rounded::new(10), 4);
+/// assert_eq!(tween.run(1).get(), 3);
+///
+/// let mut tween = Linear::new(Rounded::<u8, Ceil>::new(0)..=Rounded::new(10), 4);
+/// assert_eq!(tween.run(1).get(), 3);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Rounded<T, R> {
+    value: T,
+    rounding: PhantomData<R>,
+}
+
+impl<T, R> Rounded<T, R> {
+    /// Wraps an integer.
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            rounding: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy, R> Rounded<T, R> {
+    /// Gets the integer.
+    pub fn get(self) -> T {
+        self.value
+    }
+}
+
+impl<T, R> From<T> for Rounded<T, R> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+macro_rules! rounded_value {
+    ($($t:ty),*) => {
+        $(
+            impl<R: Rounding + Copy> TweenValue for Rounded<$t, R> {
+                const ZERO: Self = Self::new(0);
+
+                fn calculate_delta(destination: Self, start: Self) -> Self {
+                    Self::new(destination.value - start.value)
+                }
+
+                fn add(self, other: Self) -> Self {
+                    Self::new(self.value + other.value)
+                }
+
+                fn scale(self, scale: f64) -> Self {
+                    Self::new(R::round(self.value as f64 * scale) as $t)
+                }
+            }
+        )*
+    };
+}
+
+rounded_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Linear;
+
+    #[test]
+    fn rounding_modes() {
+        fn at_quarter<R: Rounding + Copy>() -> i32 {
+            Linear::new(Rounded::<i32, R>::new(0)..=Rounded::new(-10), 4)
+                .run(1)
+                .get()
+        }
+
+        assert_eq!(at_quarter::<Floor>(), -3);
+        assert_eq!(at_quarter::<Ceil>(), -2);
+        assert_eq!(at_quarter::<Nearest>(), -3);
+    }
+
+    #[test]
+    fn stochastic_tracks_the_curve() {
+        let mut tween = Linear::new(Rounded::<u32, Stochastic>::new(0)..=Rounded::new(1), 1000);
+
+        let total: u32 = (0..1000).map(|t| tween.run(t).get()).sum();
+        assert!((400..600).contains(&total), "{total}");
+
+        // and it's deterministic
+        assert_eq!(tween.run(123), tween.run(123));
+    }
+}
@@ -0,0 +1,209 @@
+use super::{Angle, Rotation};
+use crate::{
+    TweenValue,
+    math::{powf, sin_cos},
+};
+
+/// A 2D transform, made of a translation, a rotation, and a scale, which is tweened as one
+/// unit: the translation is lerped, the rotation takes the shortest way around the circle, like
+/// an [Angle], and the scale is tweened *multiplicatively*.
+///
+/// Tweening the scale multiplicatively -- that is, lerping it in log space -- means that growing
+/// from `1x` to `4x` passes `2x` at the halfway point, and feels like a steady zoom, where a
+/// plain lerp would pass `2.5x` and seem to slow down. Scales must be positive.
+///
+/// ```
+/// # use tween::{Angle, Linear, Transform2D};
+/// let start = Transform2D::IDENTITY;
+/// let end = Transform2D::new([10.0, 0.0], Angle::degrees(90.0), [4.0, 4.0]);
+///
+/// let halfway = Linear::new(start..=end, 2.0).run(1.0);
+/// assert_eq!(halfway.translation, [5.0, 0.0]);
+/// assert!((halfway.rotation.to_degrees() - 45.0).abs() < 1e-4);
+/// assert!((halfway.scale[0] - 2.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Transform2D {
+    /// The translation, applied last.
+    pub translation: [f32; 2],
+    /// The rotation, applied after the scale.
+    pub rotation: Angle,
+    /// The scale along each axis, applied first.
+    pub scale: [f32; 2],
+}
+
+impl Transform2D {
+    /// The transform which does nothing.
+    pub const IDENTITY: Self = Self::new([0.0, 0.0], Angle::radians(0.0), [1.0, 1.0]);
+
+    /// Creates a new transform.
+    pub const fn new(translation: [f32; 2], rotation: Angle, scale: [f32; 2]) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Scales, rotates, and then translates a point.
+    pub fn transform_point(&self, [x, y]: [f32; 2]) -> [f32; 2] {
+        let (sin, cos) = sin_cos(self.rotation.to_radians());
+        let [x, y] = [x * self.scale[0], y * self.scale[1]];
+
+        [
+            x * cos - y * sin + self.translation[0],
+            x * sin + y * cos + self.translation[1],
+        ]
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+// The scale's delta is the ratio between the two scales, and adding and scaling it is
+// multiplying and raising it to a power, so `ZERO` has a scale of `1`.
+impl TweenValue for Transform2D {
+    const ZERO: Self = Self::new([0.0, 0.0], Angle::ZERO, [1.0, 1.0]);
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Self {
+            translation: TweenValue::calculate_delta(destination.translation, start.translation),
+            rotation: TweenValue::calculate_delta(destination.rotation, start.rotation),
+            scale: [
+                destination.scale[0] / start.scale[0],
+                destination.scale[1] / start.scale[1],
+            ],
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            translation: self.translation.add(other.translation),
+            rotation: self.rotation.add(other.rotation),
+            scale: [self.scale[0] * other.scale[0], self.scale[1] * other.scale[1]],
+        }
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        Self {
+            translation: self.translation.scale(scale),
+            rotation: self.rotation.scale(scale),
+            scale: self.scale.map(|s| powf(s, scale as f32)),
+        }
+    }
+}
+
+/// A 3D transform, made of a translation, a [Rotation], and a per-axis scale, which is tweened as
+/// one unit: the translation and the scale are lerped, and the rotation is slerped. A camera
+/// moved with one `Transform3D` tween can't drift apart the way three separate tweens can.
+///
+/// It converts to and from `glam`'s `Mat4` and `Affine3A`, and `nalgebra`'s `Isometry3`, when
+/// their features are enabled.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Transform3D {
+    /// The translation, applied last.
+    pub translation: [f32; 3],
+    /// The rotation, applied after the scale.
+    pub rotation: Rotation,
+    /// The scale along each axis, applied first.
+    pub scale: [f32; 3],
+}
+
+impl Transform3D {
+    /// The transform which does nothing.
+    pub const IDENTITY: Self = Self::new([0.0, 0.0, 0.0], Rotation::IDENTITY, [1.0, 1.0, 1.0]);
+
+    /// Creates a new transform.
+    pub const fn new(translation: [f32; 3], rotation: Rotation, scale: [f32; 3]) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+}
+
+impl Default for Transform3D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl TweenValue for Transform3D {
+    const ZERO: Self = Self::new([0.0, 0.0, 0.0], Rotation::ZERO, [0.0, 0.0, 0.0]);
+
+    fn calculate_delta(destination: Self, start: Self) -> Self {
+        Self {
+            translation: TweenValue::calculate_delta(destination.translation, start.translation),
+            rotation: TweenValue::calculate_delta(destination.rotation, start.rotation),
+            scale: TweenValue::calculate_delta(destination.scale, start.scale),
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            translation: self.translation.add(other.translation),
+            rotation: self.rotation.add(other.rotation),
+            scale: self.scale.add(other.scale),
+        }
+    }
+
+    fn scale(self, scale: f64) -> Self {
+        Self {
+            translation: self.translation.scale(scale),
+            rotation: self.rotation.scale(scale),
+            scale: self.scale.scale(scale),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Linear, SineInOut};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn tweens_as_one_unit() {
+        let start = Transform2D::new([0.0, 0.0], Angle::degrees(170.0), [1.0, 2.0]);
+        let end = Transform2D::new([4.0, 8.0], Angle::degrees(-170.0), [8.0, 0.5]);
+
+        let mut tween = Linear::new(start..=end, 3.0);
+        let third = tween.run(1.0);
+        assert_abs_diff_eq!(third.rotation.to_degrees(), 176.66667, epsilon = 1e-3);
+        assert_abs_diff_eq!(third.scale[0], 2.0, epsilon = 1e-5);
+        assert_abs_diff_eq!(third.scale[1], 1.259921, epsilon = 1e-5);
+
+        let mut tween = SineInOut::new(start..=end, 3.0);
+        let end_value = tween.run(3.0);
+        assert_abs_diff_eq!(end_value.translation[1], 8.0, epsilon = 1e-5);
+        assert_abs_diff_eq!(end_value.scale[0], 8.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn transforms_points() {
+        let transform = Transform2D::new([1.0, 1.0], Angle::degrees(90.0), [2.0, 1.0]);
+        let [x, y] = transform.transform_point([1.0, 0.0]);
+
+        assert_abs_diff_eq!(x, 1.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(y, 3.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn transform_3d() {
+        let end = Transform3D::new(
+            [2.0, 4.0, 6.0],
+            Rotation::from_axis_angle([1.0, 0.0, 0.0], core::f32::consts::PI),
+            [3.0, 1.0, 1.0],
+        );
+
+        let mut tween = Linear::new(Transform3D::IDENTITY..=end, 2.0);
+        let halfway = tween.run(1.0);
+        assert_eq!(halfway.translation, [1.0, 2.0, 3.0]);
+        assert_eq!(halfway.scale, [2.0, 1.0, 1.0]);
+        assert_abs_diff_eq!(halfway.rotation.angle(), core::f32::consts::FRAC_PI_2, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,47 @@
+use wide::{f32x4, f32x8, f64x2, f64x4};
+
+/// Implements [TweenValue](crate::TweenValue) for a SIMD vector, so that one call to `run`
+/// computes the easing once and applies it to every lane.
+macro_rules! wide_value {
+    ($t:ty, $float:ty) => {
+        impl crate::TweenValue for $t {
+            const ZERO: Self = <$t>::ZERO;
+
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                destination - start
+            }
+
+            fn add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn scale(self, scale: f64) -> Self {
+                self * <$t>::splat(scale as $float)
+            }
+        }
+    };
+}
+
+wide_value!(f32x4, f32);
+wide_value!(f32x8, f32);
+wide_value!(f64x2, f64);
+wide_value!(f64x4, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{CubicInOut, Linear, Tween};
+    use wide::{f32x8, f64x2};
+
+    #[test]
+    fn lanes() {
+        let start = f32x8::from([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+        let end = f32x8::from([8.0; 8]);
+
+        let mut tween = Linear::new(start..=end, 2.0);
+        assert_eq!(tween.run(1.0).to_array(), [4.0, 4.5, 5.0, 5.5, 6.0, 6.5, 7.0, 7.5]);
+
+        let mut tween = CubicInOut::new(f64x2::ZERO..=f64x2::from([10.0, -10.0]), 2.0);
+        assert_eq!(tween.run(1.0).to_array(), [5.0, -5.0]);
+        assert_eq!(tween.range().end().to_array(), [10.0, -10.0]);
+    }
+}
@@ -0,0 +1,303 @@
+//! The derive macro for [tween](https://docs.rs/tween)'s `TweenValue`. Don't depend on this
+//! crate directly -- enable tween's `derive` feature instead.
+#![deny(rust_2018_idioms)]
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Expr, Index, Member, parse_macro_input, parse_quote};
+
+/// Derives `TweenValue` for a struct by tweening each of its fields. Every field must be a
+/// `TweenValue` itself, unless it is marked with `#[tween(skip)]`.
+///
+/// Skipped fields aren't tweened, and always keep the value of the start of the tween: in
+/// `calculate_delta`, `add`, and `scale`, they keep the value of `start` or `self`, never of
+/// `other`. In the struct's `ZERO`, they are set to their own `TweenValue::ZERO`. For a skipped
+/// field which isn't a `TweenValue`, give its value in `ZERO` with `#[tween(zero = <expr>)]`.
+///
+/// ```
+/// #[derive(tween::TweenValue, Clone, Copy)]
+/// struct Style {
+///     opacity: f32,
+///     #[tween(skip)]
+///     layer: u32,
+///     #[tween(skip, zero = true)]
+///     visible: bool,
+/// }
+/// ```
+///
+/// Only structs can be derived, since there's no tweening between the variants of an enum:
+///
+/// ```compile_fail
+/// #[derive(tween::TweenValue, Clone, Copy)]
+/// enum Visibility {
+///     Shown,
+///     Hidden,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(tween::TweenValue, Clone, Copy)]
+/// union Bits {
+///     float: f32,
+///     int: u32,
+/// }
+/// ```
+///
+/// `skip` takes no value, and `zero` is only for skipped fields:
+///
+/// ```compile_fail
+/// #[derive(tween::TweenValue, Clone, Copy)]
+/// struct Style {
+///     opacity: f32,
+///     #[tween(skip = false)]
+///     visible: bool,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(tween::TweenValue, Clone, Copy)]
+/// struct Style {
+///     #[tween(zero = 1.0)]
+///     opacity: f32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(tween::TweenValue, Clone, Copy)]
+/// struct Style {
+///     #[tween(hidden)]
+///     opacity: f32,
+/// }
+/// ```
+///
+/// And every field which isn't skipped must be a `TweenValue`:
+///
+/// ```compile_fail
+/// #[derive(tween::TweenValue, Clone, Copy)]
+/// struct Style {
+///     opacity: f32,
+///     visible: bool,
+/// }
+/// ```
+#[proc_macro_derive(TweenValue, attributes(tween))]
+pub fn derive_tween_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match derive(input) {
+        Ok(output) => output.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+struct Field {
+    member: Member,
+    /// `Some` with the value to use in `ZERO` if the field is skipped.
+    skip: Option<Expr>,
+}
+
+fn derive(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`TweenValue` can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut fields = Vec::new();
+    for (i, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        };
+
+        let mut skip = false;
+        let mut zero: Option<Expr> = None;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("tween")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    if meta.input.peek(syn::Token![=]) {
+                        return Err(
+                            meta.error("`skip` takes no value, give a skipped field's `ZERO` with `zero = <expr>`")
+                        );
+                    }
+                    skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("zero") {
+                    zero = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip` or `zero`"))
+                }
+            })?;
+        }
+
+        let skip = match (skip, zero) {
+            (true, Some(zero)) => Some(zero),
+            (true, None) => {
+                let ty = &field.ty;
+                Some(parse_quote!(<#ty as ::tween::TweenValue>::ZERO))
+            }
+            (false, Some(zero)) => {
+                return Err(Error::new_spanned(
+                    zero,
+                    "`zero` is only used by fields marked `#[tween(skip)]`",
+                ));
+            }
+            (false, None) => None,
+        };
+
+        fields.push(Field { member, skip });
+    }
+
+    // every type parameter which is tweened needs to be a `TweenValue` too
+    let tweened_types: Vec<_> = data
+        .fields
+        .iter()
+        .zip(&fields)
+        .filter(|(_, f)| f.skip.is_none())
+        .map(|(field, _)| field.ty.clone())
+        .collect();
+    if !input.generics.params.is_empty() {
+        let where_clause = input.generics.make_where_clause();
+        for ty in &tweened_types {
+            where_clause.predicates.push(parse_quote!(#ty: ::tween::TweenValue));
+        }
+    }
+
+    let construct = |each: &dyn Fn(&Field) -> TokenStream2| -> TokenStream2 {
+        let values = fields.iter().map(|f| {
+            let member = &f.member;
+            let value = each(f);
+            quote!(#member: #value)
+        });
+        quote!(Self { #(#values),* })
+    };
+
+    let zero = construct(&|f| match &f.skip {
+        Some(zero) => quote!(#zero),
+        None => quote!(::tween::TweenValue::ZERO),
+    });
+    let calculate_delta = construct(&|f| {
+        let m = &f.member;
+        match f.skip {
+            Some(_) => quote!(start.#m),
+            None => quote!(::tween::TweenValue::calculate_delta(destination.#m, start.#m)),
+        }
+    });
+    let add = construct(&|f| {
+        let m = &f.member;
+        match f.skip {
+            Some(_) => quote!(self.#m),
+            None => quote!(::tween::TweenValue::add(self.#m, other.#m)),
+        }
+    });
+    let scale = construct(&|f| {
+        let m = &f.member;
+        match f.skip {
+            Some(_) => quote!(self.#m),
+            None => quote!(::tween::TweenValue::scale(self.#m, scale)),
+        }
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::tween::TweenValue for #name #ty_generics #where_clause {
+            const ZERO: Self = #zero;
+
+            #[allow(unused_variables)]
+            fn calculate_delta(destination: Self, start: Self) -> Self {
+                #calculate_delta
+            }
+
+            #[allow(unused_variables)]
+            fn add(self, other: Self) -> Self {
+                #add
+            }
+
+            #[allow(unused_variables)]
+            fn scale(self, scale: f64) -> Self {
+                #scale
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(input: DeriveInput) -> String {
+        derive(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn skipped_fields_keep_self() {
+        let output = derive(parse_quote! {
+            struct Style {
+                opacity: f32,
+                #[tween(skip, zero = true)]
+                visible: bool,
+            }
+        })
+        .unwrap()
+        .to_string();
+
+        assert!(output.contains(&quote!(visible: self.visible).to_string()));
+        assert!(output.contains(&quote!(visible: true).to_string()));
+        assert!(!output.contains(&quote!(other.visible).to_string()));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            error(parse_quote!(
+                enum Visibility {
+                    Shown,
+                }
+            )),
+            "`TweenValue` can only be derived for structs"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                union Bits {
+                    float: f32,
+                }
+            )),
+            "`TweenValue` can only be derived for structs"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                struct Style {
+                    #[tween(skip = false)]
+                    visible: bool,
+                }
+            )),
+            "`skip` takes no value, give a skipped field's `ZERO` with `zero = <expr>`"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                struct Style {
+                    #[tween(zero = 1.0)]
+                    opacity: f32,
+                }
+            )),
+            "`zero` is only used by fields marked `#[tween(skip)]`"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                struct Style {
+                    #[tween(hidden)]
+                    opacity: f32,
+                }
+            )),
+            "expected `skip` or `zero`"
+        );
+    }
+}